@@ -0,0 +1,9 @@
+use mythoji::BodyPart;
+
+fn main() {
+    println!("mythoji::BodyPart::\n");
+
+    for part in BodyPart::iter() {
+        println!("{:<25} = {}", format!("{:?}", part), part);
+    }
+}
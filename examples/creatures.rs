@@ -1,5 +1,4 @@
 use mythoji::Creature;
-use strum::IntoEnumIterator;
 
 fn main() {
     println!("mythoji::Creature::\n");
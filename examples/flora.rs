@@ -0,0 +1,9 @@
+use mythoji::Flora;
+
+fn main() {
+    println!("mythoji::Flora::\n");
+
+    for flora in Flora::iter() {
+        println!("{:<25} = {}", format!("{:?}", flora), flora);
+    }
+}
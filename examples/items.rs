@@ -1,5 +1,4 @@
 use mythoji::Item;
-use strum::IntoEnumIterator;
 
 fn main() {
     println!("mythoji::Item::\n");
@@ -1,5 +1,4 @@
 use mythoji::Location;
-use strum::IntoEnumIterator;
 
 fn main() {
     println!("mythoji::Location::\n");
@@ -1,6 +1,5 @@
 use mythoji::Emoji;
 use mythoji::{Gender, Person, SkinTone};
-use strum::IntoEnumIterator;
 
 fn main() {
     println!("mythoji::Person::\n");
@@ -1,5 +1,4 @@
 use mythoji::Symbol;
-use strum::IntoEnumIterator;
 
 fn main() {
     println!("mythoji::Symbol::\n");
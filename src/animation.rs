@@ -0,0 +1,233 @@
+//! Animations, which cycle through naturally cyclic emojis, e.g. for loading spinners.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use mythoji::animation::Animation;
+//!
+//! let moon = Animation::Moon;
+//! assert_eq!(moon.frame_at(Duration::ZERO), "🌑");
+//! assert_eq!(moon.frame_at(moon.frame_duration() * 4), "🌕");
+//!
+//! let frames: String = Animation::Hourglass.cycle().take(3).collect();
+//! assert_eq!(frames, "⏳⌛⏳");
+//! ```
+//!
+//! Any emojis can be animated with an [`AnimationBuilder`], including the cells of a [`Grid`]:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use mythoji::animation::AnimationBuilder;
+//! use mythoji::grid::Grid;
+//! use mythoji::{Emoji, Location};
+//!
+//! let volcano = AnimationBuilder::new()
+//!     .frame(Emoji::Location(Location::Mountain), Duration::from_secs(2))
+//!     .frame(Emoji::Location(Location::Volcano), Duration::from_millis(500))
+//!     .build();
+//!
+//! let mut map = Grid::new(2, 1);
+//! map.set(0, 0, volcano);
+//! map.set(1, 0, Emoji::Location(Location::Castle).into());
+//! assert_eq!(map.frame_at(Duration::from_secs(1)).to_string(), "⛰🏰");
+//! assert_eq!(map.frame_at(Duration::from_secs(2)).to_string(), "🌋🏰");
+//! ```
+
+use std::time::Duration;
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::{grid::Grid, Emoji};
+
+/// A cyclic sequence of emojis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Animation {
+    /// An hourglass flipping, e.g. "⏳" and "⌛".
+    Hourglass,
+
+    /// A clock face advancing by the hour, from "🕛" to "🕚".
+    Clock,
+
+    /// The phases of the moon, from "🌑" (new) through "🌕" (full) to "🌘".
+    Moon,
+}
+
+impl_all!(Animation, [Hourglass, Clock, Moon]);
+
+impl Animation {
+    /// Returns the fully-qualified frames of the animation, in order.
+    pub const fn frames(&self) -> &'static [&'static str] {
+        match self {
+            Self::Hourglass => &["⏳", "⌛"],
+            Self::Clock => &[
+                "🕛", "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚",
+            ],
+            Self::Moon => &["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
+        }
+    }
+
+    /// Returns how long each frame is shown by [`Animation::frame_at`].
+    pub const fn frame_duration(&self) -> Duration {
+        match self {
+            Self::Hourglass => Duration::from_millis(1000),
+            Self::Clock | Self::Moon => Duration::from_millis(250),
+        }
+    }
+
+    /// Returns the frame shown after the given time has elapsed since the animation started.
+    pub fn frame_at(&self, elapsed: Duration) -> &'static str {
+        let frames = self.frames();
+        let index = elapsed.as_nanos() / self.frame_duration().as_nanos();
+        frames[(index % frames.len() as u128) as usize]
+    }
+
+    /// Returns an endless iterator over the frames of the animation, starting from the first.
+    ///
+    /// Unlike [`Animation::frames`], this repeats the frames forever, e.g. to feed a spinner.
+    pub fn cycle(&self) -> impl Iterator<Item = &'static str> {
+        self.frames().iter().copied().cycle()
+    }
+}
+
+/// Builds a [`Sequence`] of arbitrary emojis, each shown for its own duration.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AnimationBuilder {
+    frames: Vec<(Emoji, Duration)>,
+}
+
+impl AnimationBuilder {
+    /// Creates a builder without any frames.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a frame, which shows the given emoji for the given duration.
+    pub fn frame(mut self, emoji: Emoji, duration: Duration) -> Self {
+        self.frames.push((emoji, duration));
+        self
+    }
+
+    /// Builds the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no frames, or every frame has a duration of zero.
+    pub fn build(self) -> Sequence {
+        let duration = self.frames.iter().map(|(_, d)| *d).sum();
+        assert!(duration > Duration::ZERO, "animation must have a duration");
+        Sequence {
+            frames: self.frames,
+            duration,
+        }
+    }
+}
+
+/// A looping sequence of emojis, built by an [`AnimationBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sequence {
+    frames: Vec<(Emoji, Duration)>,
+    duration: Duration,
+}
+
+impl Sequence {
+    /// Returns the frames of the sequence, and how long each is shown.
+    pub fn frames(&self) -> &[(Emoji, Duration)] {
+        &self.frames
+    }
+
+    /// Returns how long it takes to show every frame once.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the emoji shown after the given time has elapsed since the sequence started.
+    pub fn frame_at(&self, elapsed: Duration) -> Emoji {
+        let mut remaining = elapsed.as_nanos() % self.duration.as_nanos();
+        for (emoji, duration) in &self.frames {
+            if remaining < duration.as_nanos() {
+                return *emoji;
+            }
+            remaining -= duration.as_nanos();
+        }
+        unreachable!("elapsed time is within the duration of the sequence")
+    }
+}
+
+/// Creates a sequence that always shows the given emoji.
+impl From<Emoji> for Sequence {
+    fn from(emoji: Emoji) -> Self {
+        AnimationBuilder::new()
+            .frame(emoji, Duration::from_secs(1))
+            .build()
+    }
+}
+
+impl Grid<Sequence> {
+    /// Returns the emojis shown in each cell after the given time has elapsed.
+    pub fn frame_at(&self, elapsed: Duration) -> Grid<Emoji> {
+        let mut frame = Grid::new(self.width(), self.height());
+        for (x, y, sequence) in self.iter() {
+            frame.set(x, y, sequence.frame_at(elapsed));
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Creature;
+
+    #[test]
+    fn test_animation_frame_at() {
+        let clock = Animation::Clock;
+        assert_eq!(clock.frame_at(Duration::ZERO), "🕛");
+        assert_eq!(clock.frame_at(Duration::from_millis(249)), "🕛");
+        assert_eq!(clock.frame_at(Duration::from_millis(250)), "🕐");
+        assert_eq!(clock.frame_at(Duration::from_secs(3)), "🕛");
+    }
+
+    #[test]
+    fn test_animation_cycle() {
+        for animation in Animation::ALL {
+            let frames = animation.frames();
+            let cycled: Vec<_> = animation.cycle().take(frames.len() * 2).collect();
+            assert_eq!(cycled[..frames.len()], *frames);
+            assert_eq!(cycled[frames.len()..], *frames);
+        }
+        assert_eq!(Animation::iter().last(), Some(Animation::Moon));
+    }
+
+    #[test]
+    fn test_sequence() {
+        let bat = Emoji::Creature(Creature::Bat);
+        let sequence = AnimationBuilder::new()
+            .frame(bat, Duration::from_millis(100))
+            .frame(Emoji::Creature(Creature::Ghost), Duration::ZERO)
+            .frame(
+                Emoji::Creature(Creature::Spider),
+                Duration::from_millis(300),
+            )
+            .build();
+        assert_eq!(sequence.duration(), Duration::from_millis(400));
+        assert_eq!(sequence.frames().len(), 3);
+        assert_eq!(sequence.frame_at(Duration::from_millis(99)), bat);
+        assert_eq!(
+            sequence.frame_at(Duration::from_millis(100)),
+            Emoji::Creature(Creature::Spider)
+        );
+        assert_eq!(sequence.frame_at(Duration::from_millis(400)), bat);
+    }
+
+    #[test]
+    #[should_panic(expected = "animation must have a duration")]
+    fn test_sequence_empty() {
+        AnimationBuilder::new().build();
+    }
+}
@@ -0,0 +1,115 @@
+//! Banners, which frame a title with emoji corners for intro and victory screens.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::banner::Banner;
+//!
+//! assert_eq!(
+//!     Banner::new("Victory!").to_string(),
+//!     "⚔️══════════⚔️\n   Victory!\n⚔️══════════⚔️",
+//! );
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{width::width, Emoji, Item};
+
+/// A title framed above and below by a line between two emojis, e.g. "⚔️═══⚔️".
+///
+/// The title is centered beneath the line, which is measured in terminal columns, so titles may
+/// contain emojis and other double-width characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Banner {
+    title: String,
+    corner: Emoji,
+    line: char,
+    padding: usize,
+    min_width: usize,
+}
+
+impl Banner {
+    /// Creates a banner with the given title, between crossed swords.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            corner: Emoji::Item(Item::SwordsCrossed),
+            line: '═',
+            padding: 1,
+            min_width: 0,
+        }
+    }
+
+    /// Places the given emoji at both ends of each line.
+    pub fn corner(mut self, corner: Emoji) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Draws the lines with the given single-width character.
+    pub fn line(mut self, line: char) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Extends the line by the given number of columns on both sides of the title.
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Makes the banner at least the given number of columns wide, including the corners.
+    pub fn min_width(mut self, min_width: usize) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Returns the width of the banner in terminal columns.
+    pub fn width(&self) -> usize {
+        self.inner_width() + 4
+    }
+
+    fn inner_width(&self) -> usize {
+        let title = width(&self.title) + self.padding * 2;
+        title.max(self.min_width.saturating_sub(4))
+    }
+}
+
+impl Display for Banner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let inner = self.inner_width();
+        let line = self.line.to_string().repeat(inner);
+        let indent = 2 + (inner - width(&self.title)) / 2;
+        writeln!(f, "{}{}{}", self.corner, line, self.corner)?;
+        writeln!(f, "{}{}", " ".repeat(indent), self.title)?;
+        write!(f, "{}{}{}", self.corner, line, self.corner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Location};
+
+    #[test]
+    fn test_banner() {
+        let banner = Banner::new("🐉 Slain")
+            .corner(Emoji::Location(Location::Castle))
+            .line('-')
+            .padding(2);
+        assert_eq!(banner.width(), 16);
+        assert_eq!(
+            banner.to_string(),
+            "🏰------------🏰\n    🐉 Slain\n🏰------------🏰"
+        );
+    }
+
+    #[test]
+    fn test_banner_min_width() {
+        let banner = Banner::new("Hi")
+            .corner(Emoji::Creature(Creature::Ghost))
+            .min_width(10);
+        assert_eq!(banner.width(), 10);
+        assert_eq!(banner.to_string(), "👻══════👻\n    Hi\n👻══════👻");
+    }
+}
@@ -0,0 +1,112 @@
+//! Combat log lines, which render two sides of a fight separated by a symbol.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::combat::CombatLine;
+//! use mythoji::{Creature, Emoji, Gender, Person, SkinTone};
+//!
+//! let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female);
+//! let dragon = Emoji::Creature(Creature::Dragon);
+//! assert_eq!(CombatLine::new([elf], [dragon]).to_string(), "🧝‍♀️ ⚔️ 🐉");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Emoji, Item, Qualification};
+
+/// A line of a combat log, e.g. "🧟🧟 ⚔️ 🧙‍♂️🛡".
+///
+/// Each side is rendered without spaces between combatants, and separated from the other side by a
+/// single space on either side of the separator. A side without combatants is omitted, along with
+/// its space.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CombatLine {
+    attackers: Vec<Emoji>,
+    defenders: Vec<Emoji>,
+    separator: Emoji,
+    qualification: Qualification,
+}
+
+impl CombatLine {
+    /// Creates a line where the attackers fight the defenders, separated by crossed swords.
+    pub fn new(
+        attackers: impl IntoIterator<Item = Emoji>,
+        defenders: impl IntoIterator<Item = Emoji>,
+    ) -> Self {
+        Self {
+            attackers: attackers.into_iter().collect(),
+            defenders: defenders.into_iter().collect(),
+            separator: Emoji::Item(Item::SwordsCrossed),
+            qualification: Qualification::Full,
+        }
+    }
+
+    /// Separates the two sides with the given emoji, e.g. [`Item::BowAndArrow`].
+    pub fn separator(mut self, separator: Emoji) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Displays every emoji in the line with the given [`Qualification`].
+    pub fn qualification(mut self, qualification: Qualification) -> Self {
+        self.qualification = qualification;
+        self
+    }
+
+    /// Returns the attacking side.
+    pub fn attackers(&self) -> &[Emoji] {
+        &self.attackers
+    }
+
+    /// Returns the defending side.
+    pub fn defenders(&self) -> &[Emoji] {
+        &self.defenders
+    }
+}
+
+impl Display for CombatLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for attacker in &self.attackers {
+            write!(f, "{}", attacker.qualified(self.qualification))?;
+        }
+        if !self.attackers.is_empty() {
+            f.write_str(" ")?;
+        }
+        write!(f, "{}", self.separator.qualified(self.qualification))?;
+        if !self.defenders.is_empty() {
+            f.write_str(" ")?;
+        }
+        for defender in &self.defenders {
+            write!(f, "{}", defender.qualified(self.qualification))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Gender, Person, SkinTone};
+
+    #[test]
+    fn test_combat_line() {
+        let zombie = Emoji::Person(Person::Zombie, SkinTone::Neutral, Gender::Neutral);
+        let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male);
+        let line = CombatLine::new([zombie, zombie], [mage, Emoji::Item(Item::Shield)]);
+        assert_eq!(line.to_string(), "🧟🧟 ⚔️ 🧙‍♂️🛡");
+        assert_eq!(
+            line.qualification(Qualification::Minimal).to_string(),
+            "🧟🧟 ⚔️ 🧙‍♂🛡"
+        );
+    }
+
+    #[test]
+    fn test_combat_line_separator() {
+        let line = CombatLine::new([], [Emoji::Creature(Creature::Dragon)])
+            .separator(Emoji::Item(Item::BowAndArrow));
+        assert_eq!(line.to_string(), "🏹 🐉");
+        assert!(line.attackers().is_empty());
+        assert_eq!(line.defenders(), [Emoji::Creature(Creature::Dragon)]);
+    }
+}
@@ -0,0 +1,250 @@
+//! Compatibility with data written by older releases of this crate, e.g. long-lived save files.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::compat::from_legacy_name;
+//! use mythoji::{Creature, Emoji, Person};
+//!
+//! // Renamed in 0.2.0 to fix a typo.
+//! let scarf = from_legacy_name("0.1.0", "HeardScarfPerson");
+//! assert_eq!(scarf, Some(Person::HeadScarfPerson.neutral()));
+//!
+//! // Names that were not renamed resolve to the current variant.
+//! let dragon = from_legacy_name("0.1.0", "Dragon");
+//! assert_eq!(dragon, Some(Emoji::Creature(Creature::Dragon)));
+//! ```
+//!
+//! Content can be gated on what an older release can parse, e.g. for clients that are not updated:
+//!
+//! ```
+//! use mythoji::{Creature, Emoji};
+//!
+//! let client = (0, 1, 0);
+//! assert!(Emoji::Creature(Creature::Dragon).added_in_crate_version() <= client);
+//! assert!(Emoji::Creature(Creature::Owl).added_in_crate_version() > client);
+//! ```
+
+use crate::metadata::{from_name, kebab_case};
+use crate::{BodyPart, Creature, Emoji, Flora, Item, Location, Person, Symbol};
+
+/// Variants that were renamed, with the release that renamed them, in the order they were renamed.
+const RENAMES: &[(&str, (u64, u64, u64), Emoji)] = &[(
+    "HeardScarfPerson",
+    (0, 2, 0),
+    Person::HeadScarfPerson.neutral(),
+)];
+
+/// Returns the current emoji for a variant name written by the given release of this crate.
+///
+/// The name is the `PascalCase` name of the variant (e.g. `"SwordsCrossed"`), or its `kebab-case`
+/// form (e.g. `"swords-crossed"`), as in [`Metadata::name`](crate::Metadata::name). The version is
+/// the `major.minor.patch` version of the crate that wrote the name, e.g. `"0.1.0"`; pre-release and
+/// build metadata are ignored.
+///
+/// Names of variants that were renamed after the given release are mapped to their current
+/// variant, and every other name is resolved as a current name. Persons are returned with a neutral
+/// skin tone and gender.
+///
+/// Returns `None` if the version is not a valid version, or the name is not known.
+pub fn from_legacy_name(crate_version: &str, name: &str) -> Option<Emoji> {
+    let version = parse_version(crate_version)?;
+    let name = kebab_case(name);
+    RENAMES
+        .iter()
+        .find(|(legacy, renamed_in, _)| version < *renamed_in && kebab_case(legacy) == name)
+        .map(|(_, _, emoji)| *emoji)
+        .or_else(|| from_name(&name))
+}
+
+/// The first release of this crate.
+const INITIAL: (u64, u64, u64) = (0, 1, 0);
+
+/// The next release of this crate, which adds every variant that is not yet released.
+const NEXT: (u64, u64, u64) = (0, 2, 0);
+
+impl Emoji {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this emoji.
+    ///
+    /// Persons are introduced with every skin tone and gender. Variants that were renamed (see
+    /// [`from_legacy_name`]) are introduced by the release that introduced them under any name.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        match self {
+            Self::Person(person, ..) => person.added_in_crate_version(),
+            Self::Creature(creature) => creature.added_in_crate_version(),
+            Self::Location(location) => location.added_in_crate_version(),
+            Self::Item(item) => item.added_in_crate_version(),
+            Self::Flora(flora) => flora.added_in_crate_version(),
+            Self::BodyPart(body_part) => body_part.added_in_crate_version(),
+            Self::Symbol(symbol) => symbol.added_in_crate_version(),
+        }
+    }
+}
+
+impl Person {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this person.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        INITIAL
+    }
+}
+
+impl Creature {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this creature.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        match self {
+            Self::Badger
+            | Self::Bear
+            | Self::Beaver
+            | Self::BlackBird
+            | Self::Blowfish
+            | Self::Cricket
+            | Self::Dolphin
+            | Self::Donkey
+            | Self::Dove
+            | Self::Fly
+            | Self::Fox
+            | Self::Frog
+            | Self::Gorilla
+            | Self::Hedgehog
+            | Self::Jellyfish
+            | Self::Lion
+            | Self::Lizard
+            | Self::Monkey
+            | Self::Moose
+            | Self::Mosquito
+            | Self::Octopus
+            | Self::Otter
+            | Self::Owl
+            | Self::Ox
+            | Self::Parrot
+            | Self::Peacock
+            | Self::Raccoon
+            | Self::Rooster
+            | Self::Seal
+            | Self::Sheep
+            | Self::Snail
+            | Self::Squid
+            | Self::Swan
+            | Self::Turkey
+            | Self::Turtle
+            | Self::Whale
+            | Self::Worm => NEXT,
+            _ => INITIAL,
+        }
+    }
+}
+
+impl Location {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this location.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        match self {
+            Self::TreeLeafless => NEXT,
+            _ => INITIAL,
+        }
+    }
+}
+
+impl Item {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this item.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        match self {
+            Self::DiyaLamp
+            | Self::FlagCheckered
+            | Self::FlagsCrossed
+            | Self::Flashlight
+            | Self::Harp
+            | Self::LightBulb
+            | Self::MouseTrap
+            | Self::Shovel
+            | Self::SpiderWeb => NEXT,
+            _ => INITIAL,
+        }
+    }
+}
+
+impl Flora {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this flora.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        NEXT
+    }
+}
+
+impl BodyPart {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this body part.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        NEXT
+    }
+}
+
+impl Symbol {
+    /// Returns the `(major, minor, patch)` release of this crate that introduced this symbol.
+    pub const fn added_in_crate_version(&self) -> (u64, u64, u64) {
+        match self {
+            Self::Fingerprint
+            | Self::Silhouette
+            | Self::Silhouettes
+            | Self::SpeechBubbleLeft
+            | Self::Splatter
+            | Self::ThoughtBubble => NEXT,
+            _ => INITIAL,
+        }
+    }
+}
+
+/// Parses a `major.minor.patch` version, ignoring pre-release and build metadata.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = version.split('.').map(str::parse);
+    let version = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_legacy_name() {
+        let scarf = Some(Person::HeadScarfPerson.neutral());
+        assert_eq!(from_legacy_name("0.1.0", "HeardScarfPerson"), scarf);
+        assert_eq!(
+            from_legacy_name("0.1.9-beta+1", "heard-scarf-person"),
+            scarf
+        );
+        assert_eq!(from_legacy_name("0.1.0", "HeadScarfPerson"), scarf);
+        assert_eq!(from_legacy_name("0.2.0", "HeadScarfPerson"), scarf);
+        assert_eq!(
+            from_legacy_name("0.1.0", "SwordsCrossed"),
+            Some(Emoji::Item(Item::SwordsCrossed))
+        );
+        assert_eq!(from_legacy_name("0.1.0", "Unicorn2"), None);
+        assert_eq!(from_legacy_name("0.1", "Dragon"), None);
+        assert_eq!(from_legacy_name("0.1.0.0", "Dragon"), None);
+        assert_eq!(from_legacy_name("latest", "Dragon"), None);
+    }
+
+    #[test]
+    fn test_added_in_crate_version() {
+        for emoji in crate::glyph::bases() {
+            let version = emoji.added_in_crate_version();
+            assert!(version == INITIAL || version == NEXT);
+        }
+        assert_eq!(
+            Person::HeadScarfPerson.neutral().added_in_crate_version(),
+            INITIAL
+        );
+        assert_eq!(Emoji::Flora(Flora::Rose).added_in_crate_version(), NEXT);
+    }
+
+    #[test]
+    fn test_renames() {
+        for (legacy, _, emoji) in RENAMES {
+            assert_ne!(emoji.metadata().name, *legacy);
+            assert_eq!(emoji.base(), *emoji);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! The building blocks of emoji sequences, for composing sequences that this crate doesn't model.
+//!
+//! These are the same components the crate uses internally, e.g. to display an [`Emoji::Person`]
+//! with a skin tone and gender, so sequences composed from them are consistent with its own.
+//!
+//! A person sequence is composed of a base, an optional skin tone modifier that directly follows the
+//! base, and optional components that are each joined by a [`ZWJ`], such as a role object or a
+//! gender sign. Gender signs, and role objects that default to text presentation, are followed by
+//! [`VS16`] when fully-qualified.
+//!
+//! [`Emoji::Person`]: crate::Emoji::Person
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::components::{FEMALE_SIGN, ROLE_FARMER, SKIN_TONE_MEDIUM, VS16, ZWJ};
+//!
+//! // A farmer, which this crate does not model.
+//! let farmer = format!("🧑{}{}{}", SKIN_TONE_MEDIUM, ZWJ, ROLE_FARMER);
+//! assert_eq!(farmer, "🧑🏽‍🌾");
+//!
+//! // A female elf, composed the same way as `Person::Elf.female()`.
+//! let elf = format!("🧝{}{}{}", ZWJ, FEMALE_SIGN, VS16);
+//! assert_eq!(elf, mythoji::Person::Elf.female().to_string());
+//! ```
+
+/// The zero width joiner, which joins the components of a sequence into a single emoji.
+pub const ZWJ: char = '\u{200d}';
+
+/// Variation selector 15, which requests text presentation of the preceding character.
+pub const VS15: char = '\u{fe0e}';
+
+/// Variation selector 16, which requests emoji presentation of the preceding character.
+pub const VS16: char = '\u{fe0f}';
+
+/// The light skin tone modifier, e.g. [`SkinTone::Light`](crate::SkinTone::Light).
+pub const SKIN_TONE_LIGHT: char = '\u{1f3fb}';
+
+/// The medium light skin tone modifier, e.g. [`SkinTone::MediumLight`](crate::SkinTone::MediumLight).
+pub const SKIN_TONE_MEDIUM_LIGHT: char = '\u{1f3fc}';
+
+/// The medium skin tone modifier, e.g. [`SkinTone::Medium`](crate::SkinTone::Medium).
+pub const SKIN_TONE_MEDIUM: char = '\u{1f3fd}';
+
+/// The medium dark skin tone modifier, e.g. [`SkinTone::MediumDark`](crate::SkinTone::MediumDark).
+pub const SKIN_TONE_MEDIUM_DARK: char = '\u{1f3fe}';
+
+/// The dark skin tone modifier, e.g. [`SkinTone::Dark`](crate::SkinTone::Dark).
+pub const SKIN_TONE_DARK: char = '\u{1f3ff}';
+
+/// Every skin tone modifier, from lightest to darkest.
+pub const SKIN_TONES: [char; 5] = [
+    SKIN_TONE_LIGHT,
+    SKIN_TONE_MEDIUM_LIGHT,
+    SKIN_TONE_MEDIUM,
+    SKIN_TONE_MEDIUM_DARK,
+    SKIN_TONE_DARK,
+];
+
+/// The male sign, e.g. [`Gender::Male`](crate::Gender::Male).
+pub const MALE_SIGN: char = '♂';
+
+/// The female sign, e.g. [`Gender::Female`](crate::Gender::Female).
+pub const FEMALE_SIGN: char = '♀';
+
+/// Red hair, e.g. "🧑‍🦰".
+pub const HAIR_RED: char = '🦰';
+
+/// Curly hair, e.g. "🧑‍🦱".
+pub const HAIR_CURLY: char = '🦱';
+
+/// White hair, e.g. "🧑‍🦳".
+pub const HAIR_WHITE: char = '🦳';
+
+/// No hair, e.g. [`Person::BaldPerson`](crate::Person::BaldPerson).
+pub const HAIR_BALD: char = '🦲';
+
+/// The role object of an artist, e.g. [`Person::Artist`](crate::Person::Artist).
+pub const ROLE_ARTIST: char = '🎨';
+
+/// The role object of a cook, e.g. "🧑‍🍳".
+pub const ROLE_COOK: char = '🍳';
+
+/// The role object of a farmer, e.g. "🧑‍🌾".
+pub const ROLE_FARMER: char = '🌾';
+
+/// The role object of a health worker, e.g. "🧑‍⚕️", which is followed by [`VS16`].
+pub const ROLE_HEALTH_WORKER: char = '⚕';
+
+/// The role object of a judge, e.g. "🧑‍⚖️", which is followed by [`VS16`].
+pub const ROLE_JUDGE: char = '⚖';
+
+/// The role object of a mechanic, e.g. "🧑‍🔧".
+pub const ROLE_MECHANIC: char = '🔧';
+
+/// The role object of a scientist, e.g. "🧑‍🔬".
+pub const ROLE_SCIENTIST: char = '🔬';
+
+/// The role object of a singer, e.g. "🧑‍🎤".
+pub const ROLE_SINGER: char = '🎤';
+
+/// The role object of a student, e.g. "🧑‍🎓".
+pub const ROLE_STUDENT: char = '🎓';
+
+/// The role object of a teacher, e.g. "🧑‍🏫".
+pub const ROLE_TEACHER: char = '🏫';
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gender, Person, SkinTone};
+
+    #[test]
+    fn test_components() {
+        for (skin, modifier) in SkinTone::ALL[1..].iter().zip(SKIN_TONES) {
+            assert_eq!(skin.to_string(), modifier.to_string());
+        }
+        assert_eq!(Gender::Male.to_string(), MALE_SIGN.to_string());
+        assert_eq!(Gender::Female.to_string(), FEMALE_SIGN.to_string());
+        assert_eq!(
+            Person::Artist
+                .with(SkinTone::Dark, Gender::Neutral)
+                .to_string(),
+            format!("🧑{}{}{}", SKIN_TONE_DARK, ZWJ, ROLE_ARTIST)
+        );
+        assert_eq!(
+            Person::BaldPerson.to_string(),
+            format!("🧑{}{}", ZWJ, HAIR_BALD)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Composites, which stack a base emoji with effects, e.g. a burning wolf.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::composite::{Composite, Order};
+//! use mythoji::{Creature, Emoji, Location, Symbol};
+//!
+//! let wolf = Composite::new(Emoji::Creature(Creature::Wolf)).effect(Symbol::Fire);
+//! assert_eq!(wolf.to_string(), "🐺🔥");
+//!
+//! let castle = Composite::new(Emoji::Location(Location::Castle))
+//!     .effect(Symbol::Snowflake)
+//!     .order(Order::EffectsFirst)
+//!     .separator(" ");
+//! assert_eq!(castle.to_string(), "❄️ 🏰");
+//! ```
+//!
+//! When rendered with a [`Renderer`], effects that are not supported are omitted, and the base
+//! emoji uses the renderer's fallback:
+//!
+//! ```
+//! use mythoji::composite::Composite;
+//! use mythoji::fallback::Fallback;
+//! use mythoji::render::Renderer;
+//! use mythoji::{Creature, Emoji, Symbol};
+//!
+//! let wolf = Composite::new(Emoji::Creature(Creature::Wolf)).effect(Symbol::Fire);
+//! assert_eq!(wolf.render(&Renderer::text(Fallback::Name)), "[wolf]");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::fallback::FallbackProvider;
+use crate::render::Renderer;
+use crate::{Emoji, Symbol};
+
+/// A base emoji, with effects rendered adjacent to it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Composite {
+    base: Emoji,
+    effects: Vec<Symbol>,
+    order: Order,
+    separator: String,
+}
+
+impl Composite {
+    /// Creates a composite of the given base emoji, without any effects.
+    pub fn new(base: Emoji) -> Self {
+        Self {
+            base,
+            effects: Vec::new(),
+            order: Order::default(),
+            separator: String::new(),
+        }
+    }
+
+    /// Adds an effect, which is rendered after any previously added effects.
+    pub fn effect(mut self, effect: Symbol) -> Self {
+        self.effects.push(effect);
+        self
+    }
+
+    /// Renders the base emoji and effects in the given order.
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Separates the base emoji and each effect with the given string, which is empty by default.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns the base emoji.
+    pub fn base(&self) -> Emoji {
+        self.base
+    }
+
+    /// Returns the effects, in order.
+    pub fn effects(&self) -> &[Symbol] {
+        &self.effects
+    }
+
+    /// Renders the composite with the given renderer.
+    ///
+    /// Effects that the renderer does not support are omitted.
+    pub fn render<F: FallbackProvider>(&self, renderer: &Renderer<F>) -> String {
+        let effects = self
+            .effects
+            .iter()
+            .filter(|e| renderer.supports_version(e.metadata().unicode_version))
+            .map(Symbol::to_string);
+        self.join(renderer.render(self.base).into_owned(), effects)
+    }
+
+    fn join(&self, base: String, effects: impl Iterator<Item = String>) -> String {
+        let mut parts = vec![base];
+        parts.extend(effects);
+        if self.order == Order::EffectsFirst {
+            parts.rotate_left(1);
+        }
+        parts.join(&self.separator)
+    }
+}
+
+impl Display for Composite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let effects = self.effects.iter().map(Symbol::to_string);
+        f.write_str(&self.join(self.base.to_string(), effects))
+    }
+}
+
+/// The order in which a [`Composite`] renders its base emoji and effects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Order {
+    /// The base emoji, followed by the effects, e.g. "🐺🔥".
+    #[default]
+    BaseFirst,
+
+    /// The effects, followed by the base emoji, e.g. "🔥🐺".
+    EffectsFirst,
+}
+
+impl_all!(Order, [BaseFirst, EffectsFirst]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, UnicodeVersion};
+
+    #[test]
+    fn test_composite() {
+        let bat = Composite::new(Emoji::Creature(Creature::Bat))
+            .effect(Symbol::Zzz)
+            .effect(Symbol::Sparkles)
+            .separator("+");
+        assert_eq!(bat.to_string(), "🦇+💤+✨");
+        assert_eq!(bat.base(), Emoji::Creature(Creature::Bat));
+        assert_eq!(bat.effects(), [Symbol::Zzz, Symbol::Sparkles]);
+
+        let bat = bat.order(Order::EffectsFirst);
+        assert_eq!(bat.to_string(), "💤+✨+🦇");
+    }
+
+    #[test]
+    fn test_composite_render() {
+        let dragon = Composite::new(Emoji::Creature(Creature::Dragon)).effect(Symbol::Fire);
+        assert_eq!(dragon.render(&Renderer::new()), "🐉🔥");
+
+        let renderer = Renderer::new().max_unicode_version(UnicodeVersion::V6);
+        let ghost = Composite::new(Emoji::Creature(Creature::Ghost))
+            .effect(Symbol::SpeechBubbleAngry)
+            .effect(Symbol::QuestionRed);
+        assert_eq!(ghost.render(&renderer), "👻❓");
+    }
+}
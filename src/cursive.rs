@@ -0,0 +1,106 @@
+//! Integration with the [cursive](https://docs.rs/cursive) TUI library, enabled by the `cursive`
+//! feature.
+//!
+//! Cursive measures text by the width of each character, which over-counts emoji sequences (e.g.
+//! "🧝‍♀️"), so [`EmojiView`] measures its content by terminal columns instead. Every [`Emoji`] also
+//! converts into a [`StyledString`], to label items in e.g. a `SelectView`.
+//!
+//! # Examples
+//!
+//! ```
+//! use cursive_core::utils::markup::StyledString;
+//! use cursive_core::{View, Vec2};
+//! use mythoji::cursive::EmojiView;
+//! use mythoji::party::Party;
+//! use mythoji::Person;
+//!
+//! let party = Party::new().member(Person::Elf.female()).unknown();
+//! let mut view = EmojiView::new(&party);
+//! assert_eq!(view.required_size(Vec2::new(80, 24)), Vec2::new(4, 1));
+//!
+//! let label = StyledString::from(Person::Mage.male());
+//! assert_eq!(label.source(), "🧙‍♂️");
+//! ```
+
+use std::fmt::Display;
+
+use cursive_core::utils::markup::StyledString;
+use cursive_core::{Printer, Vec2, View};
+
+use crate::width::width;
+use crate::Emoji;
+
+/// A view that displays emojis, or anything that displays as emojis (e.g. a
+/// [`Grid`](crate::grid::Grid)), measured by terminal columns.
+///
+/// Content with several lines is displayed from the top, one line per row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmojiView {
+    content: String,
+}
+
+impl EmojiView {
+    /// Creates a view that displays the given content.
+    pub fn new(content: impl Display) -> Self {
+        Self {
+            content: content.to_string(),
+        }
+    }
+
+    /// Replaces the content of the view.
+    pub fn set_content(&mut self, content: impl Display) {
+        self.content = content.to_string();
+    }
+
+    /// Returns the content of the view.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl View for EmojiView {
+    fn draw(&self, printer: &Printer) {
+        for (y, line) in self.content.lines().enumerate() {
+            printer.print((0, y), line);
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let columns = self.content.lines().map(width).max().unwrap_or(0);
+        Vec2::new(columns, self.content.lines().count())
+    }
+}
+
+impl From<Emoji> for EmojiView {
+    fn from(value: Emoji) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Emoji> for StyledString {
+    fn from(value: Emoji) -> Self {
+        Self::plain(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::{Creature, Location};
+
+    #[test]
+    fn test_emoji_view() {
+        let mut map = Grid::new(3, 2);
+        map.set(0, 0, Emoji::Location(Location::Castle));
+        map.set(2, 1, Emoji::Creature(Creature::Dragon));
+        let mut view = EmojiView::new(&map);
+        assert_eq!(view.required_size(Vec2::zero()), Vec2::new(6, 2));
+
+        view.set_content("");
+        assert_eq!(view.required_size(Vec2::zero()), Vec2::zero());
+
+        let view = EmojiView::from(Emoji::Creature(Creature::Dragon));
+        assert_eq!(view.content(), "🐉");
+    }
+}
@@ -0,0 +1,210 @@
+//! A dense store of user-defined data attached to emojis.
+
+use crate::{Emoji, Gender, SkinTone};
+
+/// Associates arbitrary data, such as damage values, sprite paths, or localization keys, with
+/// emojis.
+///
+/// Unlike a `HashMap<Emoji, T>`, data is stored densely by the [stable identifier](crate::Item::id)
+/// of each emoji, so lookups are a simple index.
+///
+/// Each [`Emoji::Person`] is stored separately for every skin tone and gender combination.
+///
+/// # Examples
+///
+/// ```
+/// use mythoji::{Creature, Emoji, EmojiData};
+///
+/// let mut damage = EmojiData::new();
+/// damage.insert(Emoji::Creature(Creature::Rat), 1);
+/// damage.insert(Emoji::Creature(Creature::Dragon), 50);
+///
+/// assert_eq!(damage.get(Emoji::Creature(Creature::Dragon)), Some(&50));
+/// assert_eq!(damage.get(Emoji::Creature(Creature::Wolf)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmojiData<T> {
+    persons: Vec<Option<T>>,
+    creatures: Vec<Option<T>>,
+    locations: Vec<Option<T>>,
+    items: Vec<Option<T>>,
+    flora: Vec<Option<T>>,
+    body_parts: Vec<Option<T>>,
+    symbols: Vec<Option<T>>,
+}
+
+impl<T> Default for EmojiData<T> {
+    fn default() -> Self {
+        Self {
+            persons: Vec::new(),
+            creatures: Vec::new(),
+            locations: Vec::new(),
+            items: Vec::new(),
+            flora: Vec::new(),
+            body_parts: Vec::new(),
+            symbols: Vec::new(),
+        }
+    }
+}
+
+impl<T> EmojiData<T> {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the data associated with `emoji`, if any.
+    pub fn get(&self, emoji: Emoji) -> Option<&T> {
+        let (slots, index) = self.slots(emoji);
+        slots.get(index)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the data associated with `emoji`, if any.
+    pub fn get_mut(&mut self, emoji: Emoji) -> Option<&mut T> {
+        let (slots, index) = self.slots_mut(emoji);
+        slots.get_mut(index)?.as_mut()
+    }
+
+    /// Returns whether there is data associated with `emoji`.
+    pub fn contains(&self, emoji: Emoji) -> bool {
+        self.get(emoji).is_some()
+    }
+
+    /// Associates `value` with `emoji`, returning the previously associated data, if any.
+    pub fn insert(&mut self, emoji: Emoji, value: T) -> Option<T> {
+        let (slots, index) = self.slots_mut(emoji);
+        if slots.len() <= index {
+            slots.resize_with(index + 1, || None);
+        }
+        slots[index].replace(value)
+    }
+
+    /// Removes the data associated with `emoji`, returning it, if any.
+    pub fn remove(&mut self, emoji: Emoji) -> Option<T> {
+        let (slots, index) = self.slots_mut(emoji);
+        slots.get_mut(index)?.take()
+    }
+
+    /// Removes all data.
+    pub fn clear(&mut self) {
+        self.persons.clear();
+        self.creatures.clear();
+        self.locations.clear();
+        self.items.clear();
+        self.flora.clear();
+        self.body_parts.clear();
+        self.symbols.clear();
+    }
+
+    /// Returns the number of emojis with associated data.
+    pub fn len(&self) -> usize {
+        self.values().count()
+    }
+
+    /// Returns whether no emojis have associated data.
+    pub fn is_empty(&self) -> bool {
+        self.values().next().is_none()
+    }
+
+    /// Returns an iterator over all associated data, in no particular order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.persons
+            .iter()
+            .chain(&self.creatures)
+            .chain(&self.locations)
+            .chain(&self.items)
+            .chain(&self.flora)
+            .chain(&self.body_parts)
+            .chain(&self.symbols)
+            .flatten()
+    }
+
+    fn slots(&self, emoji: Emoji) -> (&Vec<Option<T>>, usize) {
+        match emoji {
+            Emoji::Person(..) => (&self.persons, Self::index(emoji)),
+            Emoji::Creature(_) => (&self.creatures, Self::index(emoji)),
+            Emoji::Location(_) => (&self.locations, Self::index(emoji)),
+            Emoji::Item(_) => (&self.items, Self::index(emoji)),
+            Emoji::Flora(_) => (&self.flora, Self::index(emoji)),
+            Emoji::BodyPart(_) => (&self.body_parts, Self::index(emoji)),
+            Emoji::Symbol(_) => (&self.symbols, Self::index(emoji)),
+        }
+    }
+
+    fn slots_mut(&mut self, emoji: Emoji) -> (&mut Vec<Option<T>>, usize) {
+        match emoji {
+            Emoji::Person(..) => (&mut self.persons, Self::index(emoji)),
+            Emoji::Creature(_) => (&mut self.creatures, Self::index(emoji)),
+            Emoji::Location(_) => (&mut self.locations, Self::index(emoji)),
+            Emoji::Item(_) => (&mut self.items, Self::index(emoji)),
+            Emoji::Flora(_) => (&mut self.flora, Self::index(emoji)),
+            Emoji::BodyPart(_) => (&mut self.body_parts, Self::index(emoji)),
+            Emoji::Symbol(_) => (&mut self.symbols, Self::index(emoji)),
+        }
+    }
+
+    fn index(emoji: Emoji) -> usize {
+        match emoji {
+            Emoji::Person(person, skin, gender) => {
+                let combinations = SkinTone::ALL.len() * Gender::ALL.len();
+                let modifiers =
+                    usize::from(skin.id()) * Gender::ALL.len() + usize::from(gender.id());
+                usize::from(person.id()) * combinations + modifiers
+            }
+            Emoji::Creature(creature) => usize::from(creature.id()),
+            Emoji::Location(location) => usize::from(location.id()),
+            Emoji::Item(item) => usize::from(item.id()),
+            Emoji::Flora(flora) => usize::from(flora.id()),
+            Emoji::BodyPart(body_part) => usize::from(body_part.id()),
+            Emoji::Symbol(symbol) => usize::from(symbol.id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Item, Location, Person};
+
+    use super::*;
+
+    #[test]
+    fn test_emoji_data() {
+        let mut data = EmojiData::new();
+        assert!(data.is_empty());
+
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(data.insert(elf, "elf.png"), None);
+        assert_eq!(data.insert(elf, "elf-dark-female.png"), Some("elf.png"));
+        assert_eq!(data.get(elf), Some(&"elf-dark-female.png"));
+
+        let neutral_elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Neutral);
+        assert_eq!(data.get(neutral_elf), None);
+
+        data.insert(Emoji::Item(Item::Coin), "coin.png");
+        *data.get_mut(Emoji::Item(Item::Coin)).unwrap() = "gold.png";
+        assert!(data.contains(Emoji::Item(Item::Coin)));
+        assert!(!data.contains(Emoji::Location(Location::Castle)));
+        assert_eq!(data.len(), 2);
+
+        assert_eq!(data.remove(elf), Some("elf-dark-female.png"));
+        assert_eq!(data.remove(elf), None);
+        assert_eq!(data.values().collect::<Vec<_>>(), [&"gold.png"]);
+
+        data.clear();
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_emoji_data_person_indices_are_unique() {
+        let mut data = EmojiData::new();
+        for person in Person::ALL {
+            for skin in SkinTone::ALL {
+                for gender in Gender::ALL {
+                    let emoji = Emoji::Person(*person, *skin, *gender);
+                    assert_eq!(data.insert(emoji, emoji), None);
+                }
+            }
+        }
+        assert_eq!(data.len(), Person::ALL.len() * 18);
+    }
+}
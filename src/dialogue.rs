@@ -0,0 +1,149 @@
+//! Dialogue lines, which render a speaker, a speech bubble, and what they say.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::dialogue::{Dialogue, Mood};
+//! use mythoji::{Emoji, Gender, Person, SkinTone};
+//!
+//! let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male);
+//! assert_eq!(Dialogue::new(mage, "Hello!").to_string(), "🧙‍♂️ 💬 \"Hello!\"");
+//!
+//! let shout = Dialogue::new(mage, "You shall not pass!").mood(Mood::Shouting);
+//! assert_eq!(shout.to_string(), "🧙‍♂️ 🗯️ \"You shall not pass!\"");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::{Emoji, Qualification, Symbol};
+
+/// A line of dialogue, e.g. `🧙‍♂️ 💬 "Hello!"`.
+///
+/// The speaker, bubble, and quoted text are separated by single spaces. If the text is empty, only
+/// the speaker and bubble are rendered, e.g. to show that a character is about to speak.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dialogue {
+    speaker: Emoji,
+    mood: Mood,
+    text: String,
+    qualification: Qualification,
+}
+
+impl Dialogue {
+    /// Creates a line where the speaker says the given text.
+    pub fn new(speaker: Emoji, text: impl Into<String>) -> Self {
+        Self {
+            speaker,
+            mood: Mood::default(),
+            text: text.into(),
+            qualification: Qualification::Full,
+        }
+    }
+
+    /// Sets the mood of the speaker, which determines the speech bubble.
+    pub fn mood(mut self, mood: Mood) -> Self {
+        self.mood = mood;
+        self
+    }
+
+    /// Displays the speaker with the given [`Qualification`].
+    pub fn qualification(mut self, qualification: Qualification) -> Self {
+        self.qualification = qualification;
+        self
+    }
+
+    /// Returns the speaker.
+    pub fn speaker(&self) -> Emoji {
+        self.speaker
+    }
+
+    /// Returns what the speaker says.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for Dialogue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{} {}",
+            self.speaker.qualified(self.qualification),
+            self.mood.bubble()
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " \"{}\"", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// How a line of [`Dialogue`] is said.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Mood {
+    /// Said normally, e.g. "💬".
+    #[default]
+    Speaking,
+
+    /// Shouted in anger or alarm, e.g. "🗯️".
+    Shouting,
+
+    /// Thought rather than said aloud, i.e. inner monologue, e.g. "💭".
+    Thinking,
+
+    /// Said by a speaker who is not in view, e.g. "🗨️".
+    OffScreen,
+}
+
+impl_all!(Mood, [Speaking, Shouting, Thinking, OffScreen]);
+
+impl Mood {
+    /// Returns the speech bubble for this mood.
+    pub const fn bubble(&self) -> Symbol {
+        match self {
+            Self::Speaking => Symbol::SpeechBubble,
+            Self::Shouting => Symbol::SpeechBubbleAngry,
+            Self::Thinking => Symbol::ThoughtBubble,
+            Self::OffScreen => Symbol::SpeechBubbleLeft,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Gender, Person, SkinTone};
+
+    #[test]
+    fn test_dialogue() {
+        let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female);
+        let line = Dialogue::new(elf, "Over here!").mood(Mood::Shouting);
+        assert_eq!(line.to_string(), "🧝‍♀️ 🗯️ \"Over here!\"");
+        assert_eq!(
+            line.qualification(Qualification::Minimal).to_string(),
+            "🧝‍♀ 🗯️ \"Over here!\""
+        );
+    }
+
+    #[test]
+    fn test_dialogue_moods() {
+        let dragon = Emoji::Creature(Creature::Dragon);
+        let line = Dialogue::new(dragon, "Gold...").mood(Mood::Thinking);
+        assert_eq!(line.to_string(), "🐉 💭 \"Gold...\"");
+        let line = Dialogue::new(dragon, "Who goes there?").mood(Mood::OffScreen);
+        assert_eq!(line.to_string(), "🐉 🗨️ \"Who goes there?\"");
+    }
+
+    #[test]
+    fn test_dialogue_empty() {
+        let line = Dialogue::new(Emoji::Creature(Creature::Dragon), "");
+        assert_eq!(line.to_string(), "🐉 💬");
+        assert_eq!(line.speaker(), Emoji::Creature(Creature::Dragon));
+        assert_eq!(line.text(), "");
+    }
+}
@@ -0,0 +1,80 @@
+//! Conversions to and from the [`emojis`](::emojis) crate, enabled by the `emojis` feature.
+//!
+//! Every emoji in this crate converts into its counterpart in the general-purpose catalog of the
+//! `emojis` crate with [`From`], and back with [`TryFrom`], which fails with [`UnknownEmoji`] for
+//! emojis that this crate does not model.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{Creature, Emoji, Person};
+//!
+//! let dragon: &emojis::Emoji = Emoji::Creature(Creature::Dragon).into();
+//! assert_eq!(dragon.name(), "dragon");
+//!
+//! let elf = emojis::get("🧝‍♀️").unwrap();
+//! assert_eq!(Emoji::try_from(elf), Ok(Person::Elf.female()));
+//!
+//! let rocket = emojis::get("🚀").unwrap();
+//! assert!(Emoji::try_from(rocket).is_err());
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::Emoji;
+
+/// An error returned when converting an emoji that this crate does not model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownEmoji;
+
+impl Display for UnknownEmoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("emoji is not known to this crate")
+    }
+}
+
+impl std::error::Error for UnknownEmoji {}
+
+impl From<Emoji> for &'static ::emojis::Emoji {
+    fn from(value: Emoji) -> Self {
+        ::emojis::get(&value.to_string()).expect("every emoji is in the emojis crate")
+    }
+}
+
+/// Converts an emoji by its glyph, so any qualification of it is accepted.
+///
+/// A person whose glyph does not display a gender (e.g. "🧑‍🎨") is converted with a neutral gender.
+impl TryFrom<&::emojis::Emoji> for Emoji {
+    type Error = UnknownEmoji;
+
+    fn try_from(value: &::emojis::Emoji) -> std::result::Result<Self, Self::Error> {
+        match Emoji::parse_prefix(value.as_str()) {
+            Some((emoji, "")) => Ok(emoji),
+            _ => Err(UnknownEmoji),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gender, Person, SkinTone};
+
+    #[test]
+    fn test_emojis() {
+        for emoji in crate::glyph::all() {
+            let other: &::emojis::Emoji = emoji.into();
+            let emoji = emoji.to_string();
+            assert_eq!(Emoji::try_from(other).map(|e| e.to_string()), Ok(emoji));
+        }
+
+        let mage = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Male);
+        let other: &::emojis::Emoji = mage.into();
+        assert_eq!(other.skin_tone(), Some(::emojis::SkinTone::Dark));
+        assert_eq!(Emoji::try_from(::emojis::get("🧙🏿‍♂").unwrap()), Ok(mage));
+        assert_eq!(
+            Emoji::try_from(::emojis::get("🍕").unwrap()),
+            Err(UnknownEmoji)
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Encounters, which compose a [`Location`], a band of [`Creature`]s, and the [`Item`]s they guard.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::encounter::Encounter;
+//! use mythoji::{Creature, Item, Location};
+//!
+//! let encounter = Encounter {
+//!     location: Location::Desert,
+//!     creatures: vec![Creature::Scorpion, Creature::Scorpion, Creature::Snake],
+//!     items: vec![Item::Jar, Item::GemStone],
+//! };
+//! assert_eq!(encounter.to_string(), "🏜: 🦂🦂🐍 guarding 🏺💎");
+//! ```
+//!
+//! With the `rand` feature, encounters can be generated randomly for a [`Biome`] and
+//! [`Difficulty`]:
+//!
+//! ```
+//! # #[cfg(feature = "rand")]
+//! # {
+//! use mythoji::encounter::{Biome, Difficulty, Encounter};
+//!
+//! let encounter = Encounter::random(Biome::Desert, Difficulty::Hard, &mut rand::thread_rng());
+//! println!("{}", encounter);
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::{Creature, Item, Location};
+
+/// A band of creatures at a location, optionally guarding items.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Encounter {
+    /// Where the encounter takes place.
+    pub location: Location,
+
+    /// The creatures that are encountered.
+    pub creatures: Vec<Creature>,
+
+    /// The items the creatures are guarding, if any.
+    pub items: Vec<Item>,
+}
+
+impl Display for Encounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}:", self.location)?;
+        if !self.creatures.is_empty() {
+            f.write_str(" ")?;
+            for creature in &self.creatures {
+                write!(f, "{}", creature)?;
+            }
+        }
+        if !self.items.is_empty() {
+            f.write_str(" guarding ")?;
+            for item in &self.items {
+                write!(f, "{}", item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Encounter {
+    /// Generates a random encounter in the given biome, with the given difficulty.
+    ///
+    /// Harder encounters have larger bands of (more dangerous) creatures, and guard more items.
+    pub fn random<R: Rng + ?Sized>(biome: Biome, difficulty: Difficulty, rng: &mut R) -> Self {
+        let location = *biome.locations().choose(rng).unwrap();
+
+        // Creatures are ordered from least to most dangerous; easier encounters only draw from the
+        // first few, and the band is sorted in that order so that the same creatures are adjacent.
+        let creatures = biome.creatures();
+        let creatures = &creatures[..difficulty.max_tier().min(creatures.len())];
+        let kinds = rng.gen_range(1..=2);
+        let kinds: Vec<_> = creatures.choose_multiple(rng, kinds).collect();
+        let (min, max) = difficulty.band_size();
+        let mut band: Vec<Creature> = (0..rng.gen_range(min..=max))
+            .map(|_| **kinds.choose(rng).unwrap())
+            .collect();
+        band.sort_by_key(|c| creatures.iter().position(|o| o == c));
+
+        let (min, max) = difficulty.loot_size();
+        let items = (0..rng.gen_range(min..=max))
+            .map(|_| *biome.items().choose(rng).unwrap())
+            .collect();
+
+        Self {
+            location,
+            creatures: band,
+            items,
+        }
+    }
+}
+
+/// The kind of terrain an encounter takes place in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Biome {
+    /// A coast or the open sea.
+    Coast,
+
+    /// An arid desert.
+    Desert,
+
+    /// A forest.
+    Forest,
+
+    /// Mountains and the caves beneath them.
+    Mountain,
+
+    /// Abandoned castles, temples, and palaces.
+    Ruins,
+}
+
+impl_all!(Biome, [Coast, Desert, Forest, Mountain, Ruins]);
+
+impl Biome {
+    /// Returns the locations found in this biome.
+    pub const fn locations(&self) -> &'static [Location] {
+        match self {
+            Self::Coast => &[Location::BoatSail, Location::Canoe, Location::TreePalm],
+            Self::Desert => &[Location::Desert, Location::Oasis],
+            Self::Forest => &[
+                Location::Campsite,
+                Location::Hut,
+                Location::TreeDeciduous,
+                Location::TreeEvergreen,
+            ],
+            Self::Mountain => &[
+                Location::Cave,
+                Location::Mountain,
+                Location::MountainSnow,
+                Location::Volcano,
+            ],
+            Self::Ruins => &[
+                Location::BuildingClassic,
+                Location::Castle,
+                Location::CastleJapanese,
+                Location::Palace,
+            ],
+        }
+    }
+
+    /// Returns the creatures found in this biome, ordered from least to most dangerous.
+    pub const fn creatures(&self) -> &'static [Creature] {
+        match self {
+            Self::Coast => &[
+                Creature::Crab,
+                Creature::Fish,
+                Creature::TropicalFish,
+                Creature::Crocodile,
+                Creature::Shark,
+            ],
+            Self::Desert => &[
+                Creature::Beetle,
+                Creature::Camel,
+                Creature::Snake,
+                Creature::Scorpion,
+                Creature::Leopard,
+            ],
+            Self::Forest => &[
+                Creature::Rabbit,
+                Creature::Fox,
+                Creature::Deer,
+                Creature::Spider,
+                Creature::Boar,
+                Creature::Goblin,
+                Creature::Wolf,
+                Creature::Bear,
+            ],
+            Self::Mountain => &[
+                Creature::Goat,
+                Creature::Bat,
+                Creature::Eagle,
+                Creature::Bear,
+                Creature::Mammoth,
+                Creature::Ogre,
+                Creature::Dragon,
+            ],
+            Self::Ruins => &[
+                Creature::Rat,
+                Creature::Spider,
+                Creature::Bat,
+                Creature::Ghost,
+                Creature::Goblin,
+                Creature::Ogre,
+            ],
+        }
+    }
+
+    /// Returns the items that can be found in this biome.
+    pub const fn items(&self) -> &'static [Item] {
+        match self {
+            Self::Coast => &[Item::Coin, Item::GemStone, Item::Map, Item::Trident],
+            Self::Desert => &[Item::Coin, Item::GemStone, Item::Jar, Item::Scroll],
+            Self::Forest => &[Item::Axe, Item::BowAndArrow, Item::Coin, Item::Map],
+            Self::Mountain => &[Item::Coin, Item::GemStone, Item::HammerAndPick, Item::Pick],
+            Self::Ruins => &[
+                Item::Crown,
+                Item::CrystalBall,
+                Item::GemStone,
+                Item::Key,
+                Item::Scroll,
+            ],
+        }
+    }
+}
+
+/// How dangerous an encounter is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Difficulty {
+    /// A small band of the least dangerous creatures.
+    Easy,
+
+    /// A band of moderately dangerous creatures.
+    #[default]
+    Normal,
+
+    /// A large band of any creatures.
+    Hard,
+
+    /// A very large band of any creatures, guarding plenty of loot.
+    Deadly,
+}
+
+impl_all!(Difficulty, [Easy, Normal, Hard, Deadly]);
+
+impl Difficulty {
+    #[cfg(feature = "rand")]
+    const fn max_tier(&self) -> usize {
+        match self {
+            Self::Easy => 2,
+            Self::Normal => 4,
+            Self::Hard | Self::Deadly => usize::MAX,
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    const fn band_size(&self) -> (usize, usize) {
+        match self {
+            Self::Easy => (1, 2),
+            Self::Normal => (2, 3),
+            Self::Hard => (3, 4),
+            Self::Deadly => (4, 6),
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    const fn loot_size(&self) -> (usize, usize) {
+        match self {
+            Self::Easy => (0, 1),
+            Self::Normal => (1, 2),
+            Self::Hard => (2, 3),
+            Self::Deadly => (3, 4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encounter_display() {
+        let encounter = Encounter {
+            location: Location::Cave,
+            creatures: vec![Creature::Bat, Creature::Bat],
+            items: vec![],
+        };
+        assert_eq!(encounter.to_string(), "🕳: 🦇🦇");
+
+        let encounter = Encounter {
+            location: Location::Castle,
+            creatures: vec![],
+            items: vec![Item::Crown],
+        };
+        assert_eq!(encounter.to_string(), "🏰: guarding 👑");
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_encounter_random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for biome in Biome::ALL {
+            for difficulty in Difficulty::ALL {
+                for _ in 0..20 {
+                    let encounter = Encounter::random(*biome, *difficulty, &mut rng);
+                    let (min, max) = difficulty.band_size();
+                    assert!((min..=max).contains(&encounter.creatures.len()));
+                    let (min, max) = difficulty.loot_size();
+                    assert!((min..=max).contains(&encounter.items.len()));
+
+                    assert!(biome.locations().contains(&encounter.location));
+                    for creature in &encounter.creatures {
+                        let tier = biome.creatures().iter().position(|c| c == creature);
+                        assert!(tier.unwrap() < difficulty.max_tier());
+                    }
+                    for item in &encounter.items {
+                        assert!(biome.items().contains(item));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Game events, which map moments of gameplay to the emojis shown in notifications and logs.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::event::{EventEmojis, GameEvent};
+//!
+//! assert_eq!(GameEvent::LevelUp.to_string(), "✨");
+//!
+//! // A game can override the emoji of any event, and keep the defaults for the rest.
+//! let emojis = EventEmojis::new().with(GameEvent::Death, "🪦");
+//! assert_eq!(emojis.toast(GameEvent::Death, "You died"), "🪦 You died");
+//! assert_eq!(emojis.toast(GameEvent::Rest, "You feel rested"), "💤 You feel rested");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+/// A notable moment of gameplay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum GameEvent {
+    /// A character gained a level, e.g. "✨".
+    LevelUp,
+
+    /// An attack dealt critical damage, e.g. "💥".
+    CriticalHit,
+
+    /// A quest was completed, e.g. "🏆".
+    QuestComplete,
+
+    /// A character died, e.g. "💀".
+    Death,
+
+    /// Something new was found, e.g. "❗".
+    Discovery,
+
+    /// A character rested, e.g. "💤".
+    Rest,
+}
+
+impl_all!(
+    GameEvent,
+    [LevelUp, CriticalHit, QuestComplete, Death, Discovery, Rest]
+);
+
+impl GameEvent {
+    /// Returns the default fully-qualified emoji that represents this event.
+    ///
+    /// Leveling up, discoveries, and resting share the glyph of a symbol, e.g.
+    /// [`Symbol::Sparkles`](crate::Symbol::Sparkles), but critical hits ("💥"), completed quests
+    /// ("🏆"), and deaths ("💀") have no variant in this crate's catalog.
+    pub const fn emoji(&self) -> &'static str {
+        match self {
+            Self::LevelUp => "✨",
+            Self::CriticalHit => "💥",
+            Self::QuestComplete => "🏆",
+            Self::Death => "💀",
+            Self::Discovery => "❗",
+            Self::Rest => "💤",
+        }
+    }
+}
+
+impl Display for GameEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.emoji())
+    }
+}
+
+/// The emojis a game uses for each [`GameEvent`], which are the defaults unless overridden.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventEmojis {
+    overrides: HashMap<GameEvent, String>,
+}
+
+impl EventEmojis {
+    /// Creates a set of emojis that uses the default emoji of every event.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the given emoji (or any text) for the given event, instead of its default.
+    pub fn with(mut self, event: GameEvent, emoji: impl Display) -> Self {
+        self.overrides.insert(event, emoji.to_string());
+        self
+    }
+
+    /// Returns the emoji used for the given event.
+    pub fn get(&self, event: GameEvent) -> &str {
+        self.overrides
+            .get(&event)
+            .map_or(event.emoji(), String::as_str)
+    }
+
+    /// Returns a notification for the given event, e.g. "✨ Level 2!".
+    pub fn toast(&self, event: GameEvent, message: &str) -> String {
+        format!("{} {}", self.get(event), message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Symbol;
+
+    #[test]
+    fn test_game_event() {
+        assert_eq!(GameEvent::LevelUp.to_string(), Symbol::Sparkles.to_string());
+        assert_eq!(GameEvent::Rest.to_string(), Symbol::Zzz.to_string());
+        assert_eq!(
+            GameEvent::Discovery.to_string(),
+            Symbol::ExclamationRed.to_string()
+        );
+        assert_eq!(GameEvent::CriticalHit.to_string(), "💥");
+        assert_eq!(GameEvent::QuestComplete.to_string(), "🏆");
+        assert_eq!(GameEvent::Death.to_string(), "💀");
+    }
+
+    #[test]
+    fn test_event_emojis() {
+        let emojis = EventEmojis::new()
+            .with(GameEvent::CriticalHit, Symbol::Electricity)
+            .with(GameEvent::QuestComplete, "🎉");
+        assert_eq!(emojis.get(GameEvent::CriticalHit), "⚡");
+        assert_eq!(emojis.get(GameEvent::QuestComplete), "🎉");
+        assert_eq!(emojis.get(GameEvent::LevelUp), "✨");
+        assert_eq!(
+            emojis.toast(GameEvent::Discovery, "A secret door"),
+            "❗ A secret door"
+        );
+    }
+}
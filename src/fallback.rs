@@ -0,0 +1,301 @@
+//! Fallbacks, which render emojis as plain text for terminals that cannot display them.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::fallback::Fallback;
+//! use mythoji::{Creature, Emoji, Location};
+//!
+//! let dragon = Emoji::Creature(Creature::Dragon);
+//! assert_eq!(dragon.fallback(Fallback::Ascii), "D");
+//!
+//! let castle = Emoji::Location(Location::Castle);
+//! assert_eq!(castle.fallback(Fallback::AsciiArt), "|^|^|^|\n|  _  |\n|_| |_|");
+//! assert_eq!(castle.fallback(Fallback::Name), "[castle]");
+//! ```
+//!
+//! Games can supply their own substitutes by implementing [`FallbackProvider`], e.g. for a
+//! [`Renderer`](crate::render::Renderer):
+//!
+//! ```
+//! use std::borrow::Cow;
+//!
+//! use mythoji::fallback::{Fallback, FallbackProvider};
+//! use mythoji::render::Renderer;
+//! use mythoji::{Creature, Emoji};
+//!
+//! struct Wyverns;
+//!
+//! impl FallbackProvider for Wyverns {
+//!     fn fallback(&self, emoji: Emoji) -> Cow<'static, str> {
+//!         match emoji {
+//!             Emoji::Creature(Creature::Dragon) => Cow::Borrowed("W"),
+//!             _ => Fallback::Ascii.fallback(emoji),
+//!         }
+//!     }
+//! }
+//!
+//! let renderer = Renderer::text(Wyverns);
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Dragon)), "W");
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Goblin)), "G");
+//! ```
+
+use std::borrow::Cow;
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::metadata::kebab_case;
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+/// How an emoji is rendered as plain text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Fallback {
+    /// A single ASCII character, the first letter of the emoji's name, e.g. "D" for a dragon.
+    #[default]
+    Ascii,
+
+    /// Multi-line ASCII art (see [`Location::ascii_art`] and [`Creature::ascii_art`]), for purely
+    /// ASCII terminals where a single character loses too much flavor.
+    ///
+    /// Emojis without art fall back to [`Fallback::Ascii`].
+    AsciiArt,
+
+    /// The kebab-case name of the emoji in brackets, e.g. "[castle]", for deterministic and
+    /// readable output in logs, tests, and screen readers.
+    ///
+    /// Persons are followed by the skin tone and gender they display, if any, separated by colons,
+    /// e.g. "[mage]" or "[elf:dark:female]".
+    Name,
+
+    /// A single-width Unicode text symbol (see [`Emoji::text_symbol`]), e.g. "♞" for a horse, for
+    /// terminals with good Unicode support but poor emoji coverage.
+    ///
+    /// Emojis without a symbol fall back to [`Fallback::Ascii`].
+    TextSymbol,
+}
+
+impl_all!(Fallback, [Ascii, AsciiArt, Name, TextSymbol]);
+
+/// Renders emojis as plain text, when they are not supported.
+pub trait FallbackProvider {
+    /// Returns the given emoji rendered as plain text.
+    fn fallback(&self, emoji: Emoji) -> Cow<'static, str>;
+
+    /// Returns an emoji that is not defined by this crate rendered as plain text, given its
+    /// `PascalCase` name and its plain text fallback, e.g. a custom emoji of an emoji pack.
+    ///
+    /// By default, this is the plain text fallback.
+    fn fallback_custom<'a>(&self, name: &'a str, text: &'a str) -> Cow<'a, str> {
+        let _ = name;
+        Cow::Borrowed(text)
+    }
+}
+
+impl FallbackProvider for Fallback {
+    fn fallback(&self, emoji: Emoji) -> Cow<'static, str> {
+        emoji.fallback(*self)
+    }
+
+    fn fallback_custom<'a>(&self, name: &'a str, text: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Name => Cow::Owned(format!("[{}]", kebab_case(name))),
+            Self::Ascii | Self::AsciiArt | Self::TextSymbol => Cow::Borrowed(text),
+        }
+    }
+}
+
+impl Emoji {
+    /// Returns this emoji rendered as plain text, in the given [`Fallback`] style.
+    pub fn fallback(&self, fallback: Fallback) -> Cow<'static, str> {
+        if fallback == Fallback::Name {
+            return Cow::Owned(self.fallback_name());
+        }
+        if let (Fallback::TextSymbol, Some(symbol)) = (fallback, self.text_symbol()) {
+            return Cow::Borrowed(symbol);
+        }
+        let art = match self {
+            Self::Creature(creature) => creature.ascii_art(),
+            Self::Location(location) => location.ascii_art(),
+            Self::Person(..)
+            | Self::Item(_)
+            | Self::Flora(_)
+            | Self::BodyPart(_)
+            | Self::Symbol(_) => None,
+        };
+        match (fallback, art) {
+            (Fallback::AsciiArt, Some(art)) => Cow::Owned(art.join("\n")),
+            _ => Cow::Borrowed(&self.metadata().name[..1]),
+        }
+    }
+
+    /// Returns a single-width Unicode text symbol that resembles this emoji, if any, e.g. "♜" for a
+    /// castle.
+    ///
+    /// Symbols that also have an emoji presentation are followed by [`VS15`], which requests text
+    /// presentation, so every symbol is one terminal column wide.
+    ///
+    /// [`VS15`]: crate::components::VS15
+    pub const fn text_symbol(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Person(Person::Royalty, ..) => "♚",
+            Self::Creature(Creature::Horse) => "♞",
+            Self::Location(Location::Castle) => "♜",
+            Self::Location(Location::Hut) => "⌂",
+            Self::Location(Location::Mountain) => "▲",
+            Self::Location(Location::MountainSnow) => "△",
+            Self::Location(Location::TreeDeciduous) => "♣\u{fe0e}",
+            Self::Location(Location::TreeEvergreen) => "↟",
+            Self::Item(Item::Coffin) => "⚰\u{fe0e}",
+            Self::Item(Item::Coin) => "¤",
+            Self::Item(Item::Crown) => "♛",
+            Self::Item(Item::Dagger) => "†",
+            Self::Item(Item::GemStone) => "♦\u{fe0e}",
+            Self::Item(Item::Grave) => "✝\u{fe0e}",
+            Self::Item(Item::HammerAndPick) => "⚒\u{fe0e}",
+            Self::Item(Item::HeartRed) => "♥\u{fe0e}",
+            Self::Item(Item::HourglassDone) => "⧗",
+            Self::Item(Item::HourglassNotDone) => "⧖",
+            Self::Item(Item::Key) => "⚷",
+            Self::Item(Item::SwordsCrossed) => "⚔\u{fe0e}",
+            Self::Item(Item::Trident) => "♆",
+            Self::Item(Item::Urn) => "⚱\u{fe0e}",
+            Self::Flora(Flora::Clover) => "☘\u{fe0e}",
+            Self::Flora(Flora::Rose) => "✿",
+            Self::Flora(Flora::Sunflower) => "❀",
+            Self::BodyPart(BodyPart::Eye) => "◉",
+            Self::Symbol(Symbol::GenderFemale) => "♀\u{fe0e}",
+            Self::Symbol(Symbol::GenderMale) => "♂\u{fe0e}",
+            Self::Symbol(Symbol::Snowflake) => "❄\u{fe0e}",
+            _ => return None,
+        })
+    }
+
+    fn fallback_name(&self) -> String {
+        let mut name = format!("[{}", kebab_case(self.metadata().name));
+        if let Self::Person(person, skin_tone, gender) = self {
+            if person.supports_skin_tone() && *skin_tone != SkinTone::Neutral {
+                name.push(':');
+                name.push_str(&kebab_case(&format!("{:?}", skin_tone)));
+            }
+            if person.supports_gender() && *gender != Gender::Neutral {
+                name.push(':');
+                name.push_str(&kebab_case(&format!("{:?}", gender)));
+            }
+        }
+        name.push(']');
+        name
+    }
+}
+
+impl Location {
+    /// Returns a drawing of this location as lines of ASCII art, if any.
+    ///
+    /// Every line of a drawing has the same width.
+    pub const fn ascii_art(&self) -> Option<&'static [&'static str]> {
+        Some(match self {
+            Self::BoatSail => &[r"   |\  ", r"   |_\ ", r"\_____/"],
+            Self::BuildingClassic => &[r" /___\ ", r"| | | |", r"======="],
+            Self::Campsite => &[r" ^  /\ ", r"/|\/  \", r" | /__\"],
+            Self::Canoe => &[r"    /  ", r"___/__ ", r"\_____/"],
+            Self::Castle => &[r"|^|^|^|", r"|  _  |", r"|_| |_|"],
+            Self::CastleJapanese => &[r" _/^\_ ", r"/_/^\_\", r" |_|_| "],
+            Self::Cave => &[r"  ___  ", r" / _ \ ", r"/_/ \_\"],
+            Self::Desert => &[r" \ | / ", r"  (o)  ", r"_._._._"],
+            Self::Hut => &[r"  _^_  ", r" /___\ ", r" |_n_| "],
+            Self::Mountain => &[r"   /\  ", r"  /  \ ", r" /    \"],
+            Self::MountainSnow => &[r"   /\  ", r"  /**\ ", r" /    \"],
+            Self::Oasis => &[r"_\ | /_", r"   |  ~", r"~~~~~~~"],
+            Self::Palace => &[r"  _|_  ", r" /___\ ", r"|_|_|_|"],
+            Self::Tent => &[r"   ^   ", r"  /|\  ", r" /_|_\ "],
+            Self::TreeDeciduous => &[r" (   ) ", r"(_____)", r"   |   "],
+            Self::TreeEvergreen => &[r"  /_\  ", r" /___\ ", r"   |   "],
+            Self::TreeLeafless => &[r" \ | / ", r"  \|/  ", r"   |   "],
+            Self::TreePalm => &[r"_\ | /_", r"   |   ", r"  _|_  "],
+            Self::Volcano => &[r"  ( )  ", r"  /^\  ", r" /   \ "],
+        })
+    }
+}
+
+impl Creature {
+    /// Returns a drawing of this creature as lines of ASCII art, if it is large enough to have one.
+    ///
+    /// Every line of a drawing has the same width.
+    pub const fn ascii_art(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Bear => Some(&[r" ()_() ", r" (o o) ", r" (_Y_) "]),
+            Self::Dragon => Some(&[r"/\  /\ ", r" \oo/ ~", r" /VV\_/"]),
+            Self::Elephant => Some(&[r"  __   ", r" /o \__", r"/ /|_| "]),
+            Self::Ghost => Some(&[r"  .-.  ", r" (o o) ", r" |^^^| "]),
+            Self::Mammoth => Some(&[r"  __   ", r" /o \__", r"(( |_| "]),
+            Self::Ogre => Some(&[r" }___{ ", r" (o o) ", r"  \=/  "]),
+            Self::Shark => Some(&[r"   |\  ", r"___|_\_", r"~~~~~~~"]),
+            Self::Whale => Some(&[r"   '   ", r" (___o)", r"~~~~~~~"]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::width;
+
+    #[test]
+    fn test_fallback_ascii() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Female);
+        assert_eq!(mage.fallback(Fallback::Ascii), "M");
+        assert_eq!(mage.fallback(Fallback::AsciiArt), "M");
+        assert_eq!(Emoji::Item(Item::Coin).fallback(Fallback::AsciiArt), "C");
+        assert_eq!(
+            Emoji::Creature(Creature::Wolf).fallback(Fallback::AsciiArt),
+            "W"
+        );
+    }
+
+    #[test]
+    fn test_fallback_name() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Neutral);
+        assert_eq!(mage.fallback(Fallback::Name), "[mage]");
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(elf.fallback(Fallback::Name), "[elf:dark:female]");
+        let zombie = Emoji::Person(Person::Zombie, SkinTone::Light, Gender::Male);
+        assert_eq!(zombie.fallback(Fallback::Name), "[zombie:male]");
+        let artist = Emoji::Person(Person::Artist, SkinTone::MediumLight, Gender::Female);
+        assert_eq!(artist.fallback(Fallback::Name), "[artist:medium-light]");
+        let tree = Emoji::Location(Location::TreeEvergreen);
+        assert_eq!(tree.fallback(Fallback::Name), "[tree-evergreen]");
+    }
+
+    #[test]
+    fn test_fallback_text_symbol() {
+        let castle = Emoji::Location(Location::Castle);
+        assert_eq!(castle.fallback(Fallback::TextSymbol), "♜");
+        let king = Emoji::Person(Person::Royalty, SkinTone::Dark, Gender::Male);
+        assert_eq!(king.fallback(Fallback::TextSymbol), "♚");
+        let wolf = Emoji::Creature(Creature::Wolf);
+        assert_eq!(wolf.fallback(Fallback::TextSymbol), "W");
+
+        for emoji in crate::glyph::bases() {
+            if let Some(symbol) = emoji.text_symbol() {
+                assert_eq!(symbol.trim_end_matches('\u{fe0e}').chars().count(), 1);
+                assert_eq!(width(symbol), 1, "{:?}", emoji);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii_art() {
+        let creatures = Creature::ALL.iter().filter_map(Creature::ascii_art);
+        let locations = Location::ALL.iter().filter_map(Location::ascii_art);
+        for art in creatures.chain(locations) {
+            assert!(!art.is_empty());
+            for line in art {
+                assert!(line.is_ascii(), "{:?}", art);
+                assert_eq!(line.len(), art[0].len(), "{:?}", art);
+            }
+        }
+    }
+}
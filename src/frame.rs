@@ -0,0 +1,141 @@
+//! Frames, which draw a box around content with box-drawing characters and emoji accents.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::frame::Frame;
+//! use mythoji::{Emoji, Location};
+//!
+//! let menu = Frame::new("New game\nContinue").corner(Emoji::Location(Location::Castle));
+//! assert_eq!(
+//!     menu.to_string(),
+//!     "🏰────────🏰\n│ New game │\n│ Continue │\n🏰────────🏰",
+//! );
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{width::width, Emoji};
+
+/// A box drawn around one or more lines of content.
+///
+/// Corners and accents are two columns wide, so each side of the frame takes up two columns: a
+/// vertical line and a space. Content is measured in terminal columns, so it may contain emojis
+/// and other double-width characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    content: String,
+    corner: Option<Emoji>,
+    accent: Option<Emoji>,
+    padding: usize,
+}
+
+impl Frame {
+    /// Creates a frame around the given content, which may contain multiple lines.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            corner: None,
+            accent: None,
+            padding: 0,
+        }
+    }
+
+    /// Draws the given emoji at each corner, instead of box-drawing corners.
+    pub fn corner(mut self, corner: Emoji) -> Self {
+        self.corner = Some(corner);
+        self
+    }
+
+    /// Draws the given emoji in the middle of the top and bottom edges.
+    pub fn accent(mut self, accent: Emoji) -> Self {
+        self.accent = Some(accent);
+        self
+    }
+
+    /// Adds the given number of columns of space on both sides of the content.
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the width of the frame in terminal columns.
+    pub fn width(&self) -> usize {
+        self.inner_width() + 4
+    }
+
+    fn inner_width(&self) -> usize {
+        let content = self.content.lines().map(width).max().unwrap_or(0);
+        let inner = content + self.padding * 2;
+        if self.accent.is_some() {
+            inner.max(2)
+        } else {
+            inner
+        }
+    }
+
+    fn fmt_edge(&self, f: &mut Formatter<'_>, left: &str, right: &str) -> Result {
+        let inner = self.inner_width();
+        match self.corner {
+            Some(corner) => write!(f, "{}", corner)?,
+            None => write!(f, "{}─", left)?,
+        }
+        match self.accent {
+            Some(accent) => {
+                let before = (inner - 2) / 2;
+                let after = inner - 2 - before;
+                write!(f, "{}{}{}", "─".repeat(before), accent, "─".repeat(after))?;
+            }
+            None => f.write_str(&"─".repeat(inner))?,
+        }
+        match self.corner {
+            Some(corner) => write!(f, "{}", corner),
+            None => write!(f, "─{}", right),
+        }
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let inner = self.inner_width();
+        let padding = " ".repeat(self.padding);
+        self.fmt_edge(f, "┌", "┐")?;
+        for line in self.content.lines() {
+            let fill = " ".repeat(inner - self.padding * 2 - width(line));
+            write!(f, "\n│ {}{}{}{} │", padding, line, fill, padding)?;
+        }
+        f.write_str("\n")?;
+        self.fmt_edge(f, "└", "┘")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Item};
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::new("🐉 Dragon\nHP 10").padding(1);
+        assert_eq!(frame.width(), 15);
+        assert_eq!(
+            frame.to_string(),
+            "┌─────────────┐\n│  🐉 Dragon  │\n│  HP 10      │\n└─────────────┘"
+        );
+    }
+
+    #[test]
+    fn test_frame_accent() {
+        let frame = Frame::new("You died")
+            .corner(Emoji::Creature(Creature::Ghost))
+            .accent(Emoji::Item(Item::Coffin));
+        assert_eq!(
+            frame.to_string(),
+            "👻───⚰️───👻\n│ You died │\n👻───⚰️───👻"
+        );
+
+        let empty = Frame::new("").accent(Emoji::Item(Item::Coffin));
+        assert_eq!(empty.width(), 6);
+        assert_eq!(empty.to_string(), "┌─⚰️─┐\n└─⚰️─┘");
+    }
+}
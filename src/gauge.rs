@@ -0,0 +1,176 @@
+//! Gauges, which render several resource bars on one line, e.g. health, mana, and stamina.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::gauge::{Bar, Gauge};
+//!
+//! let gauge = Gauge::new()
+//!     .bar(Bar::health(60, 100))
+//!     .bar(Bar::mana(1, 4).width(4))
+//!     .bar(Bar::stamina(10, 10).width(3));
+//! assert_eq!(gauge.to_string(), "❤️❤️❤️🖤🖤 💧⚪⚪⚪ ⚡⚡⚡");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::heart::Heart;
+use crate::magnitude::Magnitude;
+use crate::{Item, Symbol};
+
+/// A single resource bar, e.g. "❤️❤️❤️🖤🖤".
+///
+/// The bar is a number of cells, where filled cells are scaled to the value as by [`Magnitude`], so
+/// any value above zero fills at least one cell, and the rest are empty.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bar {
+    filled: String,
+    empty: String,
+    value: u32,
+    max: u32,
+    width: u32,
+}
+
+impl Bar {
+    /// Creates a bar of `value` out of `max`, rendered with 5 cells of the given glyphs.
+    pub fn new(filled: impl Display, empty: impl Display, value: u32, max: u32) -> Self {
+        Self {
+            filled: filled.to_string(),
+            empty: empty.to_string(),
+            value,
+            max,
+            width: 5,
+        }
+    }
+
+    /// Creates a health bar, e.g. "❤️❤️❤️🖤🖤".
+    pub fn health(value: u32, max: u32) -> Self {
+        Self::new(Heart::Red, Heart::Black, value, max)
+    }
+
+    /// Creates a mana bar, e.g. "💧💧💧⚪⚪".
+    pub fn mana(value: u32, max: u32) -> Self {
+        Self::new(Item::WaterDrop, "⚪", value, max)
+    }
+
+    /// Creates a stamina bar, e.g. "⚡⚡⚡⚪⚪".
+    pub fn stamina(value: u32, max: u32) -> Self {
+        Self::new(Symbol::Electricity, "⚪", value, max)
+    }
+
+    /// Renders the bar with the given number of cells.
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the number of filled cells.
+    pub fn filled(&self) -> u32 {
+        Magnitude::new(&self.filled, self.value, self.max)
+            .max(self.width)
+            .count()
+    }
+}
+
+impl Display for Bar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let filled = self.filled();
+        for _ in 0..filled {
+            f.write_str(&self.filled)?;
+        }
+        for _ in filled..self.width {
+            f.write_str(&self.empty)?;
+        }
+        Ok(())
+    }
+}
+
+/// Several resource [`Bar`]s on one line, e.g. "❤️❤️❤️🖤🖤 💧💧⚪⚪⚪".
+///
+/// Bars are rendered in order, separated by a single space unless configured otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gauge {
+    bars: Vec<Bar>,
+    separator: String,
+}
+
+impl Default for Gauge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gauge {
+    /// Creates a gauge without bars.
+    pub fn new() -> Self {
+        Self {
+            bars: Vec::new(),
+            separator: " ".to_string(),
+        }
+    }
+
+    /// Adds a bar after the existing bars.
+    pub fn bar(mut self, bar: Bar) -> Self {
+        self.bars.push(bar);
+        self
+    }
+
+    /// Separates bars with the given text, e.g. `" | "`.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns the bars.
+    pub fn bars(&self) -> &[Bar] {
+        &self.bars
+    }
+
+    /// Returns the width of the gauge in terminal columns, e.g. to align it in a status line.
+    pub fn width(&self) -> usize {
+        crate::width::width(&self.to_string())
+    }
+}
+
+impl Display for Gauge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, bar) in self.bars.iter().enumerate() {
+            if i > 0 {
+                f.write_str(&self.separator)?;
+            }
+            write!(f, "{}", bar)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::width;
+
+    #[test]
+    fn test_bar() {
+        assert_eq!(Bar::health(0, 100).to_string(), "🖤🖤🖤🖤🖤");
+        assert_eq!(Bar::health(1, 100).to_string(), "❤️🖤🖤🖤🖤");
+        assert_eq!(Bar::mana(150, 100).width(2).to_string(), "💧💧");
+        assert_eq!(Bar::stamina(5, 0).filled(), 0);
+        assert_eq!(Bar::new("#", "-", 3, 4).width(4).to_string(), "###-");
+
+        for bar in [Bar::health(1, 2), Bar::mana(1, 2), Bar::stamina(1, 2)] {
+            assert_eq!(width(&bar.to_string()), 10, "{:?}", bar);
+        }
+    }
+
+    #[test]
+    fn test_gauge() {
+        let gauge = Gauge::new()
+            .bar(Bar::health(2, 2).width(2))
+            .bar(Bar::mana(0, 2).width(2))
+            .separator(" | ");
+        assert_eq!(gauge.to_string(), "❤️❤️ | ⚪⚪");
+        assert_eq!(gauge.width(), 11);
+        assert_eq!(gauge.bars().len(), 2);
+        assert_eq!(Gauge::default().to_string(), "");
+    }
+}
@@ -0,0 +1,657 @@
+//! Compares, parses, and encodes emojis by their glyphs, e.g. to check user input.
+
+use std::fmt::Write;
+
+use crate::components::VS16;
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+/// Returns every emoji, in declaration order, including every skin tone and gender of each person.
+pub(crate) fn all() -> impl Iterator<Item = Emoji> {
+    let persons = Person::ALL.iter().flat_map(|person| {
+        SkinTone::ALL.iter().flat_map(move |skin| {
+            Gender::ALL
+                .iter()
+                .map(move |gender| Emoji::Person(*person, *skin, *gender))
+        })
+    });
+    persons
+        .chain(Creature::ALL.iter().copied().map(Emoji::Creature))
+        .chain(Location::ALL.iter().copied().map(Emoji::Location))
+        .chain(Item::ALL.iter().copied().map(Emoji::Item))
+        .chain(Flora::ALL.iter().copied().map(Emoji::Flora))
+        .chain(BodyPart::ALL.iter().copied().map(Emoji::BodyPart))
+        .chain(Symbol::ALL.iter().copied().map(Emoji::Symbol))
+}
+
+/// Returns every emoji, in declaration order, with persons only in a neutral skin tone and gender.
+pub(crate) fn bases() -> impl Iterator<Item = Emoji> {
+    all().filter(|emoji| match emoji {
+        Emoji::Person(_, skin, gender) => skin == &SkinTone::Neutral && gender == &Gender::Neutral,
+        _ => true,
+    })
+}
+
+/// Returns whether two glyphs are the same emoji, ignoring variation selector 16.
+///
+/// Terminals, keyboards, and other libraries disagree on whether to include the variation selector,
+/// e.g. "⚔" and "⚔️", so it is not significant when comparing user input.
+pub(crate) fn glyph_eq(a: &str, b: &str) -> bool {
+    let a = a.chars().filter(|&c| c != VS16);
+    let b = b.chars().filter(|&c| c != VS16);
+    a.eq(b)
+}
+
+/// Returns the rest of the input after the given glyph, ignoring variation selector 16.
+fn strip_glyph<'a>(input: &'a str, glyph: &str) -> Option<&'a str> {
+    let mut rest = input;
+    for c in glyph.chars().filter(|&c| c != VS16) {
+        rest = rest.strip_prefix(c)?;
+        rest = rest.strip_prefix(VS16).unwrap_or(rest);
+    }
+    Some(rest)
+}
+
+impl Emoji {
+    /// The maximum length of any glyph in UTF-8, for buffers passed to [`Emoji::encode_utf8`].
+    pub const MAX_UTF8_LEN: usize = 32;
+
+    /// Returns the UTF-8 bytes of the glyph, if it is not composed from a person and modifiers.
+    ///
+    /// A person is only composed if it has a skin tone that it supports, or a gender that is joined
+    /// to it by a gender sign (e.g. "🧝‍♀️", but not "👩"). Composed glyphs can be encoded into a
+    /// buffer without allocating by [`Emoji::encode_utf8`].
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji, Gender, Person, SkinTone};
+    ///
+    /// assert_eq!(Emoji::Creature(Creature::Dragon).as_bytes(), Some("🐉".as_bytes()));
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female);
+    /// assert_eq!(elf.as_bytes(), None);
+    ///
+    /// let mut buffer = [0; Emoji::MAX_UTF8_LEN];
+    /// assert_eq!(elf.encode_utf8(&mut buffer), "🧝‍♀️");
+    /// ```
+    pub const fn as_bytes(&self) -> Option<&'static [u8]> {
+        match self {
+            Emoji::Person(person, skin, gender) => {
+                let skinned = !matches!(skin, SkinTone::Neutral) && person.supports_skin_tone();
+                let gendered = !matches!(gender, Gender::Neutral) && person.supports_gender();
+                if skinned {
+                    None
+                } else if gendered {
+                    // Generic persons are gendered by a distinct codepoint, so are not composed.
+                    match person.gendered_glyph(gender) {
+                        Some(glyph) => Some(glyph.as_bytes()),
+                        None => None,
+                    }
+                } else {
+                    Some(person.as_bytes())
+                }
+            }
+            Emoji::Creature(creature) => Some(creature.as_bytes()),
+            Emoji::Location(location) => Some(location.as_bytes()),
+            Emoji::Item(item) => Some(item.as_bytes()),
+            Emoji::Flora(flora) => Some(flora.as_bytes()),
+            Emoji::BodyPart(body_part) => Some(body_part.as_bytes()),
+            Emoji::Symbol(symbol) => Some(symbol.as_bytes()),
+        }
+    }
+
+    /// Encodes the glyph as UTF-8 into the given buffer, and returns the encoded part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is too small, which it never is if it has [`Emoji::MAX_UTF8_LEN`]
+    /// bytes.
+    pub fn encode_utf8<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        let mut cursor = Cursor { buffer, len: 0 };
+        write!(cursor, "{}", self).expect("buffer is large enough for the glyph");
+        let Cursor { buffer, len } = cursor;
+        std::str::from_utf8(&buffer[..len]).expect("glyph is valid UTF-8")
+    }
+
+    /// Writes the glyph into the given [`fmt::Write`](std::fmt::Write) sink, without allocating.
+    ///
+    /// This is the same as [`Display`](std::fmt::Display), but can be called without formatting
+    /// machinery at the call site, e.g. by custom renderers that write into their own buffers.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let mut line = String::from("Beware the ");
+    /// Emoji::Creature(Creature::Dragon).write_to(&mut line).unwrap();
+    /// assert_eq!(line, "Beware the 🐉");
+    /// ```
+    pub fn write_to<W: Write + ?Sized>(&self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+
+    /// Writes the glyph as UTF-8 into the given [`io::Write`](std::io::Write) sink, without
+    /// allocating, e.g. into a socket or a file.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let mut bytes = Vec::new();
+    /// Emoji::Creature(Creature::Dragon).write_to_io(&mut bytes).unwrap();
+    /// assert_eq!(bytes, "🐉".as_bytes());
+    /// ```
+    pub fn write_to_io<W: std::io::Write + ?Sized>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_fmt(format_args!("{}", self))
+    }
+
+    /// Returns the emoji whose glyph is the given codepoint, if there is exactly one.
+    ///
+    /// Variation selectors are ignored, and persons are returned with a neutral skin tone and
+    /// gender. Codepoints that are shared by multiple emojis, e.g. [`Location::Desert`] and
+    /// [`Location::Oasis`], are ambiguous, so `None` is returned.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// assert_eq!(Emoji::from_codepoint(0x1F409), Some(Emoji::Creature(Creature::Dragon)));
+    /// assert_eq!(Emoji::from_codepoint(u32::from('a')), None);
+    /// ```
+    pub fn from_codepoint(codepoint: u32) -> Option<Emoji> {
+        let (mut c, mut buffer) = ([0; 4], [0; Self::MAX_UTF8_LEN]);
+        let glyph = char::from_u32(codepoint)?.encode_utf8(&mut c);
+        unambiguous(bases().filter(|emoji| glyph_eq(emoji.encode_utf8(&mut buffer), glyph)))
+    }
+
+    /// Parses the longest emoji at the start of the input, and returns it with the rest of the
+    /// input.
+    ///
+    /// Variation selectors are optional, as in comparisons with strings. If multiple emojis share a
+    /// glyph, e.g. [`Location::Desert`] and [`Location::Oasis`], the first declared is returned.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji, Gender, Person, SkinTone};
+    ///
+    /// let (emoji, rest) = Emoji::parse_prefix("🐉 attack").unwrap();
+    /// assert_eq!(emoji, Emoji::Creature(Creature::Dragon));
+    /// assert_eq!(rest, " attack");
+    ///
+    /// let (emoji, rest) = Emoji::parse_prefix("🧝🏿‍♀️🧝").unwrap();
+    /// assert_eq!(emoji, Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female));
+    /// assert_eq!(rest, "🧝");
+    ///
+    /// assert_eq!(Emoji::parse_prefix("attack 🐉"), None);
+    /// ```
+    pub fn parse_prefix(input: &str) -> Option<(Emoji, &str)> {
+        let mut longest: Option<(Emoji, &str)> = None;
+        let mut buffer = [0; Self::MAX_UTF8_LEN];
+        for emoji in all() {
+            let Some(rest) = strip_glyph(input, emoji.encode_utf8(&mut buffer)) else {
+                continue;
+            };
+            if longest.is_none_or(|(_, longest)| rest.len() < longest.len()) {
+                longest = Some((emoji, rest));
+            }
+        }
+        longest
+    }
+
+    /// Returns the emoji with the given glyph, e.g. as written to a save file or a chat log.
+    ///
+    /// The glyph is parsed as displayed, including ZWJ sequences and skin tone modifiers, and
+    /// variation selectors are optional, as in comparisons with strings. If multiple emojis share a
+    /// glyph, e.g. a person with a skin tone it does not support, the first declared is returned.
+    ///
+    /// Returns `None` if the input is not exactly one emoji.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji, Gender, Person, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Medium, Gender::Female);
+    /// assert_eq!(Emoji::from_glyph("🧝🏽‍♀️"), Some(elf));
+    /// assert_eq!(Emoji::from_glyph(&elf.to_string()), Some(elf));
+    ///
+    /// assert_eq!(Emoji::from_glyph("🐉"), Some(Emoji::Creature(Creature::Dragon)));
+    /// assert_eq!(Emoji::from_glyph("🐉🐉"), None);
+    /// ```
+    pub fn from_glyph(glyph: &str) -> Option<Emoji> {
+        find(glyph)
+    }
+
+    /// Returns the UTF-16 code units of the glyph, including surrogate pairs, e.g. for JavaScript.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let dragon = Emoji::Creature(Creature::Dragon);
+    /// assert_eq!(dragon.utf16_units(), [0xD83D, 0xDC09]);
+    /// assert_eq!(String::from_utf16(&dragon.utf16_units()).unwrap(), "🐉");
+    /// ```
+    pub fn utf16_units(&self) -> Vec<u16> {
+        self.to_string().encode_utf16().collect()
+    }
+
+    /// Returns the glyph as Rust and JavaScript escape sequences, e.g. `\u{1F9D9}\u{200D}\u{2642}`.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Item};
+    ///
+    /// let swords = Emoji::Item(Item::SwordsCrossed);
+    /// assert_eq!(swords.to_unicode_escape(), r"\u{2694}\u{FE0F}");
+    /// assert_eq!(Emoji::from_unicode_escape(r"\u{2694}\u{fe0f}"), Some(swords));
+    /// ```
+    pub fn to_unicode_escape(&self) -> String {
+        self.to_string()
+            .chars()
+            .map(|c| format!("\\u{{{:X}}}", u32::from(c)))
+            .collect()
+    }
+
+    /// Returns the emoji with the given escaped glyph, in the format of
+    /// [`Emoji::to_unicode_escape`].
+    ///
+    /// Hexadecimal digits are case insensitive, and variation selectors are optional.
+    pub fn from_unicode_escape(escaped: &str) -> Option<Emoji> {
+        let mut glyph = String::new();
+        let mut rest = escaped;
+        while !rest.is_empty() {
+            let (hex, tail) = rest.strip_prefix("\\u{")?.split_once('}')?;
+            glyph.push(parse_codepoint(hex)?);
+            rest = tail;
+        }
+        find(&glyph)
+    }
+
+    /// Returns the codepoints of the glyph in Unicode notation, e.g. `U+1F9D9 U+200D U+2642`.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Item};
+    ///
+    /// let swords = Emoji::Item(Item::SwordsCrossed);
+    /// assert_eq!(swords.to_codepoint_string(), "U+2694 U+FE0F");
+    /// assert_eq!(Emoji::from_codepoint_string("U+2694"), Some(swords));
+    /// ```
+    pub fn to_codepoint_string(&self) -> String {
+        let codepoints: Vec<_> = self
+            .to_string()
+            .chars()
+            .map(|c| format!("U+{:04X}", u32::from(c)))
+            .collect();
+        codepoints.join(" ")
+    }
+
+    /// Returns the emoji with the given codepoints, in the format of
+    /// [`Emoji::to_codepoint_string`].
+    ///
+    /// Codepoints may be separated by any whitespace, hexadecimal digits are case insensitive, and
+    /// variation selectors are optional.
+    pub fn from_codepoint_string(codepoints: &str) -> Option<Emoji> {
+        let glyph = codepoints
+            .split_whitespace()
+            .map(|codepoint| parse_codepoint(codepoint.strip_prefix("U+")?))
+            .collect::<Option<String>>()?;
+        find(&glyph)
+    }
+
+    /// Returns the Rust expression that constructs this emoji, for generating source code.
+    ///
+    /// The expression assumes that the types are in scope, e.g. `use mythoji::*;`.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Item, Person, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+    /// assert_eq!(
+    ///     elf.to_rust_literal(),
+    ///     "Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female)",
+    /// );
+    /// assert_eq!(
+    ///     Emoji::Item(Item::SwordsCrossed).to_rust_literal(),
+    ///     "Emoji::Item(Item::SwordsCrossed)",
+    /// );
+    /// ```
+    pub fn to_rust_literal(&self) -> String {
+        match self {
+            Emoji::Person(person, skin, gender) => format!(
+                "Emoji::Person(Person::{:?}, SkinTone::{:?}, Gender::{:?})",
+                person, skin, gender
+            ),
+            Emoji::Creature(creature) => format!("Emoji::Creature(Creature::{:?})", creature),
+            Emoji::Location(location) => format!("Emoji::Location(Location::{:?})", location),
+            Emoji::Item(item) => format!("Emoji::Item(Item::{:?})", item),
+            Emoji::Flora(flora) => format!("Emoji::Flora(Flora::{:?})", flora),
+            Emoji::BodyPart(body_part) => format!("Emoji::BodyPart(BodyPart::{:?})", body_part),
+            Emoji::Symbol(symbol) => format!("Emoji::Symbol(Symbol::{:?})", symbol),
+        }
+    }
+}
+
+/// Writes UTF-8 into a fixed buffer, failing if it is full.
+struct Cursor<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for Cursor<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        let target = self.buffer.get_mut(self.len..end).ok_or(std::fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Returns the only item, if there is exactly one.
+fn unambiguous<T>(mut items: impl Iterator<Item = T>) -> Option<T> {
+    let first = items.next()?;
+    match items.next() {
+        Some(_) => None,
+        None => Some(first),
+    }
+}
+
+macro_rules! impl_from_codepoint {
+    ($($ty:ty),*) => {$(
+        impl $ty {
+            /// Returns the variant whose glyph is the given codepoint, if there is exactly one.
+            ///
+            /// Variation selectors are ignored, and codepoints that are shared by multiple variants
+            /// are ambiguous, so `None` is returned.
+            pub fn from_codepoint(codepoint: u32) -> Option<Self> {
+                let mut c = [0; 4];
+                let glyph = char::from_u32(codepoint)?.encode_utf8(&mut c);
+                unambiguous(Self::ALL.iter().copied().filter(|v| glyph_eq(v.as_str(), glyph)))
+            }
+        }
+    )*};
+}
+
+impl_from_codepoint!(Person, SkinTone, Gender, Creature, Location, Item, Flora, BodyPart, Symbol);
+
+macro_rules! impl_as_str {
+    ($($ty:ty),*) => {$(
+        impl $ty {
+            /// Returns the glyph, which unlike `to_string` can be used in `const` contexts.
+            pub const fn as_str(&self) -> &'static str {
+                self.glyph()
+            }
+
+            /// Returns the UTF-8 bytes of the glyph.
+            pub const fn as_bytes(&self) -> &'static [u8] {
+                self.glyph().as_bytes()
+            }
+        }
+    )*};
+}
+
+impl_as_str!(Person, Creature, Location, Item, Flora, BodyPart, Symbol);
+
+/// Returns the emoji with the given glyph, ignoring variation selector 16.
+fn find(glyph: &str) -> Option<Emoji> {
+    let mut buffer = [0; Emoji::MAX_UTF8_LEN];
+    all().find(|emoji| glyph_eq(emoji.encode_utf8(&mut buffer), glyph))
+}
+
+/// Parses a codepoint from 1 to 6 hexadecimal digits.
+fn parse_codepoint(hex: &str) -> Option<char> {
+    if hex.is_empty() || hex.len() > 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+macro_rules! impl_glyph_eq {
+    ($($ty:ty),*) => {$(
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<str> for $ty {
+            fn eq(&self, other: &str) -> bool {
+                glyph_eq(&self.to_string(), other)
+            }
+        }
+
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<&str> for $ty {
+            fn eq(&self, other: &&str) -> bool {
+                self == *other
+            }
+        }
+
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<String> for $ty {
+            fn eq(&self, other: &String) -> bool {
+                self == other.as_str()
+            }
+        }
+
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<$ty> for str {
+            fn eq(&self, other: &$ty) -> bool {
+                other == self
+            }
+        }
+
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<$ty> for &str {
+            fn eq(&self, other: &$ty) -> bool {
+                other == *self
+            }
+        }
+
+        /// Compares against the displayed glyph, ignoring variation selectors.
+        impl PartialEq<$ty> for String {
+            fn eq(&self, other: &$ty) -> bool {
+                other == self.as_str()
+            }
+        }
+    )*};
+}
+
+impl_glyph_eq!(Emoji, Person, SkinTone, Gender, Creature, Location, Item, Flora, BodyPart, Symbol);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_rust_literal() {
+        let dragon = Emoji::Creature(Creature::Dragon);
+        assert_eq!(
+            dragon.to_rust_literal(),
+            "Emoji::Creature(Creature::Dragon)"
+        );
+        let castle = Emoji::Location(Location::CastleJapanese);
+        assert_eq!(
+            castle.to_rust_literal(),
+            "Emoji::Location(Location::CastleJapanese)"
+        );
+    }
+
+    #[test]
+    fn test_glyph_eq() {
+        assert!(glyph_eq("⚔️", "⚔"));
+        assert!(glyph_eq("🧝‍♀️", "🧝‍♀"));
+        assert!(!glyph_eq("🧝‍♀️", "🧝"));
+        assert!(!glyph_eq("🐉", "🐲"));
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        assert_eq!(
+            Emoji::parse_prefix("⚔move"),
+            Some((Emoji::Item(Item::SwordsCrossed), "move"))
+        );
+        assert_eq!(
+            Emoji::parse_prefix("🏜️🏜"),
+            Some((Emoji::Location(Location::Desert), "🏜"))
+        );
+        assert_eq!(
+            Emoji::parse_prefix("🧙‍♂"),
+            Some((
+                Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male),
+                ""
+            ))
+        );
+        assert_eq!(
+            Emoji::parse_prefix("🧙‍"),
+            Some((
+                Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Neutral),
+                "\u{200d}"
+            ))
+        );
+        assert_eq!(Emoji::parse_prefix(""), None);
+    }
+
+    #[test]
+    fn test_from_glyph() {
+        for emoji in all() {
+            let glyph = emoji.to_string();
+            let parsed = Emoji::from_glyph(&glyph).unwrap();
+            assert_eq!(parsed.to_string(), glyph);
+            assert_eq!(
+                Emoji::from_glyph(&emoji.qualified(crate::Qualification::Minimal).to_string()),
+                Some(parsed)
+            );
+        }
+        let woman = Emoji::Person(Person::Person, SkinTone::Neutral, Gender::Female);
+        assert_eq!(Emoji::from_glyph("👩"), Some(woman));
+        assert_eq!(Emoji::from_glyph("🗝"), Some(Emoji::Item(Item::Key)));
+        assert_eq!(Emoji::from_glyph(""), None);
+        assert_eq!(Emoji::from_glyph("🐉 "), None);
+    }
+
+    #[test]
+    fn test_from_codepoint() {
+        assert_eq!(
+            Emoji::from_codepoint(0x2694),
+            Some(Emoji::Item(Item::SwordsCrossed))
+        );
+        assert_eq!(
+            Emoji::from_codepoint(0x1F9DD),
+            Some(Emoji::Person(
+                Person::Elf,
+                SkinTone::Neutral,
+                Gender::Neutral
+            ))
+        );
+        assert_eq!(Emoji::from_codepoint(0x1F3DC), None);
+        assert_eq!(Emoji::from_codepoint(0xFE0F), None);
+        assert_eq!(Emoji::from_codepoint(0xD800), None);
+
+        assert_eq!(Item::from_codepoint(0x2694), Some(Item::SwordsCrossed));
+        assert_eq!(Location::from_codepoint(0x1F3DC), None);
+        assert_eq!(Creature::from_codepoint(0x2694), None);
+        assert_eq!(SkinTone::from_codepoint(0x1F3FF), Some(SkinTone::Dark));
+        assert_eq!(Gender::from_codepoint(0x2640), Some(Gender::Female));
+        assert_eq!(Symbol::from_codepoint(0x1F525), Some(Symbol::Fire));
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        let mut buffer = [0; Emoji::MAX_UTF8_LEN];
+        for emoji in all() {
+            let glyph = emoji.to_string();
+            assert_eq!(emoji.encode_utf8(&mut buffer), glyph);
+            if let Some(bytes) = emoji.as_bytes() {
+                assert_eq!(bytes, glyph.as_bytes());
+            }
+        }
+
+        let genie = Emoji::Person(Person::Genie, SkinTone::Dark, Gender::Neutral);
+        assert_eq!(genie.as_bytes(), Some("🧞".as_bytes()));
+        let woman = Emoji::Person(Person::Person, SkinTone::Neutral, Gender::Female);
+        assert_eq!(woman.as_bytes(), Some("👩".as_bytes()));
+        assert_eq!(Symbol::Fire.as_bytes(), "🔥".as_bytes());
+    }
+
+    #[test]
+    fn test_as_str() {
+        const TILES: [&str; 3] = [
+            Location::Castle.as_str(),
+            Creature::Dragon.as_str(),
+            Item::Coin.as_str(),
+        ];
+        assert_eq!(TILES.concat(), "🏰🐉🪙");
+        for symbol in Symbol::ALL {
+            assert_eq!(symbol.as_str(), symbol.to_string());
+        }
+        assert_eq!(SkinTone::Medium.as_str(), SkinTone::Medium.to_string());
+        assert_eq!(Gender::Neutral.as_str(), "");
+    }
+
+    #[test]
+    fn test_write_to() {
+        let mut text = String::new();
+        let mut bytes = Vec::new();
+        for emoji in all() {
+            emoji.write_to(&mut text).unwrap();
+            emoji.write_to_io(&mut bytes).unwrap();
+        }
+        let expected: String = all().map(|emoji| emoji.to_string()).collect();
+        assert_eq!(text, expected);
+        assert_eq!(bytes, expected.as_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer is large enough")]
+    fn test_encode_utf8_too_small() {
+        Emoji::Creature(Creature::Dragon).encode_utf8(&mut [0; 3]);
+    }
+
+    #[test]
+    fn test_utf16_units() {
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(
+            elf.utf16_units(),
+            [0xD83E, 0xDDDD, 0xD83C, 0xDFFF, 0x200D, 0x2640, 0xFE0F]
+        );
+        assert_eq!(
+            Emoji::Item(Item::SwordsCrossed).utf16_units(),
+            [0x2694, 0xFE0F]
+        );
+    }
+
+    #[test]
+    fn test_unicode_escape() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male);
+        let escaped = r"\u{1F9D9}\u{200D}\u{2642}\u{FE0F}";
+        assert_eq!(mage.to_unicode_escape(), escaped);
+        assert_eq!(Emoji::from_unicode_escape(escaped), Some(mage));
+        assert_eq!(
+            Emoji::from_unicode_escape(r"\u{1f9d9}\u{200d}\u{2642}"),
+            Some(mage)
+        );
+        assert_eq!(Emoji::from_unicode_escape(r"\u{1F9D9}x"), None);
+        assert_eq!(Emoji::from_unicode_escape(r"\u{}"), None);
+        assert_eq!(Emoji::from_unicode_escape(r"\u{+1F9D9}"), None);
+        assert_eq!(Emoji::from_unicode_escape(r"\u{D800}"), None);
+        assert_eq!(Emoji::from_unicode_escape(""), None);
+    }
+
+    #[test]
+    fn test_codepoint_string() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male);
+        let codepoints = "U+1F9D9 U+200D U+2642 U+FE0F";
+        assert_eq!(mage.to_codepoint_string(), codepoints);
+        assert_eq!(Emoji::from_codepoint_string(codepoints), Some(mage));
+        assert_eq!(
+            Emoji::from_codepoint_string("U+1f9d9\tU+200d\nU+2642"),
+            Some(mage)
+        );
+        assert_eq!(Emoji::from_codepoint_string("1F9D9"), None);
+        assert_eq!(Emoji::from_codepoint_string("U+110000"), None);
+        assert_eq!(Emoji::from_codepoint_string(""), None);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        let input = String::from("🐉");
+        assert!(input == Creature::Dragon);
+        assert!(Creature::Dragon == input);
+        assert!("⚔" == Item::SwordsCrossed);
+        assert!(Item::SwordsCrossed == "⚔️");
+        assert!(*"🏰" == Location::Castle);
+        assert!("🐉" != Emoji::Creature(Creature::Wolf));
+
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert!(elf == "🧝🏿‍♀️");
+        assert!(elf == "🧝🏿‍♀");
+        assert!(elf != "🧝🏿");
+    }
+}
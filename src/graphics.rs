@@ -0,0 +1,229 @@
+//! Inline images, which display emojis as pictures on terminals whose fonts lack their glyphs.
+//!
+//! Terminals such as kitty and iTerm2 can display images inline using their graphics protocols, so
+//! newer sequences (e.g. a phoenix, "🐦‍🔥") can still be displayed when the installed font predates
+//! them. The crate does not bundle any images; games provide their own PNG assets.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::graphics::{InlineImages, Protocol};
+//! use mythoji::render::Renderer;
+//! use mythoji::{Creature, Emoji, UnicodeVersion};
+//!
+//! # let png = vec![0x89, b'P', b'N', b'G'];
+//! let images = InlineImages::new(Protocol::Kitty).image(Emoji::Creature(Creature::Mammoth), png);
+//! let renderer = Renderer::new()
+//!     .max_unicode_version(UnicodeVersion::V12)
+//!     .fallback(images);
+//!
+//! // Supported emojis are rendered as is.
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Dragon)), "🐉");
+//!
+//! // Unsupported emojis with an image are rendered as an image, two columns wide and one row high.
+//! assert_eq!(
+//!     renderer.render(Emoji::Creature(Creature::Mammoth)),
+//!     "\x1b_Gf=100,a=T,c=2,r=1;iVBORw==\x1b\\"
+//! );
+//!
+//! // Unsupported emojis without an image use the plain text fallback.
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Beaver)), "B");
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::fallback::{Fallback, FallbackProvider};
+use crate::Emoji;
+
+/// The maximum number of base64 bytes in a single kitty graphics command.
+const KITTY_CHUNK: usize = 4096;
+
+/// A terminal graphics protocol, which displays images inline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Protocol {
+    /// The kitty graphics protocol, also supported by e.g. WezTerm, Ghostty, and Konsole.
+    Kitty,
+
+    /// The iTerm2 inline images protocol, also supported by e.g. WezTerm and mintty.
+    ITerm2,
+}
+
+impl_all!(Protocol, [Kitty, ITerm2]);
+
+impl Protocol {
+    /// Returns the escape sequence that displays the given PNG image in a single emoji cell.
+    ///
+    /// The image is scaled to two columns wide and one row high, the width of an emoji.
+    pub fn encode(&self, png: &[u8]) -> String {
+        let data = base64(png);
+        match self {
+            Self::Kitty if data.len() <= KITTY_CHUNK => {
+                format!("\x1b_Gf=100,a=T,c=2,r=1;{}\x1b\\", data)
+            }
+            Self::Kitty => {
+                let mut out = String::new();
+                for start in (0..data.len()).step_by(KITTY_CHUNK) {
+                    let end = data.len().min(start + KITTY_CHUNK);
+                    let keys = if start == 0 { "f=100,a=T,c=2,r=1," } else { "" };
+                    let more = u8::from(end < data.len());
+                    out.push_str(&format!(
+                        "\x1b_G{}m={};{}\x1b\\",
+                        keys,
+                        more,
+                        &data[start..end]
+                    ));
+                }
+                out
+            }
+            Self::ITerm2 => format!(
+                "\x1b]1337;File=inline=1;size={};width=2;height=1;preserveAspectRatio=1:{}\x07",
+                png.len(),
+                data
+            ),
+        }
+    }
+}
+
+/// A [`FallbackProvider`] that renders emojis as inline images, using the given [`Protocol`].
+///
+/// An emoji without an image of its own uses the image of its [`Emoji::base`], if any (e.g. a
+/// female elf uses the image of an elf), and otherwise the plain text fallback, which is
+/// [`Fallback::Ascii`] unless configured otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineImages<F = Fallback> {
+    protocol: Protocol,
+    images: HashMap<Emoji, Vec<u8>>,
+    fallback: F,
+}
+
+impl InlineImages {
+    /// Creates a provider without images, which uses the given protocol.
+    pub fn new(protocol: Protocol) -> Self {
+        Self {
+            protocol,
+            images: HashMap::new(),
+            fallback: Fallback::default(),
+        }
+    }
+}
+
+impl<F: FallbackProvider> InlineImages<F> {
+    /// Displays the given emoji as the given PNG image, replacing any previous image.
+    pub fn image(mut self, emoji: Emoji, png: impl Into<Vec<u8>>) -> Self {
+        self.images.insert(emoji, png.into());
+        self
+    }
+
+    /// Renders emojis without an image using the given fallback, e.g. a [`Fallback`] style.
+    pub fn fallback<G: FallbackProvider>(self, fallback: G) -> InlineImages<G> {
+        InlineImages {
+            protocol: self.protocol,
+            images: self.images,
+            fallback,
+        }
+    }
+
+    /// Returns the protocol.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Returns the image of the given emoji, or of its [`Emoji::base`], if any.
+    pub fn get(&self, emoji: Emoji) -> Option<&[u8]> {
+        self.images
+            .get(&emoji)
+            .or_else(|| self.images.get(&emoji.base()))
+            .map(Vec::as_slice)
+    }
+}
+
+impl<F: FallbackProvider> FallbackProvider for InlineImages<F> {
+    fn fallback(&self, emoji: Emoji) -> Cow<'static, str> {
+        match self.get(emoji) {
+            Some(png) => Cow::Owned(self.protocol.encode(png)),
+            None => self.fallback.fallback(emoji),
+        }
+    }
+
+    fn fallback_custom<'a>(&self, name: &'a str, text: &'a str) -> Cow<'a, str> {
+        self.fallback.fallback_custom(name, text)
+    }
+}
+
+/// Encodes the given bytes as padded base64, as both protocols require.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |n, (i, b)| n | (*b as u32) << (16 - i * 8));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - i * 6)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Person};
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xff, 0xfe]), "//4=");
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            Protocol::ITerm2.encode(b"foo"),
+            "\x1b]1337;File=inline=1;size=3;width=2;height=1;preserveAspectRatio=1:Zm9v\x07"
+        );
+
+        // Large images are split into chunks, where every chunk but the last is followed by more.
+        let kitty = Protocol::Kitty.encode(&[0; KITTY_CHUNK]);
+        let chunks: Vec<_> = kitty.split_terminator("\x1b\\").collect();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].starts_with("\x1b_Gf=100,a=T,c=2,r=1,m=1;AAAA"));
+        assert_eq!(
+            chunks[0].len(),
+            "\x1b_Gf=100,a=T,c=2,r=1,m=1;".len() + KITTY_CHUNK
+        );
+        assert!(chunks[1].starts_with("\x1b_Gm=0;AAAA"));
+    }
+
+    #[test]
+    fn test_inline_images() {
+        let images = InlineImages::new(Protocol::ITerm2)
+            .image(Person::Elf.neutral(), b"elf".to_vec())
+            .fallback(Fallback::Name);
+        assert_eq!(images.protocol(), Protocol::ITerm2);
+        assert_eq!(images.get(Person::Elf.female()), Some(&b"elf"[..]));
+        assert_eq!(
+            FallbackProvider::fallback(&images, Person::Elf.female()),
+            Protocol::ITerm2.encode(b"elf")
+        );
+        assert_eq!(
+            FallbackProvider::fallback(&images, Emoji::Creature(Creature::Dragon)),
+            "[dragon]"
+        );
+    }
+}
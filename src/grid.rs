@@ -0,0 +1,463 @@
+//! Grids of cells, e.g. a map of [`Emoji`]s, which display as rows of double-width glyphs.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::grid::Grid;
+//! use mythoji::{Creature, Emoji, Location};
+//!
+//! let mut map = Grid::new(3, 2);
+//! map.set(0, 0, Emoji::Location(Location::TreeEvergreen));
+//! map.set(2, 1, Emoji::Creature(Creature::Dragon));
+//! assert_eq!(map.to_string(), "🌲    \n    🐉");
+//! ```
+//!
+//! Weather and other effects can be drawn over a region with an [`Overlay`], without changing the
+//! cells beneath it:
+//!
+//! ```
+//! use mythoji::grid::{Grid, Overlay, Precedence};
+//! use mythoji::{Creature, Emoji};
+//!
+//! let mut map = Grid::new(3, 1);
+//! map.set(1, 0, Emoji::Creature(Creature::Wolf));
+//!
+//! // Rain falls on the empty cells, but the wolf remains visible.
+//! map.overlay(Overlay::new("🌧️", 0, 0, 3, 1).precedence(Precedence::Below));
+//! assert_eq!(map.to_string(), "🌧️🐺🌧️");
+//!
+//! // Fog hides everything beneath it.
+//! map.overlay(Overlay::new("🌫️", 1, 0, 2, 1));
+//! assert_eq!(map.to_string(), "🌧️🌫️🌫️");
+//!
+//! map.clear_overlays();
+//! assert_eq!(map.to_string(), "  🐺  ");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::render::{EmojiRenderer, Style};
+use crate::Emoji;
+
+/// A fixed-size, two-dimensional grid of optional cells.
+///
+/// Cells are addressed by `(x, y)`, where `(0, 0)` is the top-left cell. When displayed, each row
+/// is written on its own line, and empty cells are written as two spaces, the width of an emoji.
+///
+/// To draw the ground of a map, use a grid of [`Terrain`](crate::terrain::Terrain).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = Emoji> {
+    width: usize,
+    height: usize,
+    cells: Vec<Option<T>>,
+    overlays: Vec<Overlay>,
+}
+
+impl<T> Grid<T> {
+    /// Creates an empty grid with the given number of columns and rows.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: std::iter::repeat_with(|| None)
+                .take(width * height)
+                .collect(),
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether `(x, y)` is within the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at `(x, y)`, if it is within the grid and not empty.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).and_then(|i| self.cells[i].as_ref())
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, if it is within the grid and not empty.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).and_then(|i| self.cells[i].as_mut())
+    }
+
+    /// Sets the cell at `(x, y)`, returning the previous value, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is not within the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        let i = self.index_or_panic(x, y);
+        self.cells[i].replace(value)
+    }
+
+    /// Empties the cell at `(x, y)`, returning the previous value, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is not within the grid.
+    pub fn remove(&mut self, x: usize, y: usize) -> Option<T> {
+        let i = self.index_or_panic(x, y);
+        self.cells[i].take()
+    }
+
+    /// Sets every cell to the given value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(Some(value));
+    }
+
+    /// Empties every cell.
+    pub fn clear(&mut self) {
+        self.cells.iter_mut().for_each(|cell| *cell = None);
+    }
+
+    /// Returns an iterator over the non-empty cells, as `(x, y, value)`, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.cells.iter().enumerate().filter_map(|(i, cell)| {
+            cell.as_ref()
+                .map(|value| (i % self.width, i / self.width, value))
+        })
+    }
+
+    /// Returns the cells that differ from the given previous frame, in row-major order.
+    ///
+    /// This lets terminal games repaint only what changed each tick, instead of the whole grid.
+    /// Cells outside of either frame are compared as empty, so if the grid grew the new cells that
+    /// are not empty are yielded, and if it shrank the removed cells that were not empty are
+    /// yielded as emptied. Overlays are not compared.
+    ///
+    /// ```
+    /// use mythoji::grid::Grid;
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let mut previous = Grid::new(3, 1);
+    /// previous.set(0, 0, Emoji::Creature(Creature::Wolf));
+    ///
+    /// // The wolf moves one cell to the right.
+    /// let mut next = previous.clone();
+    /// next.remove(0, 0);
+    /// next.set(1, 0, Emoji::Creature(Creature::Wolf));
+    ///
+    /// let changes: Vec<_> = next
+    ///     .diff(&previous)
+    ///     .map(|change| (change.column(), change.cell()))
+    ///     .collect();
+    /// assert_eq!(changes, [(0, None), (2, Some(&Emoji::Creature(Creature::Wolf)))]);
+    /// ```
+    pub fn diff<'a>(&'a self, previous: &'a Grid<T>) -> GridDiff<'a, T>
+    where
+        T: PartialEq,
+    {
+        GridDiff {
+            previous,
+            next: self,
+            width: self.width.max(previous.width),
+            height: self.height.max(previous.height),
+            index: 0,
+        }
+    }
+
+    /// Adds an overlay, which is drawn over any overlays that were added before it.
+    pub fn overlay(&mut self, overlay: Overlay) {
+        self.overlays.push(overlay);
+    }
+
+    /// Returns the overlays, in the order they were added.
+    pub fn overlays(&self) -> &[Overlay] {
+        &self.overlays
+    }
+
+    /// Removes every overlay.
+    pub fn clear_overlays(&mut self) {
+        self.overlays.clear();
+    }
+
+    /// Returns the overlay shown at `(x, y)`, if any.
+    ///
+    /// This is the most recently added overlay that covers the cell, ignoring overlays with
+    /// [`Precedence::Below`] if the cell is not empty.
+    pub fn overlay_at(&self, x: usize, y: usize) -> Option<&Overlay> {
+        let occupied = self.get(x, y).is_some();
+        self.overlays.iter().rev().find(|overlay| {
+            overlay.contains(x, y) && !(occupied && overlay.precedence == Precedence::Below)
+        })
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        self.contains(x, y).then(|| y * self.width + x)
+    }
+
+    fn index_or_panic(&self, x: usize, y: usize) -> usize {
+        match self.index(x, y) {
+            Some(i) => i,
+            None => panic!(
+                "({}, {}) is out of bounds for a {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl Grid<Emoji> {
+    /// Draws every non-empty cell with the given backend and style.
+    ///
+    /// Overlays are not drawn, as they are not emojis; backends can draw them with
+    /// [`Grid::overlay_at`].
+    pub fn draw<R: EmojiRenderer + ?Sized>(
+        &self,
+        backend: &mut R,
+        style: Style,
+    ) -> std::result::Result<(), R::Error> {
+        for (x, y, emoji) in self.iter() {
+            backend.draw(*emoji, x, y, style)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for y in 0..self.height {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for x in 0..self.width {
+                match (self.overlay_at(x, y), self.get(x, y)) {
+                    (Some(overlay), _) => f.write_str(overlay.glyph())?,
+                    (None, Some(value)) => write!(f, "{}", value)?,
+                    (None, None) => f.write_str("  ")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The cells that differ between two frames of a [`Grid`]; see [`Grid::diff`].
+#[derive(Debug, Clone)]
+pub struct GridDiff<'a, T = Emoji> {
+    previous: &'a Grid<T>,
+    next: &'a Grid<T>,
+    width: usize,
+    height: usize,
+    index: usize,
+}
+
+impl<'a, T: PartialEq> Iterator for GridDiff<'a, T> {
+    type Item = Change<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.width * self.height {
+            let (x, y) = (self.index % self.width, self.index / self.width);
+            let cell = self.next.get(x, y);
+            self.index += 1;
+            if cell != self.previous.get(x, y) {
+                return Some(Change { x, y, cell });
+            }
+        }
+        None
+    }
+}
+
+/// A cell that changed between two frames of a [`Grid`], yielded by [`GridDiff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Change<'a, T = Emoji> {
+    x: usize,
+    y: usize,
+    cell: Option<&'a T>,
+}
+
+impl<'a, T> Change<'a, T> {
+    /// Returns the column of the cell.
+    pub fn x(&self) -> usize {
+        self.x
+    }
+
+    /// Returns the row of the cell.
+    pub fn y(&self) -> usize {
+        self.y
+    }
+
+    /// Returns the offset of the cell in terminal columns, as each cell is two columns wide.
+    pub fn column(&self) -> usize {
+        self.x * 2
+    }
+
+    /// Returns the new value of the cell, or `None` if it was emptied.
+    pub fn cell(&self) -> Option<&'a T> {
+        self.cell
+    }
+}
+
+/// A glyph drawn over a rectangular region of a [`Grid`], e.g. rain or fog.
+///
+/// The glyph should be two columns wide, like the cells it covers, e.g. "🌧️", "❄️", or "🌫️".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Overlay {
+    glyph: String,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    precedence: Precedence,
+}
+
+impl Overlay {
+    /// Creates an overlay of the given glyph, covering `width` columns and `height` rows starting at
+    /// `(x, y)`.
+    pub fn new(glyph: impl Display, x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            glyph: glyph.to_string(),
+            x,
+            y,
+            width,
+            height,
+            precedence: Precedence::default(),
+        }
+    }
+
+    /// Sets which cells the overlay is drawn over.
+    pub fn precedence(mut self, precedence: Precedence) -> Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Returns the glyph that is drawn.
+    pub fn glyph(&self) -> &str {
+        &self.glyph
+    }
+
+    /// Returns whether the overlay covers `(x, y)`.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+}
+
+/// Which cells of a [`Grid`] an [`Overlay`] is drawn over.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Precedence {
+    /// Drawn over every cell, e.g. for a blizzard that hides everything.
+    #[default]
+    Above,
+
+    /// Drawn beneath the cells, i.e. over empty cells only, so that cells with a value (e.g.
+    /// creatures) remain visible.
+    Below,
+}
+
+impl_all!(Precedence, [Above, Below]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Item, Location, Symbol};
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::new(2, 3);
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert!(grid.contains(1, 2));
+        assert!(!grid.contains(2, 0));
+
+        assert_eq!(grid.set(1, 2, Emoji::Item(Item::Coin)), None);
+        assert_eq!(
+            grid.set(1, 2, Emoji::Item(Item::Key)),
+            Some(Emoji::Item(Item::Coin))
+        );
+        assert_eq!(grid.get(1, 2), Some(&Emoji::Item(Item::Key)));
+        assert_eq!(grid.get(0, 0), None);
+        assert_eq!(grid.get(5, 5), None);
+
+        let cells: Vec<_> = grid.iter().collect();
+        assert_eq!(cells, [(1, 2, &Emoji::Item(Item::Key))]);
+
+        assert_eq!(grid.remove(1, 2), Some(Emoji::Item(Item::Key)));
+        assert_eq!(grid.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_grid_display() {
+        let mut grid = Grid::new(2, 2);
+        grid.fill(Emoji::Location(Location::TreeDeciduous));
+        grid.set(1, 1, Emoji::Creature(Creature::Wolf));
+        assert_eq!(grid.to_string(), "🌳🌳\n🌳🐺");
+
+        grid.clear();
+        assert_eq!(grid.to_string(), "    \n    ");
+    }
+
+    #[test]
+    fn test_grid_overlay() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(1, 1, Emoji::Creature(Creature::Wolf));
+        grid.overlay(Overlay::new(Symbol::Snowflake, 0, 0, 3, 2).precedence(Precedence::Below));
+        grid.overlay(Overlay::new("🌧️", 1, 0, 2, 1));
+        assert_eq!(grid.to_string(), "❄️🌧️🌧️\n❄️🐺❄️");
+        assert_eq!(grid.overlay_at(1, 1), None);
+        assert_eq!(grid.overlay_at(2, 0).map(Overlay::glyph), Some("🌧️"));
+        assert_eq!(grid.overlays().len(), 2);
+
+        grid.overlay(Overlay::new("🌫️", 1, 1, 1, 1));
+        assert_eq!(grid.to_string(), "❄️🌧️🌧️\n❄️🌫️❄️");
+    }
+
+    #[test]
+    fn test_grid_diff() {
+        let mut previous = Grid::new(2, 2);
+        previous.fill(Emoji::Location(Location::TreeDeciduous));
+        assert_eq!(previous.diff(&previous).count(), 0);
+
+        let mut next = previous.clone();
+        next.set(1, 1, Emoji::Creature(Creature::Wolf));
+        next.remove(0, 1);
+        let changes: Vec<_> = next.diff(&previous).collect();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            (changes[0].x(), changes[0].y(), changes[0].cell()),
+            (0, 1, None)
+        );
+        assert_eq!(
+            (changes[1].x(), changes[1].y(), changes[1].column()),
+            (1, 1, 2)
+        );
+        assert_eq!(changes[1].cell(), Some(&Emoji::Creature(Creature::Wolf)));
+
+        let mut larger = Grid::new(3, 2);
+        larger.set(2, 0, Emoji::Item(Item::Coin));
+        let changes: Vec<_> = larger
+            .diff(&Grid::new(1, 1))
+            .map(|c| (c.x(), c.y()))
+            .collect();
+        assert_eq!(changes, [(2, 0)]);
+
+        let smaller = Grid::new(1, 1);
+        let changes: Vec<_> = smaller
+            .diff(&larger)
+            .map(|c| (c.x(), c.y(), c.cell()))
+            .collect();
+        assert_eq!(changes, [(2, 0, None)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is out of bounds for a 2x2 grid")]
+    fn test_grid_out_of_bounds() {
+        Grid::new(2, 2).set(2, 0, Emoji::Item(Item::Coin));
+    }
+}
@@ -0,0 +1,346 @@
+//! The official Unicode groups and subgroups of every emoji, as in the Unicode emoji ordering.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{Creature, Emoji, Person, UnicodeGroup};
+//!
+//! let elf = Person::Elf.female();
+//! assert_eq!(elf.unicode_group(), (UnicodeGroup::PeopleAndBody, "person-fantasy"));
+//!
+//! let wolf = Emoji::Creature(Creature::Wolf);
+//! assert_eq!(wolf.unicode_group(), (UnicodeGroup::AnimalsAndNature, "animal-mammal"));
+//! assert_eq!(UnicodeGroup::AnimalsAndNature.name(), "Animals & Nature");
+//! ```
+
+use crate::{BodyPart, Creature, Emoji, Flora, Item, Location, Person, Symbol};
+
+/// A group of emojis in the Unicode emoji ordering, as shown in most emoji pickers.
+///
+/// Groups are declared (and ordered) in the same order as the Unicode emoji ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnicodeGroup {
+    /// Smileys & Emotion, e.g. "👻".
+    SmileysAndEmotion,
+
+    /// People & Body, e.g. "🧝".
+    PeopleAndBody,
+
+    /// Component, e.g. skin tone modifiers.
+    Component,
+
+    /// Animals & Nature, e.g. "🐉".
+    AnimalsAndNature,
+
+    /// Food & Drink, e.g. "🍖".
+    FoodAndDrink,
+
+    /// Travel & Places, e.g. "🏰".
+    TravelAndPlaces,
+
+    /// Activities, e.g. "🔮".
+    Activities,
+
+    /// Objects, e.g. "🗡".
+    Objects,
+
+    /// Symbols, e.g. "♀️".
+    Symbols,
+
+    /// Flags, e.g. "🚩".
+    Flags,
+}
+
+impl_all!(
+    UnicodeGroup,
+    [
+        SmileysAndEmotion,
+        PeopleAndBody,
+        Component,
+        AnimalsAndNature,
+        FoodAndDrink,
+        TravelAndPlaces,
+        Activities,
+        Objects,
+        Symbols,
+        Flags
+    ]
+);
+
+impl UnicodeGroup {
+    /// Returns the official name of this group, e.g. "People & Body".
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::SmileysAndEmotion => "Smileys & Emotion",
+            Self::PeopleAndBody => "People & Body",
+            Self::Component => "Component",
+            Self::AnimalsAndNature => "Animals & Nature",
+            Self::FoodAndDrink => "Food & Drink",
+            Self::TravelAndPlaces => "Travel & Places",
+            Self::Activities => "Activities",
+            Self::Objects => "Objects",
+            Self::Symbols => "Symbols",
+            Self::Flags => "Flags",
+        }
+    }
+}
+
+impl Emoji {
+    /// Returns the official Unicode group and subgroup of this emoji, e.g. "person-fantasy".
+    ///
+    /// Persons are in the same group and subgroup regardless of skin tone and gender.
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        match self {
+            Self::Person(person, ..) => person.unicode_group(),
+            Self::Creature(creature) => creature.unicode_group(),
+            Self::Location(location) => location.unicode_group(),
+            Self::Item(item) => item.unicode_group(),
+            Self::Flora(flora) => flora.unicode_group(),
+            Self::BodyPart(body_part) => body_part.unicode_group(),
+            Self::Symbol(symbol) => symbol.unicode_group(),
+        }
+    }
+}
+
+impl Person {
+    /// Returns the official Unicode group and subgroup of this person, e.g. "person-fantasy".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        let subgroup = match self {
+            Self::Baby
+            | Self::BaldPerson
+            | Self::BeardedPerson
+            | Self::Child
+            | Self::OldPerson
+            | Self::Person => "person",
+            Self::Artist
+            | Self::HeadScarfPerson
+            | Self::Royalty
+            | Self::SkullCapPerson
+            | Self::TurbanPerson => "person-role",
+            Self::Fairy
+            | Self::Elf
+            | Self::Genie
+            | Self::Mage
+            | Self::MerPerson
+            | Self::Vampire
+            | Self::Zombie => "person-fantasy",
+        };
+        (UnicodeGroup::PeopleAndBody, subgroup)
+    }
+}
+
+impl Creature {
+    /// Returns the official Unicode group and subgroup of this creature, e.g. "animal-mammal".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        use UnicodeGroup::*;
+        match self {
+            Self::Ghost | Self::Goblin | Self::Ogre => (SmileysAndEmotion, "face-costume"),
+            Self::BlackBird
+            | Self::Dove
+            | Self::Eagle
+            | Self::Owl
+            | Self::Parrot
+            | Self::Peacock
+            | Self::Rooster
+            | Self::Swan
+            | Self::Turkey => (AnimalsAndNature, "animal-bird"),
+            Self::Frog => (AnimalsAndNature, "animal-amphibian"),
+            Self::Crocodile | Self::Dragon | Self::Lizard | Self::Snake | Self::Turtle => {
+                (AnimalsAndNature, "animal-reptile")
+            }
+            Self::Blowfish
+            | Self::Crab
+            | Self::Dolphin
+            | Self::Fish
+            | Self::Jellyfish
+            | Self::Octopus
+            | Self::Seal
+            | Self::Shark
+            | Self::Squid
+            | Self::TropicalFish
+            | Self::Whale => (AnimalsAndNature, "animal-marine"),
+            Self::Ant
+            | Self::Beetle
+            | Self::Bug
+            | Self::Butterfly
+            | Self::Cockroach
+            | Self::Cricket
+            | Self::Fly
+            | Self::Honeybee
+            | Self::Mosquito
+            | Self::Scorpion
+            | Self::Snail
+            | Self::Spider
+            | Self::Worm => (AnimalsAndNature, "animal-bug"),
+            _ => (AnimalsAndNature, "animal-mammal"),
+        }
+    }
+}
+
+impl Location {
+    /// Returns the official Unicode group and subgroup of this location, e.g. "place-building".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        use UnicodeGroup::*;
+        match self {
+            Self::BoatSail | Self::Canoe => (TravelAndPlaces, "transport-water"),
+            Self::BuildingClassic
+            | Self::Castle
+            | Self::CastleJapanese
+            | Self::Hut
+            | Self::Palace => (TravelAndPlaces, "place-building"),
+            Self::Campsite
+            | Self::Desert
+            | Self::Mountain
+            | Self::MountainSnow
+            | Self::Oasis
+            | Self::Volcano => (TravelAndPlaces, "place-geographic"),
+            Self::Cave => (SmileysAndEmotion, "emotion"),
+            Self::Tent => (TravelAndPlaces, "place-other"),
+            Self::TreeDeciduous | Self::TreeEvergreen | Self::TreeLeafless | Self::TreePalm => {
+                (AnimalsAndNature, "plant-other")
+            }
+        }
+    }
+}
+
+impl Item {
+    /// Returns the official Unicode group and subgroup of this item, e.g. "tool".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        use UnicodeGroup::*;
+        match self {
+            Self::CrystalBall | Self::Dart | Self::Wand => (Activities, "game"),
+            Self::Firecracker | Self::RedEnvelope => (Activities, "event"),
+            Self::Axe
+            | Self::Boomerang
+            | Self::BowAndArrow
+            | Self::Dagger
+            | Self::Hammer
+            | Self::HammerAndPick
+            | Self::Pick
+            | Self::Shield
+            | Self::Shovel
+            | Self::SwordsCrossed => (Objects, "tool"),
+            Self::Bag | Self::Coat | Self::Crown | Self::GemStone | Self::PrayerBeads => {
+                (Objects, "clothing")
+            }
+            Self::Bandage | Self::BloodDrop => (Objects, "medical"),
+            Self::Bed | Self::Door | Self::MouseTrap => (Objects, "household"),
+            Self::BookClosed | Self::BookOpen | Self::Scroll => (Objects, "book-paper"),
+            Self::Candle
+            | Self::DiyaLamp
+            | Self::Flashlight
+            | Self::LightBulb
+            | Self::RedLantern => (Objects, "light & video"),
+            Self::Amulet | Self::Bomb | Self::Coffin | Self::Grave | Self::Urn => {
+                (Objects, "other-object")
+            }
+            Self::Coin => (Objects, "money"),
+            Self::Harp => (Objects, "musical-instrument"),
+            Self::Key => (Objects, "lock"),
+            Self::Beer => (FoodAndDrink, "drink"),
+            Self::Jar => (FoodAndDrink, "dishware"),
+            Self::MeatOnBone | Self::MeatCut | Self::PoultryLeg => (FoodAndDrink, "food-prepared"),
+            Self::HeartRed => (SmileysAndEmotion, "heart"),
+            Self::Brick | Self::Rock => (TravelAndPlaces, "place-building"),
+            Self::HourglassDone | Self::HourglassNotDone => (TravelAndPlaces, "time"),
+            Self::Map => (TravelAndPlaces, "place-map"),
+            Self::WaterDrop => (TravelAndPlaces, "sky & weather"),
+            Self::Leaf | Self::LeafFallen | Self::LeafMaple => (AnimalsAndNature, "plant-other"),
+            Self::SpiderWeb => (AnimalsAndNature, "animal-bug"),
+            Self::FlagBlack | Self::FlagCheckered | Self::FlagTriangle | Self::FlagsCrossed => {
+                (Flags, "flag")
+            }
+            Self::Trident => (Symbols, "other-symbol"),
+        }
+    }
+}
+
+impl Flora {
+    /// Returns the official Unicode group and subgroup of this flora, e.g. "plant-flower".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        let subgroup = match self {
+            Self::FlowerWilted | Self::Rose | Self::Sunflower => "plant-flower",
+            Self::Cactus | Self::Clover | Self::Herb | Self::Mushroom | Self::Sheaf => {
+                "plant-other"
+            }
+        };
+        (UnicodeGroup::AnimalsAndNature, subgroup)
+    }
+}
+
+impl BodyPart {
+    /// Returns the official Unicode group and subgroup of this body part, e.g. "body-parts".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        let subgroup = match self {
+            Self::Footprints => "person-symbol",
+            _ => "body-parts",
+        };
+        (UnicodeGroup::PeopleAndBody, subgroup)
+    }
+}
+
+impl Symbol {
+    /// Returns the official Unicode group and subgroup of this symbol, e.g. "punctuation".
+    pub const fn unicode_group(&self) -> (UnicodeGroup, &'static str) {
+        use UnicodeGroup::*;
+        match self {
+            Self::Anger
+            | Self::SpeechBubble
+            | Self::SpeechBubbleAngry
+            | Self::SpeechBubbleLeft
+            | Self::ThoughtBubble
+            | Self::Zzz => (SmileysAndEmotion, "emotion"),
+            Self::Fingerprint | Self::Silhouette | Self::Silhouettes => {
+                (PeopleAndBody, "person-symbol")
+            }
+            Self::Comet | Self::Cyclone | Self::Electricity | Self::Fire | Self::Snowflake => {
+                (TravelAndPlaces, "sky & weather")
+            }
+            Self::Sparkles => (Activities, "event"),
+            Self::ExclamationDouble
+            | Self::ExclamationWithQuestion
+            | Self::ExclamationRed
+            | Self::ExclamationWhite
+            | Self::QuestionRed
+            | Self::QuestionWhite => (Symbols, "punctuation"),
+            Self::GenderFemale | Self::GenderMale => (Symbols, "gender"),
+            Self::Splatter => (Symbols, "other-symbol"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gender, SkinTone};
+
+    #[test]
+    fn test_unicode_group() {
+        let zombie = Emoji::Person(Person::Zombie, SkinTone::Dark, Gender::Male);
+        assert_eq!(zombie.unicode_group(), Person::Zombie.unicode_group());
+        assert_eq!(
+            Emoji::Location(Location::Castle).unicode_group(),
+            (UnicodeGroup::TravelAndPlaces, "place-building")
+        );
+        assert_eq!(
+            Emoji::Creature(Creature::Ghost).unicode_group(),
+            (UnicodeGroup::SmileysAndEmotion, "face-costume")
+        );
+        assert_eq!(
+            Symbol::GenderMale.unicode_group(),
+            (UnicodeGroup::Symbols, "gender")
+        );
+
+        for (group, subgroup) in crate::glyph::bases().map(|e| e.unicode_group()) {
+            assert_ne!(group, UnicodeGroup::Component);
+            assert!(
+                subgroup
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || " &-".contains(c)),
+                "{}",
+                subgroup
+            );
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Hearts, which express the state of a relationship or the health of a character in colors.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::heart::Heart;
+//!
+//! let meter = [Heart::Red, Heart::Red, Heart::Broken];
+//! let hud: String = meter.iter().map(Heart::to_string).collect();
+//! assert_eq!(hud, "❤️❤️💔");
+//!
+//! // Some hearts are sequences of a red heart joined to another emoji.
+//! assert_eq!(Heart::Mending.to_string(), "❤️\u{200d}🩹");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::components::ZWJ;
+
+/// A heart of a single color, or a red heart in a particular state.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Heart {
+    /// A red heart, e.g. "❤️".
+    #[default]
+    Red,
+
+    /// A pink heart, e.g. "🩷".
+    Pink,
+
+    /// An orange heart, e.g. "🧡".
+    Orange,
+
+    /// A yellow heart, e.g. "💛".
+    Yellow,
+
+    /// A green heart, e.g. "💚".
+    Green,
+
+    /// A light blue heart, e.g. "🩵".
+    LightBlue,
+
+    /// A blue heart, e.g. "💙".
+    Blue,
+
+    /// A purple heart, e.g. "💜".
+    Purple,
+
+    /// A brown heart, e.g. "🤎".
+    Brown,
+
+    /// A black heart, e.g. "🖤".
+    Black,
+
+    /// A grey heart, e.g. "🩶".
+    Grey,
+
+    /// A white heart, e.g. "🤍".
+    White,
+
+    /// A broken heart, e.g. "💔".
+    Broken,
+
+    /// A bandaged red heart, e.g. "❤️‍🩹".
+    Mending,
+
+    /// A red heart in flames, e.g. "❤️‍🔥".
+    OnFire,
+}
+
+impl_all!(
+    Heart,
+    [
+        Red, Pink, Orange, Yellow, Green, LightBlue, Blue, Purple, Brown, Black, Grey, White,
+        Broken, Mending, OnFire
+    ]
+);
+
+impl Heart {
+    /// Returns the heart that the glyph starts with, e.g. "❤️" for [`Heart::Mending`].
+    pub const fn base(&self) -> &'static str {
+        match self {
+            Self::Red | Self::Mending | Self::OnFire => "❤️",
+            Self::Pink => "🩷",
+            Self::Orange => "🧡",
+            Self::Yellow => "💛",
+            Self::Green => "💚",
+            Self::LightBlue => "🩵",
+            Self::Blue => "💙",
+            Self::Purple => "💜",
+            Self::Brown => "🤎",
+            Self::Black => "🖤",
+            Self::Grey => "🩶",
+            Self::White => "🤍",
+            Self::Broken => "💔",
+        }
+    }
+
+    /// Returns the emoji joined to the base heart with a zero-width joiner, if any.
+    pub const fn joined(&self) -> Option<&'static str> {
+        match self {
+            Self::Mending => Some("🩹"),
+            Self::OnFire => Some("🔥"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Heart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.base())?;
+        if let Some(joined) = self.joined() {
+            write!(f, "{}{}", ZWJ, joined)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::width;
+    use crate::{Item, Symbol};
+
+    #[test]
+    fn test_heart() {
+        assert_eq!(Heart::Red.to_string(), Item::HeartRed.to_string());
+        assert_eq!(Heart::Purple.to_string(), "💜");
+        assert_eq!(Heart::OnFire.to_string(), "❤\u{fe0f}\u{200d}🔥");
+        assert_eq!(
+            Heart::OnFire.joined(),
+            Some(Symbol::Fire.to_string().as_str())
+        );
+        assert_eq!(Heart::Mending.base(), Heart::Red.base());
+        assert_eq!(Heart::Broken.joined(), None);
+
+        for heart in Heart::ALL {
+            assert!(heart.to_string().starts_with(heart.base()), "{:?}", heart);
+            assert_eq!(width(&heart.to_string()), 2, "{:?}", heart);
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//! Stable identifiers for every emoji, which never change between crate versions.
+//!
+//! Each enum assigns its own identifiers, starting at `0`, in the order variants were added to the
+//! crate. New variants always receive the next unused identifier, even if they are declared in the
+//! middle of the enum, so identifiers are suitable for persistence or networking.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::Creature;
+//!
+//! let id = Creature::Dragon.id();
+//! assert_eq!(Creature::from_id(id), Some(Creature::Dragon));
+//! ```
+
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+impl Emoji {
+    /// Returns a 32-bit hash of this emoji, which never changes between crate versions.
+    ///
+    /// The hash is the 32-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of:
+    ///
+    /// 1. A category byte: `0` for persons, `1` for creatures, `2` for locations, `3` for items, `4`
+    ///    for flora, `5` for body parts, and `6` for symbols.
+    /// 2. The stable identifier of the variant, as a little-endian `u16`.
+    /// 3. For persons, the stable identifiers of the skin tone and gender, as one byte each.
+    ///
+    /// Hashes are unique for every emoji in this version of the crate, so clients and servers built
+    /// from different versions can exchange them in place of larger identifiers; see
+    /// [`Emoji::from_stable_hash32`].
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let dragon = Emoji::Creature(Creature::Dragon);
+    /// assert_eq!(dragon.stable_hash32(), 0xb5b3_f6bb);
+    /// assert_eq!(Emoji::from_stable_hash32(0xb5b3_f6bb), Some(dragon));
+    /// ```
+    pub const fn stable_hash32(&self) -> u32 {
+        let (category, id, modifiers) = match self {
+            Emoji::Person(person, skin, gender) => {
+                (0, person.id(), Some([skin.id() as u8, gender.id() as u8]))
+            }
+            Emoji::Creature(creature) => (1, creature.id(), None),
+            Emoji::Location(location) => (2, location.id(), None),
+            Emoji::Item(item) => (3, item.id(), None),
+            Emoji::Flora(flora) => (4, flora.id(), None),
+            Emoji::BodyPart(body_part) => (5, body_part.id(), None),
+            Emoji::Symbol(symbol) => (6, symbol.id(), None),
+        };
+        let id = id.to_le_bytes();
+        let hash = fnv1a(FNV_OFFSET_BASIS, &[category, id[0], id[1]]);
+        match modifiers {
+            Some(modifiers) => fnv1a(hash, &modifiers),
+            None => hash,
+        }
+    }
+
+    /// Returns the emoji with the given [`Emoji::stable_hash32`], if any.
+    pub fn from_stable_hash32(hash: u32) -> Option<Self> {
+        crate::glyph::all().find(|emoji| emoji.stable_hash32() == hash)
+    }
+}
+
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+/// Continues a 32-bit FNV-1a hash with the given bytes.
+const fn fnv1a(mut hash: u32, bytes: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+impl Person {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Artist => 0,
+            Self::Baby => 1,
+            Self::BaldPerson => 2,
+            Self::BeardedPerson => 3,
+            Self::Child => 4,
+            Self::Fairy => 5,
+            Self::Elf => 6,
+            Self::Genie => 7,
+            Self::HeadScarfPerson => 8,
+            Self::Mage => 9,
+            Self::MerPerson => 10,
+            Self::OldPerson => 11,
+            Self::Person => 12,
+            Self::Royalty => 13,
+            Self::SkullCapPerson => 14,
+            Self::TurbanPerson => 15,
+            Self::Vampire => 16,
+            Self::Zombie => 17,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl SkinTone {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Neutral => 0,
+            Self::Light => 1,
+            Self::MediumLight => 2,
+            Self::Medium => 3,
+            Self::MediumDark => 4,
+            Self::Dark => 5,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Gender {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Neutral => 0,
+            Self::Male => 1,
+            Self::Female => 2,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Creature {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Ant => 0,
+            Self::Badger => 64,
+            Self::Bat => 1,
+            Self::Bear => 65,
+            Self::Beaver => 66,
+            Self::Beetle => 2,
+            Self::Bison => 3,
+            Self::BlackBird => 42,
+            Self::Blowfish => 49,
+            Self::Boar => 4,
+            Self::Bug => 5,
+            Self::Butterfly => 6,
+            Self::Camel => 7,
+            Self::Cat => 8,
+            Self::Cockroach => 9,
+            Self::Cow => 10,
+            Self::Crab => 11,
+            Self::Cricket => 57,
+            Self::Crocodile => 12,
+            Self::Deer => 13,
+            Self::Dog => 14,
+            Self::Dolphin => 50,
+            Self::Donkey => 74,
+            Self::Dove => 43,
+            Self::Dragon => 15,
+            Self::Eagle => 16,
+            Self::Elephant => 17,
+            Self::Fish => 18,
+            Self::Fly => 58,
+            Self::Fox => 67,
+            Self::Frog => 59,
+            Self::Ghost => 19,
+            Self::Goat => 20,
+            Self::Goblin => 21,
+            Self::Gorilla => 68,
+            Self::Hedgehog => 69,
+            Self::Honeybee => 22,
+            Self::Horse => 23,
+            Self::Jellyfish => 51,
+            Self::Leopard => 24,
+            Self::Lion => 70,
+            Self::Lizard => 60,
+            Self::Llama => 25,
+            Self::Mammoth => 26,
+            Self::Monkey => 71,
+            Self::Moose => 75,
+            Self::Mosquito => 61,
+            Self::Mouse => 27,
+            Self::Octopus => 52,
+            Self::Ogre => 28,
+            Self::Otter => 72,
+            Self::Owl => 44,
+            Self::Ox => 76,
+            Self::Parrot => 45,
+            Self::Peacock => 46,
+            Self::Pig => 29,
+            Self::Rabbit => 30,
+            Self::Raccoon => 73,
+            Self::Ram => 31,
+            Self::Rat => 32,
+            Self::Rhinoceros => 33,
+            Self::Rooster => 47,
+            Self::Scorpion => 34,
+            Self::Seal => 53,
+            Self::Shark => 35,
+            Self::Sheep => 77,
+            Self::Snail => 62,
+            Self::Snake => 36,
+            Self::Spider => 37,
+            Self::Squid => 54,
+            Self::Swan => 48,
+            Self::Tiger => 38,
+            Self::TropicalFish => 39,
+            Self::Turkey => 78,
+            Self::Turtle => 55,
+            Self::WaterBuffalo => 40,
+            Self::Whale => 56,
+            Self::Wolf => 41,
+            Self::Worm => 63,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Location {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::BoatSail => 0,
+            Self::BuildingClassic => 1,
+            Self::Campsite => 2,
+            Self::Canoe => 3,
+            Self::Castle => 4,
+            Self::CastleJapanese => 5,
+            Self::Cave => 6,
+            Self::Desert => 7,
+            Self::Hut => 8,
+            Self::Mountain => 9,
+            Self::MountainSnow => 10,
+            Self::Oasis => 11,
+            Self::Palace => 12,
+            Self::Tent => 13,
+            Self::TreeDeciduous => 14,
+            Self::TreeEvergreen => 15,
+            Self::TreeLeafless => 18,
+            Self::TreePalm => 16,
+            Self::Volcano => 17,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Item {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Amulet => 0,
+            Self::Axe => 1,
+            Self::Bag => 2,
+            Self::Bandage => 3,
+            Self::Bed => 4,
+            Self::Beer => 5,
+            Self::BloodDrop => 6,
+            Self::Bomb => 7,
+            Self::BookClosed => 8,
+            Self::BookOpen => 9,
+            Self::Boomerang => 10,
+            Self::BowAndArrow => 11,
+            Self::Brick => 12,
+            Self::Candle => 13,
+            Self::Coat => 14,
+            Self::Coffin => 15,
+            Self::Coin => 16,
+            Self::Crown => 17,
+            Self::CrystalBall => 18,
+            Self::Dagger => 19,
+            Self::Dart => 20,
+            Self::DiyaLamp => 59,
+            Self::Door => 21,
+            Self::FlagBlack => 22,
+            Self::FlagCheckered => 57,
+            Self::FlagTriangle => 23,
+            Self::Firecracker => 24,
+            Self::FlagsCrossed => 58,
+            Self::Flashlight => 60,
+            Self::GemStone => 25,
+            Self::Grave => 26,
+            Self::Hammer => 27,
+            Self::HammerAndPick => 28,
+            Self::Harp => 53,
+            Self::HeartRed => 29,
+            Self::HourglassDone => 30,
+            Self::HourglassNotDone => 31,
+            Self::Jar => 32,
+            Self::Key => 33,
+            Self::Leaf => 34,
+            Self::LeafFallen => 35,
+            Self::LeafMaple => 36,
+            Self::LightBulb => 61,
+            Self::Map => 37,
+            Self::MeatOnBone => 38,
+            Self::MeatCut => 39,
+            Self::MouseTrap => 55,
+            Self::Pick => 40,
+            Self::PoultryLeg => 41,
+            Self::PrayerBeads => 42,
+            Self::RedEnvelope => 43,
+            Self::RedLantern => 44,
+            Self::Rock => 45,
+            Self::Scroll => 46,
+            Self::Shield => 47,
+            Self::Shovel => 54,
+            Self::SpiderWeb => 56,
+            Self::SwordsCrossed => 48,
+            Self::Trident => 49,
+            Self::Urn => 50,
+            Self::Wand => 51,
+            Self::WaterDrop => 52,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Flora {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Cactus => 0,
+            Self::Clover => 1,
+            Self::FlowerWilted => 2,
+            Self::Herb => 3,
+            Self::Mushroom => 4,
+            Self::Rose => 5,
+            Self::Sheaf => 6,
+            Self::Sunflower => 7,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl BodyPart {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Brain => 0,
+            Self::Ear => 1,
+            Self::Eye => 2,
+            Self::Footprints => 3,
+            Self::Lips => 4,
+            Self::Muscle => 5,
+            Self::Tooth => 6,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl Symbol {
+    /// Returns the stable identifier of this variant.
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Anger => 0,
+            Self::Comet => 1,
+            Self::Cyclone => 2,
+            Self::Fingerprint => 18,
+            Self::Fire => 3,
+            Self::Electricity => 4,
+            Self::ExclamationDouble => 5,
+            Self::ExclamationWithQuestion => 6,
+            Self::ExclamationRed => 7,
+            Self::ExclamationWhite => 8,
+            Self::GenderFemale => 9,
+            Self::GenderMale => 10,
+            Self::QuestionRed => 11,
+            Self::QuestionWhite => 12,
+            Self::Silhouette => 22,
+            Self::Silhouettes => 23,
+            Self::Sparkles => 13,
+            Self::SpeechBubble => 14,
+            Self::SpeechBubbleAngry => 15,
+            Self::Snowflake => 16,
+            Self::SpeechBubbleLeft => 20,
+            Self::Splatter => 19,
+            Self::ThoughtBubble => 21,
+            Self::Zzz => 17,
+        }
+    }
+
+    /// Returns the variant with the given stable identifier, if any.
+    pub fn from_id(id: u16) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_unique(ids: impl Iterator<Item = u16>) {
+        let mut ids: Vec<_> = ids.collect();
+        let len = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), len, "identifiers must be unique");
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        assert_unique(Person::ALL.iter().map(Person::id));
+        assert_unique(SkinTone::ALL.iter().map(SkinTone::id));
+        assert_unique(Gender::ALL.iter().map(Gender::id));
+        assert_unique(Creature::ALL.iter().map(Creature::id));
+        assert_unique(Location::ALL.iter().map(Location::id));
+        assert_unique(Item::ALL.iter().map(Item::id));
+        assert_unique(Flora::ALL.iter().map(Flora::id));
+        assert_unique(BodyPart::ALL.iter().map(BodyPart::id));
+        assert_unique(Symbol::ALL.iter().map(Symbol::id));
+    }
+
+    #[test]
+    fn test_ids_are_stable() {
+        // These must never change; new variants receive new identifiers.
+        assert_eq!(Person::Artist.id(), 0);
+        assert_eq!(Person::HeadScarfPerson.id(), 8);
+        assert_eq!(Creature::Dragon.id(), 15);
+        assert_eq!(Location::Castle.id(), 4);
+        assert_eq!(Item::Coin.id(), 16);
+        assert_eq!(Symbol::Zzz.id(), 17);
+    }
+
+    #[test]
+    fn test_stable_hash32() {
+        let mut hashes: Vec<_> = crate::glyph::all().map(|e| e.stable_hash32()).collect();
+        let len = hashes.len();
+        hashes.sort_unstable();
+        hashes.dedup();
+        assert_eq!(hashes.len(), len, "hashes must be unique");
+
+        // These must never change.
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xe40c_292c);
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(elf.stable_hash32(), 0xa50e_c80a);
+        assert_eq!(Emoji::Item(Item::Coin).stable_hash32(), 0x2970_0472);
+        assert_eq!(Emoji::from_stable_hash32(elf.stable_hash32()), Some(elf));
+    }
+
+    #[test]
+    fn test_from_id() {
+        for item in Item::ALL {
+            assert_eq!(Item::from_id(item.id()), Some(*item));
+        }
+        assert_eq!(Item::from_id(u16::MAX), None);
+    }
+}
@@ -0,0 +1,148 @@
+//! Inventory stacks, which render an [`Item`] with a quantity, e.g. "🪙×42".
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::inventory::{CountStyle, Stack};
+//! use mythoji::Item;
+//!
+//! let coins = Stack::new(Item::Coin, 42);
+//! assert_eq!(coins.to_string(), "🪙×42");
+//! assert_eq!(coins.style(CountStyle::Keycap).to_string(), "🪙 4️⃣2️⃣");
+//! ```
+//!
+//! Stacks respect the width and alignment of the formatter, measured in terminal columns (where an
+//! emoji is two columns wide), so inventory screens line up:
+//!
+//! ```
+//! use mythoji::inventory::Stack;
+//! use mythoji::Item;
+//!
+//! assert_eq!(format!("[{:<7}]", Stack::new(Item::Coin, 42)), "[🪙×42  ]");
+//! assert_eq!(format!("[{:>7}]", Stack::new(Item::Key, 1)), "[   🗝️×1]");
+//! ```
+
+use std::fmt::{Alignment, Display, Formatter, Result, Write};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::Item;
+
+/// A quantity of an [`Item`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stack {
+    item: Item,
+    count: u32,
+    style: CountStyle,
+}
+
+impl Stack {
+    /// Creates a stack of the given item and quantity.
+    pub const fn new(item: Item, count: u32) -> Self {
+        Self {
+            item,
+            count,
+            style: CountStyle::Times,
+        }
+    }
+
+    /// Renders the quantity in the given style.
+    pub const fn style(mut self, style: CountStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the item.
+    pub const fn item(&self) -> Item {
+        self.item
+    }
+
+    /// Returns the quantity.
+    pub const fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the width of the stack in terminal columns, assuming emojis are two columns wide.
+    pub fn width(&self) -> usize {
+        let digits = self.count.checked_ilog10().unwrap_or(0) as usize + 1;
+        match self.style {
+            CountStyle::Times => 2 + 1 + digits,
+            CountStyle::Keycap => 2 + 1 + digits * 2,
+        }
+    }
+
+    fn fmt_unpadded(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.item)?;
+        match self.style {
+            CountStyle::Times => write!(f, "×{}", self.count),
+            CountStyle::Keycap => {
+                f.write_char(' ')?;
+                for digit in self.count.to_string().chars() {
+                    write!(f, "{}\u{fe0f}\u{20e3}", digit)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Stack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let padding = f.width().unwrap_or(0).saturating_sub(self.width());
+        let (before, after) = match f.align() {
+            None | Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.fmt_unpadded(f)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// How the quantity of a [`Stack`] is rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum CountStyle {
+    /// A multiplication sign followed by the quantity, e.g. "🪙×42".
+    #[default]
+    Times,
+
+    /// A space followed by the quantity as keycap emojis, e.g. "🪙 4️⃣2️⃣".
+    Keycap,
+}
+
+impl_all!(CountStyle, [Times, Keycap]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack() {
+        let stack = Stack::new(Item::Coin, 0);
+        assert_eq!(stack.to_string(), "🪙×0");
+        assert_eq!(stack.width(), 4);
+        assert_eq!(stack.item(), Item::Coin);
+        assert_eq!(stack.count(), 0);
+
+        let stack = Stack::new(Item::Coin, 105).style(CountStyle::Keycap);
+        assert_eq!(stack.to_string(), "🪙 1️⃣0️⃣5️⃣");
+        assert_eq!(stack.width(), 9);
+    }
+
+    #[test]
+    fn test_stack_padding() {
+        let stack = Stack::new(Item::Coin, 42);
+        assert_eq!(format!("{:5}", stack), "🪙×42");
+        assert_eq!(format!("{:^9}", stack), "  🪙×42  ");
+        assert_eq!(format!("{:->8}", stack), "---🪙×42");
+    }
+}
@@ -0,0 +1,340 @@
+//! Short labels, which abbreviate every emoji as one or two ASCII characters.
+//!
+//! Labels are curated to be unique within each enum, e.g. "Mg" for [`Person::Mage`] and "Dr" for
+//! [`Creature::Dragon`], for ultra-compact interfaces such as minimap legends and debug overlays,
+//! where even single-character fallbacks collide.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{Creature, Emoji, Location, Person};
+//!
+//! assert_eq!(Person::Mage.female().short_label(), "Mg");
+//! assert_eq!(Emoji::Creature(Creature::Dragon).short_label(), "Dr");
+//! assert_eq!(Location::Castle.short_label(), "Ca");
+//! ```
+
+use crate::{BodyPart, Creature, Emoji, Flora, Item, Location, Person, Symbol};
+
+impl Emoji {
+    /// Returns a one or two character ASCII abbreviation of this emoji, e.g. "Dr" for a dragon.
+    ///
+    /// Labels are unique within each category, but not across categories, and persons are labeled
+    /// regardless of their skin tone and gender.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Emoji::Person(person, _, _) => person.short_label(),
+            Emoji::Creature(creature) => creature.short_label(),
+            Emoji::Location(location) => location.short_label(),
+            Emoji::Item(item) => item.short_label(),
+            Emoji::Flora(flora) => flora.short_label(),
+            Emoji::BodyPart(body_part) => body_part.short_label(),
+            Emoji::Symbol(symbol) => symbol.short_label(),
+        }
+    }
+}
+
+impl Person {
+    /// Returns a one or two character ASCII abbreviation, which is unique among persons.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Artist => "Ar",
+            Self::Baby => "Ba",
+            Self::BaldPerson => "BP",
+            Self::BeardedPerson => "Be",
+            Self::Child => "Ch",
+            Self::Fairy => "Fa",
+            Self::Elf => "El",
+            Self::Genie => "Ge",
+            Self::HeadScarfPerson => "HP",
+            Self::Mage => "Mg",
+            Self::MerPerson => "MP",
+            Self::OldPerson => "OP",
+            Self::Person => "Pe",
+            Self::Royalty => "Ro",
+            Self::SkullCapPerson => "SP",
+            Self::TurbanPerson => "TP",
+            Self::Vampire => "Va",
+            Self::Zombie => "Zo",
+        }
+    }
+}
+
+impl Creature {
+    /// Returns a one or two character ASCII abbreviation, which is unique among creatures.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Ant => "An",
+            Self::Badger => "Ba",
+            Self::Bat => "Bt",
+            Self::Bear => "Be",
+            Self::Beaver => "Bv",
+            Self::Beetle => "Bl",
+            Self::Bison => "Bi",
+            Self::BlackBird => "BB",
+            Self::Blowfish => "Bw",
+            Self::Boar => "Bo",
+            Self::Bug => "Bu",
+            Self::Butterfly => "Br",
+            Self::Camel => "Ca",
+            Self::Cat => "Ct",
+            Self::Cockroach => "Co",
+            Self::Cow => "Cw",
+            Self::Crab => "Cr",
+            Self::Cricket => "Cc",
+            Self::Crocodile => "Cd",
+            Self::Deer => "De",
+            Self::Dog => "Do",
+            Self::Dolphin => "Dl",
+            Self::Donkey => "Dn",
+            Self::Dove => "Dv",
+            Self::Dragon => "Dr",
+            Self::Eagle => "Ea",
+            Self::Elephant => "El",
+            Self::Fish => "Fi",
+            Self::Fly => "Fl",
+            Self::Fox => "Fo",
+            Self::Frog => "Fr",
+            Self::Ghost => "Gh",
+            Self::Goat => "Go",
+            Self::Goblin => "Gb",
+            Self::Gorilla => "Gr",
+            Self::Hedgehog => "He",
+            Self::Honeybee => "Ho",
+            Self::Horse => "Hr",
+            Self::Jellyfish => "Je",
+            Self::Leopard => "Le",
+            Self::Lion => "Li",
+            Self::Lizard => "Lz",
+            Self::Llama => "Ll",
+            Self::Mammoth => "Ma",
+            Self::Monkey => "Mo",
+            Self::Moose => "Ms",
+            Self::Mosquito => "Mq",
+            Self::Mouse => "Mu",
+            Self::Octopus => "Oc",
+            Self::Ogre => "Og",
+            Self::Otter => "Ot",
+            Self::Owl => "Ow",
+            Self::Ox => "Ox",
+            Self::Parrot => "Pa",
+            Self::Peacock => "Pe",
+            Self::Pig => "Pi",
+            Self::Rabbit => "Ra",
+            Self::Raccoon => "Rc",
+            Self::Ram => "Rm",
+            Self::Rat => "Rt",
+            Self::Rhinoceros => "Rh",
+            Self::Rooster => "Ro",
+            Self::Scorpion => "Sc",
+            Self::Seal => "Se",
+            Self::Shark => "Sh",
+            Self::Sheep => "Sp",
+            Self::Snail => "Sn",
+            Self::Snake => "Sk",
+            Self::Spider => "Sd",
+            Self::Squid => "Sq",
+            Self::Swan => "Sw",
+            Self::Tiger => "Ti",
+            Self::TropicalFish => "TF",
+            Self::Turkey => "Tu",
+            Self::Turtle => "Tr",
+            Self::WaterBuffalo => "WB",
+            Self::Whale => "Wh",
+            Self::Wolf => "Wo",
+            Self::Worm => "Wr",
+        }
+    }
+}
+
+impl Location {
+    /// Returns a one or two character ASCII abbreviation, which is unique among locations.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::BoatSail => "BS",
+            Self::BuildingClassic => "BC",
+            Self::Campsite => "Cm",
+            Self::Canoe => "Cn",
+            Self::Castle => "Ca",
+            Self::CastleJapanese => "CJ",
+            Self::Cave => "Cv",
+            Self::Desert => "De",
+            Self::Hut => "Hu",
+            Self::Mountain => "Mo",
+            Self::MountainSnow => "MS",
+            Self::Oasis => "Oa",
+            Self::Palace => "Pa",
+            Self::Tent => "Te",
+            Self::TreeDeciduous => "TD",
+            Self::TreeEvergreen => "TE",
+            Self::TreeLeafless => "TL",
+            Self::TreePalm => "TP",
+            Self::Volcano => "Vo",
+        }
+    }
+}
+
+impl Item {
+    /// Returns a one or two character ASCII abbreviation, which is unique among items.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Amulet => "Am",
+            Self::Axe => "Ax",
+            Self::Bag => "Ba",
+            Self::Bandage => "Bn",
+            Self::Bed => "Be",
+            Self::Beer => "Br",
+            Self::BloodDrop => "BD",
+            Self::Bomb => "Bo",
+            Self::BookClosed => "BC",
+            Self::BookOpen => "BO",
+            Self::Boomerang => "Bm",
+            Self::BowAndArrow => "BA",
+            Self::Brick => "Bc",
+            Self::Candle => "Ca",
+            Self::Coat => "Co",
+            Self::Coffin => "Cf",
+            Self::Coin => "Cn",
+            Self::Crown => "Cr",
+            Self::CrystalBall => "CB",
+            Self::Dagger => "Da",
+            Self::DiyaLamp => "DL",
+            Self::Dart => "Dr",
+            Self::Door => "Do",
+            Self::FlagBlack => "FB",
+            Self::FlagCheckered => "FC",
+            Self::FlagsCrossed => "FX",
+            Self::FlagTriangle => "FT",
+            Self::Flashlight => "Fl",
+            Self::Firecracker => "Fi",
+            Self::GemStone => "GS",
+            Self::Grave => "Gr",
+            Self::Hammer => "Ha",
+            Self::HammerAndPick => "HP",
+            Self::Harp => "Hr",
+            Self::HeartRed => "HR",
+            Self::HourglassDone => "HD",
+            Self::HourglassNotDone => "HN",
+            Self::Jar => "Ja",
+            Self::Key => "Ke",
+            Self::Leaf => "Le",
+            Self::LeafFallen => "LF",
+            Self::LeafMaple => "LM",
+            Self::LightBulb => "LB",
+            Self::Map => "Ma",
+            Self::MeatOnBone => "MB",
+            Self::MeatCut => "MC",
+            Self::MouseTrap => "MT",
+            Self::Pick => "Pi",
+            Self::PoultryLeg => "PL",
+            Self::PrayerBeads => "PB",
+            Self::RedEnvelope => "RE",
+            Self::RedLantern => "RL",
+            Self::Rock => "Ro",
+            Self::Scroll => "Sc",
+            Self::Shield => "Sh",
+            Self::Shovel => "Sv",
+            Self::SpiderWeb => "SW",
+            Self::SwordsCrossed => "SC",
+            Self::Trident => "Tr",
+            Self::Urn => "Ur",
+            Self::Wand => "Wa",
+            Self::WaterDrop => "WD",
+        }
+    }
+}
+
+impl Flora {
+    /// Returns a one or two character ASCII abbreviation, which is unique among flora.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Cactus => "Ca",
+            Self::Clover => "Cl",
+            Self::FlowerWilted => "FW",
+            Self::Herb => "He",
+            Self::Mushroom => "Mu",
+            Self::Rose => "Ro",
+            Self::Sheaf => "Sh",
+            Self::Sunflower => "Su",
+        }
+    }
+}
+
+impl BodyPart {
+    /// Returns a one or two character ASCII abbreviation, which is unique among body parts.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Brain => "Br",
+            Self::Ear => "Ea",
+            Self::Eye => "Ey",
+            Self::Footprints => "Fo",
+            Self::Lips => "Li",
+            Self::Muscle => "Mu",
+            Self::Tooth => "To",
+        }
+    }
+}
+
+impl Symbol {
+    /// Returns a one or two character ASCII abbreviation, which is unique among symbols.
+    pub const fn short_label(&self) -> &'static str {
+        match self {
+            Self::Anger => "An",
+            Self::Comet => "Co",
+            Self::Cyclone => "Cy",
+            Self::Fingerprint => "Fi",
+            Self::Fire => "Fr",
+            Self::Electricity => "El",
+            Self::ExclamationDouble => "ED",
+            Self::ExclamationWithQuestion => "EQ",
+            Self::ExclamationRed => "ER",
+            Self::ExclamationWhite => "EW",
+            Self::GenderFemale => "GF",
+            Self::GenderMale => "GM",
+            Self::QuestionRed => "QR",
+            Self::QuestionWhite => "QW",
+            Self::Silhouette => "Si",
+            Self::Silhouettes => "Sl",
+            Self::Sparkles => "Sp",
+            Self::SpeechBubble => "SB",
+            Self::SpeechBubbleAngry => "SA",
+            Self::Snowflake => "Sn",
+            Self::SpeechBubbleLeft => "SL",
+            Self::Splatter => "St",
+            Self::ThoughtBubble => "TB",
+            Self::Zzz => "Zz",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_unique(labels: impl Iterator<Item = &'static str>) {
+        let mut labels: Vec<_> = labels.collect();
+        for label in &labels {
+            assert!(matches!(label.len(), 1..=2), "{}", label);
+            assert!(
+                label.chars().all(|c| c.is_ascii_alphanumeric()),
+                "{}",
+                label
+            );
+        }
+        let len = labels.len();
+        labels.sort();
+        labels.dedup();
+        assert_eq!(labels.len(), len);
+    }
+
+    #[test]
+    fn test_short_labels() {
+        assert_unique(Person::iter().map(|person| person.short_label()));
+        assert_unique(Creature::iter().map(|creature| creature.short_label()));
+        assert_unique(Location::iter().map(|location| location.short_label()));
+        assert_unique(Item::iter().map(|item| item.short_label()));
+        assert_unique(Flora::iter().map(|flora| flora.short_label()));
+        assert_unique(BodyPart::iter().map(|body_part| body_part.short_label()));
+        assert_unique(Symbol::iter().map(|symbol| symbol.short_label()));
+    }
+}
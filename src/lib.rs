@@ -20,17 +20,154 @@
 //!
 //! # Features
 //!
-//! - `iter`: Enables the `EnumIter` derive macro for all enums. _Disabled_ by default.
-
-use std::fmt::{Display, Formatter, Result};
+//! - `async-graphql`: Implements `async_graphql::Enum` for all enums without data, so they can be
+//!   exposed in a GraphQL schema. _Disabled_ by default.
+//! - `clap`: Implements `clap::ValueEnum` for all enums without data, so they can be used as
+//!   command-line arguments, e.g. `--creature dragon`. _Disabled_ by default.
+//! - `cursive`: Enables a view that displays emojis in the cursive TUI library with correct widths,
+//!   see [`cursive`](mod@cursive). _Disabled_ by default.
+//! - `defmt`: Implements `defmt::Format` for the emoji enums, [`Category`], [`UnicodeGroup`],
+//!   [`UnicodeVersion`], and the enums of game state (e.g. [`Rarity`](rarity::Rarity)), which log
+//!   the names of variants (not glyphs), e.g. over RTT on embedded targets. _Disabled_ by default.
+//! - `emojis`: Enables conversions to and from the general-purpose catalog of the `emojis` crate,
+//!   see [`emojis`](mod@emojis). _Disabled_ by default.
+//! - `graphics`: Enables displaying emojis as inline images on terminals that support the kitty or
+//!   iTerm2 graphics protocols, see [`graphics`]. _Disabled_ by default.
+//! - `iter`: Enables the `EnumIter` derive macro for all enums. _Disabled_ by default. Every enum
+//!   also has a built-in `iter()` function, which does not require this feature.
+//! - `pack`: Enables loading custom emojis from TOML or JSON at runtime, see [`pack`]. Enables the
+//!   `serde` feature. _Disabled_ by default.
+//! - `prost`: Enables protocol buffer definitions for all enums, and conversions to and from them,
+//!   see [`proto`]. _Disabled_ by default.
+//! - `rand`: Enables random generation, e.g. [`RandomPerson`]. _Disabled_ by default.
+//! - `serde`: Enables serialization, following the format documented in [`schema`]. _Disabled_ by
+//!   default.
+//! - `sqlx`: Enables storing emojis in SQL databases as text, see [`sql`]. _Disabled_ by default.
+//! - `utoipa`: Implements `utoipa::ToSchema` for all enums, matching the `serde` format (which this
+//!   feature enables), so they can be documented in an OpenAPI specification. _Disabled_ by default.
+
+use std::fmt::{Display, Formatter, Result, Write};
+
+use components::{VS16, ZWJ};
+
+/// Implements `ALL` and `iter()` for an enum whose variants are all listed, in declaration order.
+///
+/// Listing a variant twice or forgetting one fails to compile, so `ALL` is always exhaustive.
+macro_rules! impl_all {
+    ($ty:ident, [$($variant:ident),* $(,)?]) => {
+        impl $ty {
+            /// All variants, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Returns an iterator over all variants, in declaration order.
+            ///
+            /// Unlike the `iter` feature, this does not require any dependencies.
+            pub fn iter() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            #[deny(unreachable_patterns)]
+            fn exhaustive(variant: $ty) {
+                match variant {
+                    $($ty::$variant => {})*
+                }
+            }
+        };
+    };
+}
 
+pub mod animation;
+pub mod banner;
+pub mod combat;
+pub mod compat;
+pub mod components;
+pub mod composite;
+#[cfg(feature = "cursive")]
+pub mod cursive;
+mod data;
+pub mod dialogue;
+#[cfg(feature = "emojis")]
+pub mod emojis;
+pub mod encounter;
+pub mod event;
+pub mod fallback;
+pub mod frame;
+pub mod gauge;
+mod glyph;
+#[cfg(feature = "graphics")]
+pub mod graphics;
+pub mod grid;
+mod group;
+pub mod heart;
+mod id;
+pub mod inventory;
+mod label;
+pub mod loot;
+pub mod magnitude;
+mod metadata;
+pub mod minimap;
+mod normalization;
+#[cfg(feature = "pack")]
+pub mod pack;
+mod parse;
+pub mod party;
+pub mod prelude;
+#[cfg(feature = "prost")]
+pub mod proto;
+mod query;
+pub mod quest;
+#[cfg(feature = "rand")]
+mod random;
+pub mod rarity;
+pub mod render;
+pub mod schema;
+pub mod season;
+mod seeded;
+mod self_check;
+mod shortcode;
+#[cfg(feature = "sqlx")]
+pub mod sql;
+pub mod status;
+pub mod stream;
+pub mod terrain;
+pub mod theme;
+mod width;
+
+pub use data::EmojiData;
+pub use group::UnicodeGroup;
+pub use metadata::{Category, Metadata, UnicodeVersion};
+pub use normalization::{verify_normalization, NormalizationError};
+pub use parse::ParseNameError;
+pub use query::EmojiQuery;
+#[cfg(feature = "rand")]
+pub use random::RandomPerson;
+pub use self_check::{self_check, Mismatch};
+pub use width::{truncate, wrap};
+
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "iter")]
 use strum_macros::EnumIter;
 
 /// A collection of all emojis that might be used in a fantasy text-based game.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Emoji {
     /// Contains all person emojis that can be used with different genders and skin tones.
+    ///
+    /// Skin tones and genders that the person does not support are ignored when displayed; see
+    /// [`Person::supports_skin_tone`] and [`Person::supports_gender`].
     Person(Person, SkinTone, Gender),
 
     /// Contains all other living emojis that don't fit in [`Person`].
@@ -41,6 +178,15 @@ pub enum Emoji {
 
     /// Contains all item emojis.
     Item(Item),
+
+    /// Contains all plant and fungus emojis.
+    Flora(Flora),
+
+    /// Contains all body part emojis.
+    BodyPart(BodyPart),
+
+    /// Contains all symbol emojis, e.g. effects and punctuation.
+    Symbol(Symbol),
 }
 
 impl Default for Emoji {
@@ -49,40 +195,316 @@ impl Default for Emoji {
     }
 }
 
-impl Display for Emoji {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+impl Emoji {
+    /// Returns a value that displays this emoji with the given [`Qualification`].
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Person, Qualification, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female);
+    /// assert_eq!(elf.qualified(Qualification::Full).to_string(), "🧝\u{200d}♀\u{fe0f}");
+    /// assert_eq!(elf.qualified(Qualification::Minimal).to_string(), "🧝\u{200d}♀");
+    /// ```
+    pub const fn qualified(&self, qualification: Qualification) -> Qualified {
+        Qualified {
+            emoji: *self,
+            qualification,
+        }
+    }
+
+    /// Returns this emoji with a neutral skin tone and gender, e.g. to group characters by archetype.
+    ///
+    /// Emojis other than [`Emoji::Person`] are returned unchanged.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Person, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+    /// assert_eq!(elf.base(), Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Neutral));
+    /// ```
+    pub const fn base(&self) -> Emoji {
+        self.strip_skin_tone().strip_gender()
+    }
+
+    /// Returns this emoji with a neutral skin tone, keeping its gender.
+    ///
+    /// This is useful to fall back to when skin tone modifiers don't render, e.g. in older fonts.
+    pub const fn strip_skin_tone(&self) -> Emoji {
+        self.with_skin_tone(SkinTone::Neutral)
+    }
+
+    /// Returns this emoji with a neutral gender, keeping its skin tone.
+    ///
+    /// This is useful to fall back to when gendered sequences don't render, e.g. in older fonts.
+    pub const fn strip_gender(&self) -> Emoji {
+        self.with_gender(Gender::Neutral)
+    }
+
+    /// Returns the person, if this is an [`Emoji::Person`].
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji, Gender, Person, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+    /// assert_eq!(elf.person(), Some(Person::Elf));
+    /// assert_eq!(elf.skin_tone(), Some(SkinTone::Dark));
+    /// assert_eq!(elf.gender(), Some(Gender::Female));
+    /// assert_eq!(Emoji::Creature(Creature::Dragon).person(), None);
+    /// ```
+    pub const fn person(&self) -> Option<Person> {
         match self {
-            Emoji::Person(person, skin, gender) => {
-                const ZWJ: char = '\u{200d}';
-                const VARIATION_SELECTOR_16: char = '\u{fe0f}';
+            Emoji::Person(person, _, _) => Some(*person),
+            _ => None,
+        }
+    }
 
-                let mut buffer = person.to_string();
+    /// Returns the skin tone, if this is an [`Emoji::Person`].
+    ///
+    /// The skin tone is returned even if the person does not support it.
+    pub const fn skin_tone(&self) -> Option<SkinTone> {
+        match self {
+            Emoji::Person(_, skin, _) => Some(*skin),
+            _ => None,
+        }
+    }
 
-                if gender != &Gender::Neutral {
-                    buffer.push(ZWJ);
-                    buffer.push_str(&gender.to_string());
-                }
-                if skin != &SkinTone::Neutral {
-                    buffer.push(ZWJ);
-                    buffer.push_str(&skin.to_string());
+    /// Returns the gender, if this is an [`Emoji::Person`].
+    ///
+    /// The gender is returned even if the person does not support it.
+    pub const fn gender(&self) -> Option<Gender> {
+        match self {
+            Emoji::Person(_, _, gender) => Some(*gender),
+            _ => None,
+        }
+    }
+
+    /// Returns this emoji with the given skin tone, if it is an [`Emoji::Person`].
+    ///
+    /// Emojis other than [`Emoji::Person`] are returned unchanged.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Person, SkinTone};
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Neutral)
+    ///     .with_skin_tone(SkinTone::Dark)
+    ///     .with_gender(Gender::Female);
+    /// assert_eq!(elf, Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female));
+    /// ```
+    pub const fn with_skin_tone(&self, skin: SkinTone) -> Emoji {
+        match *self {
+            Emoji::Person(person, _, gender) => Emoji::Person(person, skin, gender),
+            emoji => emoji,
+        }
+    }
+
+    /// Returns this emoji with the given gender, if it is an [`Emoji::Person`].
+    ///
+    /// Emojis other than [`Emoji::Person`] are returned unchanged.
+    pub const fn with_gender(&self, gender: Gender) -> Emoji {
+        match *self {
+            Emoji::Person(person, skin, _) => Emoji::Person(person, skin, gender),
+            emoji => emoji,
+        }
+    }
+
+    /// Returns whether this is an [`Emoji::Person`].
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let dragon = Emoji::Creature(Creature::Dragon);
+    /// assert!(dragon.is_creature());
+    /// assert!(!dragon.is_person());
+    /// assert_eq!(dragon.as_creature(), Some(Creature::Dragon));
+    /// assert_eq!(dragon.as_item(), None);
+    /// ```
+    pub const fn is_person(&self) -> bool {
+        matches!(self, Emoji::Person(..))
+    }
+
+    /// Returns whether this is an [`Emoji::Creature`].
+    pub const fn is_creature(&self) -> bool {
+        matches!(self, Emoji::Creature(_))
+    }
+
+    /// Returns whether this is an [`Emoji::Location`].
+    pub const fn is_location(&self) -> bool {
+        matches!(self, Emoji::Location(_))
+    }
+
+    /// Returns whether this is an [`Emoji::Item`].
+    pub const fn is_item(&self) -> bool {
+        matches!(self, Emoji::Item(_))
+    }
+
+    /// Returns whether this is an [`Emoji::Flora`].
+    pub const fn is_flora(&self) -> bool {
+        matches!(self, Emoji::Flora(_))
+    }
+
+    /// Returns whether this is an [`Emoji::BodyPart`].
+    pub const fn is_body_part(&self) -> bool {
+        matches!(self, Emoji::BodyPart(_))
+    }
+
+    /// Returns whether this is an [`Emoji::Symbol`].
+    pub const fn is_symbol(&self) -> bool {
+        matches!(self, Emoji::Symbol(_))
+    }
+
+    /// Returns the creature, if this is an [`Emoji::Creature`].
+    pub const fn as_creature(&self) -> Option<Creature> {
+        match self {
+            Emoji::Creature(creature) => Some(*creature),
+            _ => None,
+        }
+    }
+
+    /// Returns the location, if this is an [`Emoji::Location`].
+    pub const fn as_location(&self) -> Option<Location> {
+        match self {
+            Emoji::Location(location) => Some(*location),
+            _ => None,
+        }
+    }
+
+    /// Returns the item, if this is an [`Emoji::Item`].
+    pub const fn as_item(&self) -> Option<Item> {
+        match self {
+            Emoji::Item(item) => Some(*item),
+            _ => None,
+        }
+    }
+
+    /// Returns the flora, if this is an [`Emoji::Flora`].
+    pub const fn as_flora(&self) -> Option<Flora> {
+        match self {
+            Emoji::Flora(flora) => Some(*flora),
+            _ => None,
+        }
+    }
+
+    /// Returns the body part, if this is an [`Emoji::BodyPart`].
+    pub const fn as_body_part(&self) -> Option<BodyPart> {
+        match self {
+            Emoji::BodyPart(body_part) => Some(*body_part),
+            _ => None,
+        }
+    }
+
+    /// Returns the symbol, if this is an [`Emoji::Symbol`].
+    pub const fn as_symbol(&self) -> Option<Symbol> {
+        match self {
+            Emoji::Symbol(symbol) => Some(*symbol),
+            _ => None,
+        }
+    }
+
+    fn fmt_qualified(&self, f: &mut Formatter<'_>, qualification: Qualification) -> Result {
+        match self {
+            Emoji::Person(person, skin, gender) => {
+                // The skin tone modifier directly follows the first codepoint of the person, even
+                // if the person is itself a sequence (e.g. "🧑🏽‍🎨"), while the gender sign is joined
+                // to the end of the sequence and followed by a variation selector (e.g. "🧝🏽‍♀️"),
+                // unless the person has a distinct gendered glyph (e.g. "👩🏽").
+                let gendered = person.gendered_glyph(gender);
+                let glyph = match gendered {
+                    Some(glyph) => glyph,
+                    None => person.glyph(),
+                };
+                let (first, rest) = glyph.split_at(glyph.chars().next().map_or(0, char::len_utf8));
+                f.write_str(first)?;
+
+                if skin != &SkinTone::Neutral && person.supports_skin_tone() {
+                    f.write_str(skin.as_str())?;
                 }
-                if gender != &Gender::Neutral || skin != &SkinTone::Neutral {
-                    buffer.push(VARIATION_SELECTOR_16);
+                f.write_str(rest)?;
+                if gender != &Gender::Neutral && person.supports_gender() && gendered.is_none() {
+                    f.write_char(ZWJ)?;
+                    f.write_str(gender.as_str())?;
+
+                    // The variation selector is redundant after the first element of a sequence.
+                    if qualification == Qualification::Full {
+                        f.write_char(VS16)?;
+                    }
                 }
-
-                write!(f, "{}", buffer)?;
             }
             Emoji::Creature(creature) => write!(f, "{}", creature)?,
             Emoji::Location(location) => write!(f, "{}", location)?,
             Emoji::Item(item) => write!(f, "{}", item)?,
+            Emoji::Flora(flora) => write!(f, "{}", flora)?,
+            Emoji::BodyPart(body_part) => write!(f, "{}", body_part)?,
+            Emoji::Symbol(symbol) => write!(f, "{}", symbol)?,
         };
         Ok(())
     }
 }
 
+/// Displays the emoji, writing its components directly, so formatting never allocates.
+impl Display for Emoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.fmt_qualified(f, Qualification::Full)
+    }
+}
+
+macro_rules! impl_from {
+    ($($name:ident),*) => {$(
+        impl From<$name> for Emoji {
+            fn from(value: $name) -> Self {
+                Self::$name(value)
+            }
+        }
+    )*};
+}
+
+impl_from!(Creature, Location, Item, Flora, BodyPart, Symbol);
+
+/// How emoji sequences are qualified with variation selectors when displayed.
+///
+/// See [Unicode Technical Standard #51](https://unicode.org/reports/tr51/#def_qualified_emoji_character)
+/// for the exact definitions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Qualification {
+    /// Includes every variation selector, which is the recommended form for display.
+    #[default]
+    Full,
+
+    /// Omits variation selectors that are redundant within a sequence, e.g. after a gender sign.
+    ///
+    /// This saves a few bytes (e.g. in network messages), and avoids some terminals rendering the
+    /// sequence wider than a single emoji, while still being recognized as the same emoji.
+    Minimal,
+}
+
+/// Displays an [`Emoji`] with a specific [`Qualification`].
+///
+/// Created by [`Emoji::qualified`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Qualified {
+    emoji: Emoji,
+    qualification: Qualification,
+}
+
+impl Display for Qualified {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.emoji.fmt_qualified(f, self.qualification)
+    }
+}
+
 /// Emojis that can be used with different genders and skin tones.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Person {
     /// An artist, e.g. "👩‍🎨".
     Artist,
@@ -109,7 +531,9 @@ pub enum Person {
     Genie,
 
     /// A person with a head scarf, e.g. "🧕".
-    HeardScarfPerson,
+    #[cfg_attr(feature = "clap", value(alias = "heard-scarf-person"))]
+    #[cfg_attr(feature = "serde", serde(alias = "heard-scarf-person"))]
+    HeadScarfPerson,
 
     /// A mage, e.g. "🧙".
     Mage,
@@ -140,38 +564,181 @@ pub enum Person {
     Zombie,
 }
 
+impl_all!(
+    Person,
+    [
+        Artist,
+        Baby,
+        BaldPerson,
+        BeardedPerson,
+        Child,
+        Fairy,
+        Elf,
+        Genie,
+        HeadScarfPerson,
+        Mage,
+        MerPerson,
+        OldPerson,
+        Person,
+        Royalty,
+        SkullCapPerson,
+        TurbanPerson,
+        Vampire,
+        Zombie
+    ]
+);
+
+impl Person {
+    /// A person with a head scarf, e.g. "🧕".
+    ///
+    /// This is a deprecated alias of [`Person::HeadScarfPerson`], which was misspelled.
+    #[deprecated(since = "0.2.0", note = "Use `Person::HeadScarfPerson` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const HeardScarfPerson: Person = Person::HeadScarfPerson;
+
+    /// Returns whether the person can be displayed with a [`SkinTone`] other than neutral.
+    ///
+    /// ```
+    /// use mythoji::Person;
+    ///
+    /// assert!(Person::Elf.supports_skin_tone());
+    /// assert!(!Person::Zombie.supports_skin_tone());
+    /// ```
+    pub const fn supports_skin_tone(&self) -> bool {
+        !matches!(self, Self::Genie | Self::Zombie)
+    }
+
+    /// Returns whether the person can be displayed with a [`Gender`] other than neutral.
+    ///
+    /// Most persons are gendered by joining a gender sign (e.g. "🧝‍♀️"), but generic persons have
+    /// distinct gendered codepoints instead (e.g. "👩" for [`Person::Person`]).
+    ///
+    /// ```
+    /// use mythoji::Person;
+    ///
+    /// assert!(Person::Elf.supports_gender());
+    /// assert!(Person::Child.supports_gender());
+    /// assert!(!Person::Baby.supports_gender());
+    /// ```
+    pub const fn supports_gender(&self) -> bool {
+        matches!(
+            self,
+            Self::BeardedPerson
+                | Self::Child
+                | Self::Elf
+                | Self::Fairy
+                | Self::Genie
+                | Self::Mage
+                | Self::MerPerson
+                | Self::OldPerson
+                | Self::Person
+                | Self::TurbanPerson
+                | Self::Vampire
+                | Self::Zombie
+        )
+    }
+
+    /// Returns an [`Emoji::Person`] of this person with the given skin tone and gender.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Person, SkinTone};
+    ///
+    /// assert_eq!(
+    ///     Person::Elf.with(SkinTone::Dark, Gender::Female),
+    ///     Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female),
+    /// );
+    /// assert_eq!(Person::Elf.female().to_string(), "🧝‍♀️");
+    /// ```
+    pub const fn with(&self, skin: SkinTone, gender: Gender) -> Emoji {
+        Emoji::Person(*self, skin, gender)
+    }
+
+    /// Returns an [`Emoji::Person`] of this person with a neutral skin tone and female gender.
+    pub const fn female(&self) -> Emoji {
+        self.with(SkinTone::Neutral, Gender::Female)
+    }
+
+    /// Returns an [`Emoji::Person`] of this person with a neutral skin tone and male gender.
+    pub const fn male(&self) -> Emoji {
+        self.with(SkinTone::Neutral, Gender::Male)
+    }
+
+    /// Returns an [`Emoji::Person`] of this person with a neutral skin tone and gender.
+    pub const fn neutral(&self) -> Emoji {
+        self.with(SkinTone::Neutral, Gender::Neutral)
+    }
+
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Artist => "🧑‍🎨",
+            Self::Baby => "👶",
+            Self::BaldPerson => "🧑‍🦲",
+            Self::BeardedPerson => "🧔",
+            Self::Child => "🧒",
+            Self::Elf => "🧝",
+            Self::Fairy => "🧚",
+            Self::Genie => "🧞",
+            Self::HeadScarfPerson => "🧕",
+            Self::Mage => "🧙",
+            Self::MerPerson => "🧜",
+            Self::OldPerson => "🧓",
+            Self::Person => "🧑",
+            Self::Royalty => "🤴",
+            Self::SkullCapPerson => "👲",
+            Self::TurbanPerson => "👳",
+            Self::Vampire => "🧛",
+            Self::Zombie => "🧟",
+        }
+    }
+
+    /// Returns the distinct glyph for this person in the given gender, if there is one.
+    ///
+    /// Gender signs can't be joined to generic persons (e.g. "🧑‍♀️" is not a valid sequence), so
+    /// they are gendered by a different codepoint instead.
+    pub(crate) const fn gendered_glyph(&self, gender: &Gender) -> Option<&'static str> {
+        match (self, gender) {
+            (Self::Child, Gender::Male) => Some("👦"),
+            (Self::Child, Gender::Female) => Some("👧"),
+            (Self::OldPerson, Gender::Male) => Some("👴"),
+            (Self::OldPerson, Gender::Female) => Some("👵"),
+            (Self::Person, Gender::Male) => Some("👨"),
+            (Self::Person, Gender::Female) => Some("👩"),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Person {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Artist => "🧑‍🎨",
-                Self::Baby => "👶",
-                Self::BaldPerson => "🧑‍🦲",
-                Self::BeardedPerson => "🧔",
-                Self::Child => "🧒",
-                Self::Elf => "🧝",
-                Self::Fairy => "🧚",
-                Self::Genie => "🧞",
-                Self::HeardScarfPerson => "🧕",
-                Self::Mage => "🧙",
-                Self::MerPerson => "🧜",
-                Self::OldPerson => "🧓",
-                Self::Person => "🧑",
-                Self::Royalty => "🤴",
-                Self::SkullCapPerson => "👲",
-                Self::TurbanPerson => "👳",
-                Self::Vampire => "🧛",
-                Self::Zombie => "🧟",
-            }
-        )
+        f.write_str(self.glyph())
     }
 }
 
 /// Skin tones that can be used with certain emojis.
+///
+/// Displaying a skin tone on its own writes the bare skin tone modifier (or nothing, for
+/// [`SkinTone::Neutral`]), which is a building block for composing an [`Emoji::Person`] and is not
+/// meant to be shown to users. To display the skin tone itself, e.g. in a character creator, use
+/// [`SkinTone::swatch`] instead:
+///
+/// ```
+/// use mythoji::SkinTone;
+///
+/// assert_eq!(SkinTone::Medium.to_string(), "🏽");
+/// assert_eq!(SkinTone::Medium.swatch(), "✋🏽");
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum SkinTone {
     /// Makes a skin toned emoji appear with a neutral skin tone, which is often "Simpsons yellow".
     #[default]
@@ -195,26 +762,123 @@ pub enum SkinTone {
 
 impl Display for SkinTone {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Neutral => "",
-                Self::Light => "🏻",
-                Self::MediumLight => "🏼",
-                Self::Medium => "🏽",
-                Self::MediumDark => "🏾",
-                Self::Dark => "🏿",
-            }
-        )
+        f.write_str(self.as_str())
+    }
+}
+
+impl_all!(
+    SkinTone,
+    [Neutral, Light, MediumLight, Medium, MediumDark, Dark]
+);
+
+impl SkinTone {
+    /// Returns a standalone glyph that previews this skin tone, e.g. "✋🏽".
+    ///
+    /// Unlike the bare modifier written by [`Display`], which many fonts render inconsistently (or
+    /// not at all) on its own, the swatch applies the skin tone to a raised hand, which renders
+    /// consistently, including for [`SkinTone::Neutral`].
+    pub const fn swatch(&self) -> &'static str {
+        match self {
+            Self::Neutral => "✋",
+            Self::Light => "✋🏻",
+            Self::MediumLight => "✋🏼",
+            Self::Medium => "✋🏽",
+            Self::MediumDark => "✋🏾",
+            Self::Dark => "✋🏿",
+        }
+    }
+
+    /// Returns the bare skin tone modifier written by [`Display`], e.g. "🏽", or an empty string for
+    /// [`SkinTone::Neutral`].
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Neutral => "",
+            Self::Light => "🏻",
+            Self::MediumLight => "🏼",
+            Self::Medium => "🏽",
+            Self::MediumDark => "🏾",
+            Self::Dark => "🏿",
+        }
+    }
+
+    /// Returns the skin tone for a type on the [Fitzpatrick scale][], from `1` (I) to `6` (VI).
+    ///
+    /// As with the Unicode skin tone modifiers, types I and II are both [`SkinTone::Light`].
+    ///
+    /// Returns `None` if `fitzpatrick_type` is not in the range `1..=6`.
+    ///
+    /// [Fitzpatrick scale]: https://en.wikipedia.org/wiki/Fitzpatrick_scale
+    ///
+    /// ```
+    /// use mythoji::SkinTone;
+    ///
+    /// assert_eq!(SkinTone::from_fitzpatrick(2), Some(SkinTone::Light));
+    /// assert_eq!(SkinTone::from_fitzpatrick(6), Some(SkinTone::Dark));
+    /// assert_eq!(SkinTone::from_fitzpatrick(7), None);
+    /// ```
+    pub const fn from_fitzpatrick(fitzpatrick_type: u8) -> Option<SkinTone> {
+        match fitzpatrick_type {
+            1 | 2 => Some(Self::Light),
+            3 => Some(Self::MediumLight),
+            4 => Some(Self::Medium),
+            5 => Some(Self::MediumDark),
+            6 => Some(Self::Dark),
+            _ => None,
+        }
+    }
+
+    /// Returns the type on the [Fitzpatrick scale][], from `1` (I) to `6` (VI).
+    ///
+    /// [`SkinTone::Light`] covers both types I and II, and is reported as `1`, while
+    /// [`SkinTone::Neutral`] has no equivalent and returns `None`.
+    ///
+    /// [Fitzpatrick scale]: https://en.wikipedia.org/wiki/Fitzpatrick_scale
+    ///
+    /// ```
+    /// use mythoji::SkinTone;
+    ///
+    /// assert_eq!(SkinTone::Light.fitzpatrick(), Some(1));
+    /// assert_eq!(SkinTone::Dark.fitzpatrick(), Some(6));
+    /// assert_eq!(SkinTone::Neutral.fitzpatrick(), None);
+    /// ```
+    pub const fn fitzpatrick(&self) -> Option<u8> {
+        match self {
+            Self::Neutral => None,
+            Self::Light => Some(1),
+            Self::MediumLight => Some(3),
+            Self::Medium => Some(4),
+            Self::MediumDark => Some(5),
+            Self::Dark => Some(6),
+        }
     }
 }
 
 /// Genders that can be used with certain emojis.
+///
+/// Displaying a gender on its own writes the bare gender sign without a variation selector (or
+/// nothing, for [`Gender::Neutral`]), which is a building block for composing an [`Emoji::Person`]
+/// and often renders as a text glyph. To display the gender itself, e.g. in a character creator,
+/// use [`Gender::sign`] instead:
+///
+/// ```
+/// use mythoji::Gender;
+///
+/// assert_eq!(Gender::Female.to_string(), "♀");
+/// assert_eq!(Gender::Female.sign(), "♀\u{fe0f}");
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Gender {
-    /// Makes a
+    /// Makes a gendered emoji appear gender neutral.
     #[default]
     Neutral,
 
@@ -225,17 +889,36 @@ pub enum Gender {
     Female,
 }
 
+impl_all!(Gender, [Neutral, Male, Female]);
+
+impl Gender {
+    /// Returns a standalone, fully-qualified glyph for this gender, e.g. "♀️".
+    ///
+    /// Unlike the bare sign written by [`Display`], the glyph includes a variation selector so it
+    /// renders as an emoji. As there is no gender neutral sign, [`Gender::Neutral`] is represented by
+    /// the gender neutral adult, "🧑".
+    pub const fn sign(&self) -> &'static str {
+        match self {
+            Self::Neutral => "🧑",
+            Self::Male => "♂️",
+            Self::Female => "♀️",
+        }
+    }
+
+    /// Returns the bare gender sign written by [`Display`], e.g. "♀", or an empty string for
+    /// [`Gender::Neutral`].
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Neutral => "",
+            Self::Male => "♂",
+            Self::Female => "♀",
+        }
+    }
+}
+
 impl Display for Gender {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Gender::Neutral => "",
-                Gender::Male => "♂",
-                Gender::Female => "♀",
-            }
-        )
+        f.write_str(self.as_str())
     }
 }
 
@@ -244,20 +927,44 @@ impl Display for Gender {
 /// **NOTE**: All emojis are meant to represent the side view, not face, of the creature, _if_ able.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Creature {
     /// An ant, e.g. "🐜".
     #[default]
     Ant,
 
+    /// A badger, e.g. "🦡".
+    Badger,
+
     /// A bat, e.g. "🦇".
     Bat,
 
+    /// A bear, e.g. "🐻".
+    Bear,
+
+    /// A beaver, e.g. "🦫".
+    Beaver,
+
     /// A beetle, e.g. "🐞".
     Beetle,
 
     /// A bison, e.g. "🦬".
     Bison,
 
+    /// A black bird, such as a raven or crow, e.g. "🐦‍⬛".
+    BlackBird,
+
+    /// A blowfish, e.g. "🐡".
+    Blowfish,
+
     /// A boar, e.g. "🐗".
     Boar,
 
@@ -282,6 +989,9 @@ pub enum Creature {
     /// A crab, e.g. "🦀".
     Crab,
 
+    /// A cricket, e.g. "🦗".
+    Cricket,
+
     /// A crocodile, e.g. "🐊".
     Crocodile,
 
@@ -291,6 +1001,15 @@ pub enum Creature {
     /// A dog, e.g. "🐕".
     Dog,
 
+    /// A dolphin, e.g. "🐬".
+    Dolphin,
+
+    /// A donkey, e.g. "🫏".
+    Donkey,
+
+    /// A dove, e.g. "🕊️".
+    Dove,
+
     /// A dragon, e.g. "🐉".
     Dragon,
 
@@ -303,6 +1022,15 @@ pub enum Creature {
     /// A fish, e.g. "🐟".
     Fish,
 
+    /// A fly, e.g. "🪰".
+    Fly,
+
+    /// A fox, e.g. "🦊".
+    Fox,
+
+    /// A frog, e.g. "🐸".
+    Frog,
+
     /// A ghost, e.g. "👻".
     Ghost,
 
@@ -312,33 +1040,78 @@ pub enum Creature {
     /// A goblin, e.g. "👺".
     Goblin,
 
+    /// A gorilla, e.g. "🦍".
+    Gorilla,
+
+    /// A hedgehog, e.g. "🦔".
+    Hedgehog,
+
     /// A honeybee, e.g. "🐝".
     Honeybee,
 
     /// A horse, e.g. "🐎".
     Horse,
 
+    /// A jellyfish, e.g. "🪼".
+    Jellyfish,
+
     /// A leopard, e.g. "🐆".
     Leopard,
 
+    /// A lion, e.g. "🦁".
+    Lion,
+
+    /// A lizard, e.g. "🦎".
+    Lizard,
+
     /// A llama, e.g. "🦙".
     Llama,
 
     /// A mammoth, e.g. "🦣".
     Mammoth,
 
+    /// A monkey, e.g. "🐒".
+    Monkey,
+
+    /// A moose, e.g. "🫎".
+    Moose,
+
+    /// A mosquito, e.g. "🦟".
+    Mosquito,
+
     /// A mouse, e.g. "🐁".
     Mouse,
 
+    /// An octopus, e.g. "🐙".
+    Octopus,
+
     /// An ogre, e.g. "👹".
     Ogre,
 
+    /// An otter, e.g. "🦦".
+    Otter,
+
+    /// An owl, e.g. "🦉".
+    Owl,
+
+    /// An ox, e.g. "🐂".
+    Ox,
+
+    /// A parrot, e.g. "🦜".
+    Parrot,
+
+    /// A peacock, e.g. "🦚".
+    Peacock,
+
     /// A pig, e.g. "🐖".
     Pig,
 
     /// A rabbit, e.g. "🐇".
     Rabbit,
 
+    /// A raccoon, e.g. "🦝".
+    Raccoon,
+
     /// A ram, e.g. "🐏".
     Ram,
 
@@ -348,87 +1121,253 @@ pub enum Creature {
     /// A rhinoceros, e.g. "🦏".
     Rhinoceros,
 
+    /// A rooster, e.g. "🐓".
+    Rooster,
+
     /// A scorpion, e.g. "🦂".
     Scorpion,
 
+    /// A seal, e.g. "🦭".
+    Seal,
+
     /// A shark, e.g. "🦈".
     Shark,
 
+    /// A sheep, e.g. "🐑".
+    ///
+    /// For a male sheep, see [`Creature::Ram`].
+    Sheep,
+
+    /// A snail, e.g. "🐌".
+    Snail,
+
     /// A snake, e.g. "🐍".
     Snake,
 
     /// A spider, e.g. "🕷".
     Spider,
 
+    /// A squid, e.g. "🦑".
+    Squid,
+
+    /// A swan, e.g. "🦢".
+    Swan,
+
     /// A tiger, e.g. "🐅".
     Tiger,
 
     /// A tropical fish, e.g. "🐠".
     TropicalFish,
 
+    /// A turkey, e.g. "🦃".
+    Turkey,
+
+    /// A turtle, e.g. "🐢".
+    Turtle,
+
     /// A water buffalo, e.g. "🐃".
     WaterBuffalo,
 
+    /// A whale, e.g. "🐋".
+    Whale,
+
     /// A wolf, e.g. "🐺".
     Wolf,
+
+    /// A worm, e.g. "🪱".
+    Worm,
+}
+
+impl_all!(
+    Creature,
+    [
+        Ant,
+        Badger,
+        Bat,
+        Bear,
+        Beaver,
+        Beetle,
+        Bison,
+        BlackBird,
+        Blowfish,
+        Boar,
+        Bug,
+        Butterfly,
+        Camel,
+        Cat,
+        Cockroach,
+        Cow,
+        Crab,
+        Cricket,
+        Crocodile,
+        Deer,
+        Dog,
+        Dolphin,
+        Donkey,
+        Dove,
+        Dragon,
+        Eagle,
+        Elephant,
+        Fish,
+        Fly,
+        Fox,
+        Frog,
+        Ghost,
+        Goat,
+        Goblin,
+        Gorilla,
+        Hedgehog,
+        Honeybee,
+        Horse,
+        Jellyfish,
+        Leopard,
+        Lion,
+        Lizard,
+        Llama,
+        Mammoth,
+        Monkey,
+        Moose,
+        Mosquito,
+        Mouse,
+        Octopus,
+        Ogre,
+        Otter,
+        Owl,
+        Ox,
+        Parrot,
+        Peacock,
+        Pig,
+        Rabbit,
+        Raccoon,
+        Ram,
+        Rat,
+        Rhinoceros,
+        Rooster,
+        Scorpion,
+        Seal,
+        Shark,
+        Sheep,
+        Snail,
+        Snake,
+        Spider,
+        Squid,
+        Swan,
+        Tiger,
+        TropicalFish,
+        Turkey,
+        Turtle,
+        WaterBuffalo,
+        Whale,
+        Wolf,
+        Worm
+    ]
+);
+
+impl Creature {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Ant => "🐜",
+            Self::Badger => "🦡",
+            Self::Bat => "🦇",
+            Self::Bear => "🐻",
+            Self::Beaver => "🦫",
+            Self::Beetle => "🐞",
+            Self::Bison => "🦬",
+            Self::BlackBird => "🐦‍⬛",
+            Self::Blowfish => "🐡",
+            Self::Boar => "🐗",
+            Self::Bug => "🐛",
+            Self::Butterfly => "🦋",
+            Self::Camel => "🐫",
+            Self::Cat => "🐈",
+            Self::Cockroach => "🪳",
+            Self::Cow => "🐄",
+            Self::Crab => "🦀",
+            Self::Cricket => "🦗",
+            Self::Crocodile => "🐊",
+            Self::Deer => "🦌",
+            Self::Dog => "🐕",
+            Self::Dolphin => "🐬",
+            Self::Donkey => "🫏",
+            Self::Dove => "🕊️",
+            Self::Dragon => "🐉",
+            Self::Eagle => "🦅",
+            Self::Elephant => "🐘",
+            Self::Fish => "🐟",
+            Self::Fly => "🪰",
+            Self::Fox => "🦊",
+            Self::Frog => "🐸",
+            Self::Ghost => "👻",
+            Self::Goat => "🐐",
+            Self::Goblin => "👺",
+            Self::Gorilla => "🦍",
+            Self::Hedgehog => "🦔",
+            Self::Honeybee => "🐝",
+            Self::Horse => "🐎",
+            Self::Jellyfish => "🪼",
+            Self::Leopard => "🐆",
+            Self::Lion => "🦁",
+            Self::Lizard => "🦎",
+            Self::Llama => "🦙",
+            Self::Mammoth => "🦣",
+            Self::Monkey => "🐒",
+            Self::Moose => "🫎",
+            Self::Mosquito => "🦟",
+            Self::Mouse => "🐁",
+            Self::Octopus => "🐙",
+            Self::Ogre => "👹",
+            Self::Otter => "🦦",
+            Self::Owl => "🦉",
+            Self::Ox => "🐂",
+            Self::Parrot => "🦜",
+            Self::Peacock => "🦚",
+            Self::Pig => "🐖",
+            Self::Rabbit => "🐇",
+            Self::Raccoon => "🦝",
+            Self::Ram => "🐏",
+            Self::Rat => "🐀",
+            Self::Rhinoceros => "🦏",
+            Self::Rooster => "🐓",
+            Self::Scorpion => "🦂",
+            Self::Seal => "🦭",
+            Self::Shark => "🦈",
+            Self::Sheep => "🐑",
+            Self::Snail => "🐌",
+            Self::Snake => "🐍",
+            Self::Spider => "🕷",
+            Self::Squid => "🦑",
+            Self::Swan => "🦢",
+            Self::Tiger => "🐅",
+            Self::TropicalFish => "🐠",
+            Self::Turkey => "🦃",
+            Self::Turtle => "🐢",
+            Self::WaterBuffalo => "🐃",
+            Self::Whale => "🐋",
+            Self::Wolf => "🐺",
+            Self::Worm => "🪱",
+        }
+    }
 }
 
 impl Display for Creature {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Ant => "🐜",
-                Self::Bat => "🦇",
-                Self::Beetle => "🐞",
-                Self::Bison => "🦬",
-                Self::Boar => "🐗",
-                Self::Bug => "🐛",
-                Self::Butterfly => "🦋",
-                Self::Camel => "🐫",
-                Self::Cat => "🐈",
-                Self::Cockroach => "🪳",
-                Self::Cow => "🐄",
-                Self::Crab => "🦀",
-                Self::Crocodile => "🐊",
-                Self::Deer => "🦌",
-                Self::Dog => "🐕",
-                Self::Dragon => "🐉",
-                Self::Eagle => "🦅",
-                Self::Elephant => "🐘",
-                Self::Fish => "🐟",
-                Self::Ghost => "👻",
-                Self::Goat => "🐐",
-                Self::Goblin => "👺",
-                Self::Honeybee => "🐝",
-                Self::Horse => "🐎",
-                Self::Leopard => "🐆",
-                Self::Llama => "🦙",
-                Self::Mammoth => "🦣",
-                Self::Mouse => "🐁",
-                Self::Ogre => "👹",
-                Self::Pig => "🐖",
-                Self::Rabbit => "🐇",
-                Self::Ram => "🐏",
-                Self::Rat => "🐀",
-                Self::Rhinoceros => "🦏",
-                Self::Scorpion => "🦂",
-                Self::Shark => "🦈",
-                Self::Snake => "🐍",
-                Self::Spider => "🕷",
-                Self::Tiger => "🐅",
-                Self::TropicalFish => "🐠",
-                Self::WaterBuffalo => "🐃",
-                Self::Wolf => "🐺",
-            }
-        )
+        f.write_str(self.glyph())
     }
 }
 
 /// Emojis that can be used to represent a location.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Location {
     /// A sailboat, e.g. "⛵".
     BoatSail,
@@ -478,6 +1417,9 @@ pub enum Location {
     /// An evergreen tree, e.g. "🌲".
     TreeEvergreen,
 
+    /// A leafless tree, e.g. "🪾".
+    TreeLeafless,
+
     /// A palm tree, e.g. "🌴".
     TreePalm,
 
@@ -485,38 +1427,76 @@ pub enum Location {
     Volcano,
 }
 
+impl_all!(
+    Location,
+    [
+        BoatSail,
+        BuildingClassic,
+        Campsite,
+        Canoe,
+        Castle,
+        CastleJapanese,
+        Cave,
+        Desert,
+        Hut,
+        Mountain,
+        MountainSnow,
+        Oasis,
+        Palace,
+        Tent,
+        TreeDeciduous,
+        TreeEvergreen,
+        TreeLeafless,
+        TreePalm,
+        Volcano
+    ]
+);
+
+impl Location {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::BoatSail => "⛵",
+            Self::BuildingClassic => "🏛",
+            Self::Campsite => "🏕",
+            Self::Canoe => "🛶",
+            Self::Castle => "🏰",
+            Self::CastleJapanese => "🏯",
+            Self::Cave => "🕳",
+            Self::Desert => "🏜",
+            Self::Hut => "🛖",
+            Self::Mountain => "⛰",
+            Self::MountainSnow => "🏔",
+            Self::Oasis => "🏜",
+            Self::Palace => "🏯",
+            Self::Tent => "⛺",
+            Self::TreeDeciduous => "🌳",
+            Self::TreeEvergreen => "🌲",
+            Self::TreeLeafless => "🪾",
+            Self::TreePalm => "🌴",
+            Self::Volcano => "🌋",
+        }
+    }
+}
+
 impl Display for Location {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::BoatSail => "⛵",
-                Self::BuildingClassic => "🏛",
-                Self::Campsite => "🏕",
-                Self::Canoe => "🛶",
-                Self::Castle => "🏰",
-                Self::CastleJapanese => "🏯",
-                Self::Cave => "🕳",
-                Self::Desert => "🏜",
-                Self::Hut => "🛖",
-                Self::Mountain => "⛰",
-                Self::MountainSnow => "🏔",
-                Self::Oasis => "🏜",
-                Self::Palace => "🏯",
-                Self::Tent => "⛺",
-                Self::TreeDeciduous => "🌳",
-                Self::TreeEvergreen => "🌲",
-                Self::TreePalm => "🌴",
-                Self::Volcano => "🌋",
-            }
-        )
+        f.write_str(self.glyph())
     }
 }
 
 /// Emojis that can be used to represent an item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Item {
     /// An amulet, e.g. "🧿".
     Amulet,
@@ -581,18 +1561,30 @@ pub enum Item {
     /// A dart, e.g. "🎯".
     Dart,
 
+    /// A diya lamp, e.g. "🪔".
+    DiyaLamp,
+
     /// A door, e.g. "🚪".
     Door,
 
     /// A black flag, e.g. "🏴".
     FlagBlack,
 
+    /// A chequered flag, e.g. "🏁".
+    FlagCheckered,
+
     /// A triangular flag, e.g. "🚩".
     FlagTriangle,
 
     /// A firecracker, e.g. "🧨".
     Firecracker,
 
+    /// Crossed flags, e.g. "🎌".
+    FlagsCrossed,
+
+    /// A flashlight, or a torch, e.g. "🔦".
+    Flashlight,
+
     /// A gemstone, e.g. "💎".
     GemStone,
 
@@ -605,6 +1597,9 @@ pub enum Item {
     /// A hammer and pick, e.g. "⚒️".
     HammerAndPick,
 
+    /// A harp, e.g. "🪉".
+    Harp,
+
     /// A red heart, e.g. "❤️".
     HeartRed,
 
@@ -629,6 +1624,9 @@ pub enum Item {
     /// A maple leaf, e.g. "🍁".
     LeafMaple,
 
+    /// A light bulb, e.g. "💡".
+    LightBulb,
+
     /// A map, e.g. "🗺".
     Map,
 
@@ -638,6 +1636,9 @@ pub enum Item {
     /// A cut of meat, e.g. "🥩".
     MeatCut,
 
+    /// A mouse trap, e.g. "🪤".
+    MouseTrap,
+
     /// A pickaxe, e.g. "⛏".
     Pick,
 
@@ -662,6 +1663,12 @@ pub enum Item {
     /// A shield, e.g. "🛡".
     Shield,
 
+    /// A shovel, e.g. "🪏".
+    Shovel,
+
+    /// A spider web, e.g. "🕸️".
+    SpiderWeb,
+
     /// Swords crossed, e.g. "⚔️".
     SwordsCrossed,
 
@@ -678,73 +1685,294 @@ pub enum Item {
     WaterDrop,
 }
 
+impl_all!(
+    Item,
+    [
+        Amulet,
+        Axe,
+        Bag,
+        Bandage,
+        Bed,
+        Beer,
+        BloodDrop,
+        Bomb,
+        BookClosed,
+        BookOpen,
+        Boomerang,
+        BowAndArrow,
+        Brick,
+        Candle,
+        Coat,
+        Coffin,
+        Coin,
+        Crown,
+        CrystalBall,
+        Dagger,
+        Dart,
+        DiyaLamp,
+        Door,
+        FlagBlack,
+        FlagCheckered,
+        FlagTriangle,
+        Firecracker,
+        FlagsCrossed,
+        Flashlight,
+        GemStone,
+        Grave,
+        Hammer,
+        HammerAndPick,
+        Harp,
+        HeartRed,
+        HourglassDone,
+        HourglassNotDone,
+        Jar,
+        Key,
+        Leaf,
+        LeafFallen,
+        LeafMaple,
+        LightBulb,
+        Map,
+        MeatOnBone,
+        MeatCut,
+        MouseTrap,
+        Pick,
+        PoultryLeg,
+        PrayerBeads,
+        RedEnvelope,
+        RedLantern,
+        Rock,
+        Scroll,
+        Shield,
+        Shovel,
+        SpiderWeb,
+        SwordsCrossed,
+        Trident,
+        Urn,
+        Wand,
+        WaterDrop
+    ]
+);
+
+impl Item {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Amulet => "🧿",
+            Self::Axe => "🪓",
+            Self::Bag => "🎒",
+            Self::Bandage => "🩹",
+            Self::Bed => "🛏",
+            Self::Beer => "🍺",
+            Self::BloodDrop => "🩸",
+            Self::Bomb => "💣",
+            Self::BookClosed => "📕",
+            Self::BookOpen => "📖",
+            Self::Boomerang => "🪃",
+            Self::BowAndArrow => "🏹",
+            Self::Brick => "🧱",
+            Self::Candle => "🕯",
+            Self::Coat => "🧥",
+            Self::Coffin => "⚰️",
+            Self::Coin => "🪙",
+            Self::Crown => "👑",
+            Self::CrystalBall => "🔮",
+            Self::Dagger => "🗡",
+            Self::Dart => "🎯",
+            Self::DiyaLamp => "🪔",
+            Self::Door => "🚪",
+            Self::FlagBlack => "🏴",
+            Self::FlagCheckered => "🏁",
+            Self::FlagTriangle => "🚩",
+            Self::Firecracker => "🧨",
+            Self::FlagsCrossed => "🎌",
+            Self::Flashlight => "🔦",
+            Self::GemStone => "💎",
+            Self::Grave => "🪦",
+            Self::Hammer => "🔨",
+            Self::HammerAndPick => "⚒️",
+            Self::Harp => "🪉",
+            Self::HeartRed => "❤️",
+            Self::HourglassDone => "⌛",
+            Self::HourglassNotDone => "⏳",
+            Self::Jar => "🏺",
+            Self::Key => "🗝️",
+            Self::Leaf => "🍃",
+            Self::LeafFallen => "🍂",
+            Self::LeafMaple => "🍁",
+            Self::LightBulb => "💡",
+            Self::Map => "🗺",
+            Self::MeatOnBone => "🍖",
+            Self::MeatCut => "🥩",
+            Self::MouseTrap => "🪤",
+            Self::Pick => "⛏",
+            Self::PoultryLeg => "🍗",
+            Self::PrayerBeads => "📿",
+            Self::RedEnvelope => "🧧",
+            Self::RedLantern => "🏮",
+            Self::Rock => "🪨",
+            Self::Scroll => "📜",
+            Self::Shield => "🛡",
+            Self::Shovel => "🪏",
+            Self::SpiderWeb => "🕸️",
+            Self::SwordsCrossed => "⚔️",
+            Self::Trident => "🔱",
+            Self::Urn => "⚱️",
+            Self::Wand => "🪄",
+            Self::WaterDrop => "💧",
+        }
+    }
+}
+
 impl Display for Item {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Amulet => "🧿",
-                Self::Axe => "🪓",
-                Self::Bag => "🎒",
-                Self::Bandage => "🩹",
-                Self::Bed => "🛏",
-                Self::Beer => "🍺",
-                Self::BloodDrop => "🩸",
-                Self::Bomb => "💣",
-                Self::BookClosed => "📕",
-                Self::BookOpen => "📖",
-                Self::Boomerang => "🪃",
-                Self::BowAndArrow => "🏹",
-                Self::Brick => "🧱",
-                Self::Candle => "🕯",
-                Self::Coat => "🧥",
-                Self::Coffin => "⚰️",
-                Self::Coin => "🪙",
-                Self::Crown => "👑",
-                Self::CrystalBall => "🔮",
-                Self::Dagger => "🗡",
-                Self::Dart => "🎯",
-                Self::Door => "🚪",
-                Self::FlagBlack => "🏴",
-                Self::FlagTriangle => "🚩",
-                Self::Firecracker => "🧨",
-                Self::GemStone => "💎",
-                Self::Grave => "🪦",
-                Self::Hammer => "🔨",
-                Self::HammerAndPick => "⚒️",
-                Self::HeartRed => "❤️",
-                Self::HourglassDone => "⌛",
-                Self::HourglassNotDone => "⏳",
-                Self::Jar => "🏺",
-                Self::Key => "🗝️",
-                Self::Leaf => "🍃",
-                Self::LeafFallen => "🍂",
-                Self::LeafMaple => "🍁",
-                Self::Map => "🗺",
-                Self::MeatOnBone => "🍖",
-                Self::MeatCut => "🥩",
-                Self::Pick => "⛏",
-                Self::PoultryLeg => "🍗",
-                Self::PrayerBeads => "📿",
-                Self::RedEnvelope => "🧧",
-                Self::RedLantern => "🏮",
-                Self::Rock => "🪨",
-                Self::Scroll => "📜",
-                Self::Shield => "🛡",
-                Self::SwordsCrossed => "⚔️",
-                Self::Trident => "🔱",
-                Self::Urn => "⚱️",
-                Self::Wand => "🪄",
-                Self::WaterDrop => "💧",
-            }
-        )
+        f.write_str(self.glyph())
+    }
+}
+
+/// Emojis that can be used to represent plants and fungi, e.g. for gathering and herbalism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum Flora {
+    /// A cactus, e.g. "🌵".
+    Cactus,
+
+    /// A four leaf clover, e.g. "🍀".
+    Clover,
+
+    /// A wilted flower, e.g. "🥀".
+    FlowerWilted,
+
+    /// An herb, e.g. "🌿".
+    Herb,
+
+    /// A mushroom, e.g. "🍄".
+    Mushroom,
+
+    /// A rose, e.g. "🌹".
+    Rose,
+
+    /// A sheaf of grain, e.g. "🌾".
+    Sheaf,
+
+    /// A sunflower, e.g. "🌻".
+    Sunflower,
+}
+
+impl_all!(
+    Flora,
+    [
+        Cactus,
+        Clover,
+        FlowerWilted,
+        Herb,
+        Mushroom,
+        Rose,
+        Sheaf,
+        Sunflower
+    ]
+);
+
+impl Flora {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Cactus => "🌵",
+            Self::Clover => "🍀",
+            Self::FlowerWilted => "🥀",
+            Self::Herb => "🌿",
+            Self::Mushroom => "🍄",
+            Self::Rose => "🌹",
+            Self::Sheaf => "🌾",
+            Self::Sunflower => "🌻",
+        }
+    }
+}
+
+impl Display for Flora {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.glyph())
+    }
+}
+
+/// Emojis that can be used to represent parts of a body, e.g. for clues, injuries, and monster parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum BodyPart {
+    /// A brain, e.g. "🧠".
+    Brain,
+
+    /// An ear, e.g. "👂".
+    Ear,
+
+    /// An eye, e.g. "👁️".
+    Eye,
+
+    /// Footprints, e.g. "👣".
+    Footprints,
+
+    /// Lips, e.g. "👄".
+    Lips,
+
+    /// A flexed muscle, e.g. "💪".
+    Muscle,
+
+    /// A tooth, e.g. "🦷".
+    Tooth,
+}
+
+impl_all!(BodyPart, [Brain, Ear, Eye, Footprints, Lips, Muscle, Tooth]);
+
+impl BodyPart {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Brain => "🧠",
+            Self::Ear => "👂",
+            Self::Eye => "👁️",
+            Self::Footprints => "👣",
+            Self::Lips => "👄",
+            Self::Muscle => "💪",
+            Self::Tooth => "🦷",
+        }
+    }
+}
+
+impl Display for BodyPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.glyph())
     }
 }
 
 /// Emojis that can be used to represent a symbol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "iter", derive(EnumIter))]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Symbol {
     /// A symbol of anger, e.g. "💢".
     Anger,
@@ -755,6 +1983,9 @@ pub enum Symbol {
     /// A symbol of a cyclone, e.g. "🌀".
     Cyclone,
 
+    /// A symbol of a fingerprint, e.g. "🫆".
+    Fingerprint,
+
     /// A symbol of fire, e.g. "🔥".
     Fire,
 
@@ -785,6 +2016,12 @@ pub enum Symbol {
     /// A symbol of a white question, e.g. "❔".
     QuestionWhite,
 
+    /// A silhouette of a bust, e.g. "👤".
+    Silhouette,
+
+    /// Silhouettes of two busts, e.g. "👥".
+    Silhouettes,
+
     /// A symbol of sparkles, e.g. "✨".
     Sparkles,
 
@@ -797,36 +2034,84 @@ pub enum Symbol {
     /// A snowflake, e.g. "❄️".
     Snowflake,
 
+    /// A speech bubble pointing left, e.g. "🗨️".
+    SpeechBubbleLeft,
+
+    /// A symbol of a splatter, e.g. "🫟".
+    Splatter,
+
+    /// A thought bubble, e.g. "💭".
+    ThoughtBubble,
+
     /// A "zzz" symbol, e.g. "💤".
     Zzz,
 }
 
+impl_all!(
+    Symbol,
+    [
+        Anger,
+        Comet,
+        Cyclone,
+        Fingerprint,
+        Fire,
+        Electricity,
+        ExclamationDouble,
+        ExclamationWithQuestion,
+        ExclamationRed,
+        ExclamationWhite,
+        GenderFemale,
+        GenderMale,
+        QuestionRed,
+        QuestionWhite,
+        Silhouette,
+        Silhouettes,
+        Sparkles,
+        SpeechBubble,
+        SpeechBubbleAngry,
+        Snowflake,
+        SpeechBubbleLeft,
+        Splatter,
+        ThoughtBubble,
+        Zzz
+    ]
+);
+
+impl Symbol {
+    /// Returns the glyph for this emoji.
+    pub(crate) const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Anger => "💢",
+            Self::Comet => "☄️",
+            Self::Cyclone => "🌀",
+            Self::Fingerprint => "🫆",
+            Self::Fire => "🔥",
+            Self::Electricity => "⚡",
+            Self::ExclamationDouble => "‼️",
+            Self::ExclamationWithQuestion => "⁉️",
+            Self::ExclamationRed => "❗",
+            Self::ExclamationWhite => "❕",
+            Self::GenderFemale => "♀️",
+            Self::GenderMale => "♂️",
+            Self::QuestionRed => "❓",
+            Self::QuestionWhite => "❔",
+            Self::Silhouette => "👤",
+            Self::Silhouettes => "👥",
+            Self::Sparkles => "✨",
+            Self::SpeechBubble => "💬",
+            Self::SpeechBubbleAngry => "🗯️",
+            Self::Snowflake => "❄️",
+            Self::SpeechBubbleLeft => "🗨️",
+            Self::Splatter => "🫟",
+            Self::ThoughtBubble => "💭",
+            Self::Zzz => "💤",
+        }
+    }
+}
+
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Anger => "💢",
-                Self::Comet => "☄️",
-                Self::Cyclone => "🌀",
-                Self::Fire => "🔥",
-                Self::Electricity => "⚡",
-                Self::ExclamationDouble => "‼️",
-                Self::ExclamationWithQuestion => "⁉️",
-                Self::ExclamationRed => "❗",
-                Self::ExclamationWhite => "❕",
-                Self::GenderFemale => "♀️",
-                Self::GenderMale => "♂️",
-                Self::QuestionRed => "❓",
-                Self::QuestionWhite => "❔",
-                Self::Sparkles => "✨",
-                Self::SpeechBubble => "💬",
-                Self::SpeechBubbleAngry => "🗯️",
-                Self::Snowflake => "❄️",
-                Self::Zzz => "💤",
-            }
-        )
+        f.write_str(self.glyph())
     }
 }
 
@@ -834,6 +2119,41 @@ impl Display for Symbol {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "iter")]
+    #[test]
+    fn test_all() {
+        fn assert_all<T: strum::IntoEnumIterator + std::fmt::Debug + PartialEq>(all: &[T]) {
+            assert_eq!(T::iter().collect::<Vec<_>>(), all);
+        }
+        assert_all(Person::ALL);
+        assert_all(animation::Animation::ALL);
+        assert_all(SkinTone::ALL);
+        assert_all(Gender::ALL);
+        assert_all(Creature::ALL);
+        assert_all(Location::ALL);
+        assert_all(Item::ALL);
+        assert_all(Flora::ALL);
+        assert_all(BodyPart::ALL);
+        assert_all(Symbol::ALL);
+        assert_all(composite::Order::ALL);
+        assert_all(dialogue::Mood::ALL);
+        assert_all(encounter::Biome::ALL);
+        assert_all(encounter::Difficulty::ALL);
+        assert_all(event::GameEvent::ALL);
+        assert_all(fallback::Fallback::ALL);
+        assert_all(grid::Precedence::ALL);
+        assert_all(heart::Heart::ALL);
+        assert_all(inventory::CountStyle::ALL);
+        assert_all(quest::QuestMarker::ALL);
+        assert_all(rarity::Rarity::ALL);
+        assert_all(season::Season::ALL);
+        assert_all(status::StatusEffect::ALL);
+        assert_all(terrain::Terrain::ALL);
+        assert_all(theme::Role::ALL);
+        #[cfg(feature = "graphics")]
+        assert_all(graphics::Protocol::ALL);
+    }
+
     #[test]
     fn test_item() {
         assert_eq!(Item::Amulet.to_string(), "🧿");
@@ -857,14 +2177,19 @@ mod tests {
         assert_eq!(Item::CrystalBall.to_string(), "🔮");
         assert_eq!(Item::Dagger.to_string(), "🗡");
         assert_eq!(Item::Dart.to_string(), "🎯");
+        assert_eq!(Item::DiyaLamp.to_string(), "🪔");
         assert_eq!(Item::Door.to_string(), "🚪");
         assert_eq!(Item::FlagBlack.to_string(), "🏴");
+        assert_eq!(Item::FlagCheckered.to_string(), "🏁");
         assert_eq!(Item::FlagTriangle.to_string(), "🚩");
         assert_eq!(Item::Firecracker.to_string(), "🧨");
+        assert_eq!(Item::FlagsCrossed.to_string(), "🎌");
+        assert_eq!(Item::Flashlight.to_string(), "🔦");
         assert_eq!(Item::GemStone.to_string(), "💎");
         assert_eq!(Item::Grave.to_string(), "🪦");
         assert_eq!(Item::Hammer.to_string(), "🔨");
         assert_eq!(Item::HammerAndPick.to_string(), "⚒️");
+        assert_eq!(Item::Harp.to_string(), "🪉");
         assert_eq!(Item::HeartRed.to_string(), "❤️");
         assert_eq!(Item::HourglassDone.to_string(), "⌛");
         assert_eq!(Item::HourglassNotDone.to_string(), "⏳");
@@ -873,9 +2198,11 @@ mod tests {
         assert_eq!(Item::Leaf.to_string(), "🍃");
         assert_eq!(Item::LeafFallen.to_string(), "🍂");
         assert_eq!(Item::LeafMaple.to_string(), "🍁");
+        assert_eq!(Item::LightBulb.to_string(), "💡");
         assert_eq!(Item::Map.to_string(), "🗺");
         assert_eq!(Item::MeatOnBone.to_string(), "🍖");
         assert_eq!(Item::MeatCut.to_string(), "🥩");
+        assert_eq!(Item::MouseTrap.to_string(), "🪤");
         assert_eq!(Item::Pick.to_string(), "⛏");
         assert_eq!(Item::PoultryLeg.to_string(), "🍗");
         assert_eq!(Item::PrayerBeads.to_string(), "📿");
@@ -884,6 +2211,8 @@ mod tests {
         assert_eq!(Item::Rock.to_string(), "🪨");
         assert_eq!(Item::Scroll.to_string(), "📜");
         assert_eq!(Item::Shield.to_string(), "🛡");
+        assert_eq!(Item::Shovel.to_string(), "🪏");
+        assert_eq!(Item::SpiderWeb.to_string(), "🕸️");
         assert_eq!(Item::SwordsCrossed.to_string(), "⚔️");
         assert_eq!(Item::Trident.to_string(), "🔱");
         assert_eq!(Item::Urn.to_string(), "⚱️");
@@ -891,11 +2220,35 @@ mod tests {
         assert_eq!(Item::WaterDrop.to_string(), "💧");
     }
 
+    #[test]
+    fn test_flora() {
+        assert_eq!(Flora::Cactus.to_string(), "🌵");
+        assert_eq!(Flora::Clover.to_string(), "🍀");
+        assert_eq!(Flora::FlowerWilted.to_string(), "🥀");
+        assert_eq!(Flora::Herb.to_string(), "🌿");
+        assert_eq!(Flora::Mushroom.to_string(), "🍄");
+        assert_eq!(Flora::Rose.to_string(), "🌹");
+        assert_eq!(Flora::Sheaf.to_string(), "🌾");
+        assert_eq!(Flora::Sunflower.to_string(), "🌻");
+    }
+
+    #[test]
+    fn test_body_part() {
+        assert_eq!(BodyPart::Brain.to_string(), "🧠");
+        assert_eq!(BodyPart::Ear.to_string(), "👂");
+        assert_eq!(BodyPart::Eye.to_string(), "👁️");
+        assert_eq!(BodyPart::Footprints.to_string(), "👣");
+        assert_eq!(BodyPart::Lips.to_string(), "👄");
+        assert_eq!(BodyPart::Muscle.to_string(), "💪");
+        assert_eq!(BodyPart::Tooth.to_string(), "🦷");
+    }
+
     #[test]
     fn test_symbol() {
         assert_eq!(Symbol::Anger.to_string(), "💢");
         assert_eq!(Symbol::Comet.to_string(), "☄️");
         assert_eq!(Symbol::Cyclone.to_string(), "🌀");
+        assert_eq!(Symbol::Fingerprint.to_string(), "🫆");
         assert_eq!(Symbol::Fire.to_string(), "🔥");
         assert_eq!(Symbol::Electricity.to_string(), "⚡");
         assert_eq!(Symbol::ExclamationDouble.to_string(), "‼️");
@@ -906,10 +2259,15 @@ mod tests {
         assert_eq!(Symbol::GenderMale.to_string(), "♂️");
         assert_eq!(Symbol::QuestionRed.to_string(), "❓");
         assert_eq!(Symbol::QuestionWhite.to_string(), "❔");
+        assert_eq!(Symbol::Silhouette.to_string(), "👤");
+        assert_eq!(Symbol::Silhouettes.to_string(), "👥");
         assert_eq!(Symbol::Sparkles.to_string(), "✨");
         assert_eq!(Symbol::SpeechBubble.to_string(), "💬");
         assert_eq!(Symbol::SpeechBubbleAngry.to_string(), "🗯️");
         assert_eq!(Symbol::Snowflake.to_string(), "❄️");
+        assert_eq!(Symbol::SpeechBubbleLeft.to_string(), "🗨️");
+        assert_eq!(Symbol::Splatter.to_string(), "🫟");
+        assert_eq!(Symbol::ThoughtBubble.to_string(), "💭");
         assert_eq!(Symbol::Zzz.to_string(), "💤");
     }
 
@@ -931,6 +2289,7 @@ mod tests {
         assert_eq!(Location::Tent.to_string(), "⛺");
         assert_eq!(Location::TreeDeciduous.to_string(), "🌳");
         assert_eq!(Location::TreeEvergreen.to_string(), "🌲");
+        assert_eq!(Location::TreeLeafless.to_string(), "🪾");
         assert_eq!(Location::TreePalm.to_string(), "🌴");
         assert_eq!(Location::Volcano.to_string(), "🌋");
     }
@@ -945,7 +2304,7 @@ mod tests {
         assert_eq!(Person::Elf.to_string(), "🧝");
         assert_eq!(Person::Fairy.to_string(), "🧚");
         assert_eq!(Person::Genie.to_string(), "🧞");
-        assert_eq!(Person::HeardScarfPerson.to_string(), "🧕");
+        assert_eq!(Person::HeadScarfPerson.to_string(), "🧕");
         assert_eq!(Person::Mage.to_string(), "🧙");
         assert_eq!(Person::MerPerson.to_string(), "🧜");
         assert_eq!(Person::OldPerson.to_string(), "🧓");
@@ -957,12 +2316,444 @@ mod tests {
         assert_eq!(Person::Zombie.to_string(), "🧟");
     }
 
+    #[test]
+    fn test_emoji_person() {
+        const PERSONS: [Person; 18] = [
+            Person::Artist,
+            Person::Baby,
+            Person::BaldPerson,
+            Person::BeardedPerson,
+            Person::Child,
+            Person::Elf,
+            Person::Fairy,
+            Person::Genie,
+            Person::HeadScarfPerson,
+            Person::Mage,
+            Person::MerPerson,
+            Person::OldPerson,
+            Person::Person,
+            Person::Royalty,
+            Person::SkullCapPerson,
+            Person::TurbanPerson,
+            Person::Vampire,
+            Person::Zombie,
+        ];
+        const SKIN_TONES: [SkinTone; 6] = [
+            SkinTone::Neutral,
+            SkinTone::Light,
+            SkinTone::MediumLight,
+            SkinTone::Medium,
+            SkinTone::MediumDark,
+            SkinTone::Dark,
+        ];
+        const GENDERS: [Gender; 3] = [Gender::Neutral, Gender::Male, Gender::Female];
+
+        // Indexed by person, then skin tone, then gender.
+        const EXPECTED: [[[&str; 3]; 6]; 18] = [
+            // Person::Artist
+            [
+                ["🧑‍🎨", "🧑‍🎨", "🧑‍🎨"],
+                ["🧑🏻‍🎨", "🧑🏻‍🎨", "🧑🏻‍🎨"],
+                ["🧑🏼‍🎨", "🧑🏼‍🎨", "🧑🏼‍🎨"],
+                ["🧑🏽‍🎨", "🧑🏽‍🎨", "🧑🏽‍🎨"],
+                ["🧑🏾‍🎨", "🧑🏾‍🎨", "🧑🏾‍🎨"],
+                ["🧑🏿‍🎨", "🧑🏿‍🎨", "🧑🏿‍🎨"],
+            ],
+            // Person::Baby
+            [
+                ["👶", "👶", "👶"],
+                ["👶🏻", "👶🏻", "👶🏻"],
+                ["👶🏼", "👶🏼", "👶🏼"],
+                ["👶🏽", "👶🏽", "👶🏽"],
+                ["👶🏾", "👶🏾", "👶🏾"],
+                ["👶🏿", "👶🏿", "👶🏿"],
+            ],
+            // Person::BaldPerson
+            [
+                ["🧑‍🦲", "🧑‍🦲", "🧑‍🦲"],
+                ["🧑🏻‍🦲", "🧑🏻‍🦲", "🧑🏻‍🦲"],
+                ["🧑🏼‍🦲", "🧑🏼‍🦲", "🧑🏼‍🦲"],
+                ["🧑🏽‍🦲", "🧑🏽‍🦲", "🧑🏽‍🦲"],
+                ["🧑🏾‍🦲", "🧑🏾‍🦲", "🧑🏾‍🦲"],
+                ["🧑🏿‍🦲", "🧑🏿‍🦲", "🧑🏿‍🦲"],
+            ],
+            // Person::BeardedPerson
+            [
+                ["🧔", "🧔‍♂️", "🧔‍♀️"],
+                ["🧔🏻", "🧔🏻‍♂️", "🧔🏻‍♀️"],
+                ["🧔🏼", "🧔🏼‍♂️", "🧔🏼‍♀️"],
+                ["🧔🏽", "🧔🏽‍♂️", "🧔🏽‍♀️"],
+                ["🧔🏾", "🧔🏾‍♂️", "🧔🏾‍♀️"],
+                ["🧔🏿", "🧔🏿‍♂️", "🧔🏿‍♀️"],
+            ],
+            // Person::Child
+            [
+                ["🧒", "👦", "👧"],
+                ["🧒🏻", "👦🏻", "👧🏻"],
+                ["🧒🏼", "👦🏼", "👧🏼"],
+                ["🧒🏽", "👦🏽", "👧🏽"],
+                ["🧒🏾", "👦🏾", "👧🏾"],
+                ["🧒🏿", "👦🏿", "👧🏿"],
+            ],
+            // Person::Elf
+            [
+                ["🧝", "🧝‍♂️", "🧝‍♀️"],
+                ["🧝🏻", "🧝🏻‍♂️", "🧝🏻‍♀️"],
+                ["🧝🏼", "🧝🏼‍♂️", "🧝🏼‍♀️"],
+                ["🧝🏽", "🧝🏽‍♂️", "🧝🏽‍♀️"],
+                ["🧝🏾", "🧝🏾‍♂️", "🧝🏾‍♀️"],
+                ["🧝🏿", "🧝🏿‍♂️", "🧝🏿‍♀️"],
+            ],
+            // Person::Fairy
+            [
+                ["🧚", "🧚‍♂️", "🧚‍♀️"],
+                ["🧚🏻", "🧚🏻‍♂️", "🧚🏻‍♀️"],
+                ["🧚🏼", "🧚🏼‍♂️", "🧚🏼‍♀️"],
+                ["🧚🏽", "🧚🏽‍♂️", "🧚🏽‍♀️"],
+                ["🧚🏾", "🧚🏾‍♂️", "🧚🏾‍♀️"],
+                ["🧚🏿", "🧚🏿‍♂️", "🧚🏿‍♀️"],
+            ],
+            // Person::Genie
+            [
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+                ["🧞", "🧞‍♂️", "🧞‍♀️"],
+            ],
+            // Person::HeadScarfPerson
+            [
+                ["🧕", "🧕", "🧕"],
+                ["🧕🏻", "🧕🏻", "🧕🏻"],
+                ["🧕🏼", "🧕🏼", "🧕🏼"],
+                ["🧕🏽", "🧕🏽", "🧕🏽"],
+                ["🧕🏾", "🧕🏾", "🧕🏾"],
+                ["🧕🏿", "🧕🏿", "🧕🏿"],
+            ],
+            // Person::Mage
+            [
+                ["🧙", "🧙‍♂️", "🧙‍♀️"],
+                ["🧙🏻", "🧙🏻‍♂️", "🧙🏻‍♀️"],
+                ["🧙🏼", "🧙🏼‍♂️", "🧙🏼‍♀️"],
+                ["🧙🏽", "🧙🏽‍♂️", "🧙🏽‍♀️"],
+                ["🧙🏾", "🧙🏾‍♂️", "🧙🏾‍♀️"],
+                ["🧙🏿", "🧙🏿‍♂️", "🧙🏿‍♀️"],
+            ],
+            // Person::MerPerson
+            [
+                ["🧜", "🧜‍♂️", "🧜‍♀️"],
+                ["🧜🏻", "🧜🏻‍♂️", "🧜🏻‍♀️"],
+                ["🧜🏼", "🧜🏼‍♂️", "🧜🏼‍♀️"],
+                ["🧜🏽", "🧜🏽‍♂️", "🧜🏽‍♀️"],
+                ["🧜🏾", "🧜🏾‍♂️", "🧜🏾‍♀️"],
+                ["🧜🏿", "🧜🏿‍♂️", "🧜🏿‍♀️"],
+            ],
+            // Person::OldPerson
+            [
+                ["🧓", "👴", "👵"],
+                ["🧓🏻", "👴🏻", "👵🏻"],
+                ["🧓🏼", "👴🏼", "👵🏼"],
+                ["🧓🏽", "👴🏽", "👵🏽"],
+                ["🧓🏾", "👴🏾", "👵🏾"],
+                ["🧓🏿", "👴🏿", "👵🏿"],
+            ],
+            // Person::Person
+            [
+                ["🧑", "👨", "👩"],
+                ["🧑🏻", "👨🏻", "👩🏻"],
+                ["🧑🏼", "👨🏼", "👩🏼"],
+                ["🧑🏽", "👨🏽", "👩🏽"],
+                ["🧑🏾", "👨🏾", "👩🏾"],
+                ["🧑🏿", "👨🏿", "👩🏿"],
+            ],
+            // Person::Royalty
+            [
+                ["🤴", "🤴", "🤴"],
+                ["🤴🏻", "🤴🏻", "🤴🏻"],
+                ["🤴🏼", "🤴🏼", "🤴🏼"],
+                ["🤴🏽", "🤴🏽", "🤴🏽"],
+                ["🤴🏾", "🤴🏾", "🤴🏾"],
+                ["🤴🏿", "🤴🏿", "🤴🏿"],
+            ],
+            // Person::SkullCapPerson
+            [
+                ["👲", "👲", "👲"],
+                ["👲🏻", "👲🏻", "👲🏻"],
+                ["👲🏼", "👲🏼", "👲🏼"],
+                ["👲🏽", "👲🏽", "👲🏽"],
+                ["👲🏾", "👲🏾", "👲🏾"],
+                ["👲🏿", "👲🏿", "👲🏿"],
+            ],
+            // Person::TurbanPerson
+            [
+                ["👳", "👳‍♂️", "👳‍♀️"],
+                ["👳🏻", "👳🏻‍♂️", "👳🏻‍♀️"],
+                ["👳🏼", "👳🏼‍♂️", "👳🏼‍♀️"],
+                ["👳🏽", "👳🏽‍♂️", "👳🏽‍♀️"],
+                ["👳🏾", "👳🏾‍♂️", "👳🏾‍♀️"],
+                ["👳🏿", "👳🏿‍♂️", "👳🏿‍♀️"],
+            ],
+            // Person::Vampire
+            [
+                ["🧛", "🧛‍♂️", "🧛‍♀️"],
+                ["🧛🏻", "🧛🏻‍♂️", "🧛🏻‍♀️"],
+                ["🧛🏼", "🧛🏼‍♂️", "🧛🏼‍♀️"],
+                ["🧛🏽", "🧛🏽‍♂️", "🧛🏽‍♀️"],
+                ["🧛🏾", "🧛🏾‍♂️", "🧛🏾‍♀️"],
+                ["🧛🏿", "🧛🏿‍♂️", "🧛🏿‍♀️"],
+            ],
+            // Person::Zombie
+            [
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+                ["🧟", "🧟‍♂️", "🧟‍♀️"],
+            ],
+        ];
+
+        for (person, expected) in PERSONS.iter().zip(EXPECTED) {
+            for (skin, expected) in SKIN_TONES.iter().zip(expected) {
+                for (gender, expected) in GENDERS.iter().zip(expected) {
+                    assert_eq!(
+                        Emoji::Person(*person, *skin, *gender).to_string(),
+                        expected,
+                        "{:?} + {:?} + {:?}",
+                        person,
+                        skin,
+                        gender
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_emoji_base() {
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(
+            elf.strip_skin_tone(),
+            Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female)
+        );
+        assert_eq!(
+            elf.strip_gender(),
+            Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Neutral)
+        );
+        assert_eq!(elf.base(), elf.strip_skin_tone().strip_gender());
+        assert_eq!(elf.base().to_string(), "🧝");
+
+        let dragon = Emoji::Creature(Creature::Dragon);
+        assert_eq!(dragon.base(), dragon);
+    }
+
+    #[test]
+    fn test_emoji_person_accessors() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Light, Gender::Male);
+        assert_eq!(mage.person(), Some(Person::Mage));
+        assert_eq!(mage.skin_tone(), Some(SkinTone::Light));
+        assert_eq!(mage.gender(), Some(Gender::Male));
+        assert_eq!(
+            mage.with_skin_tone(SkinTone::Dark)
+                .with_gender(Gender::Female),
+            Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Female)
+        );
+
+        let castle = Emoji::Location(Location::Castle);
+        assert_eq!(castle.person(), None);
+        assert_eq!(castle.skin_tone(), None);
+        assert_eq!(castle.gender(), None);
+        assert_eq!(castle.with_skin_tone(SkinTone::Dark), castle);
+        assert_eq!(castle.with_gender(Gender::Female), castle);
+    }
+
+    #[test]
+    fn test_person_constructors() {
+        assert_eq!(
+            Person::Mage.male(),
+            Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Male)
+        );
+        assert_eq!(
+            Person::Mage.neutral(),
+            Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Neutral)
+        );
+        assert_eq!(Person::Person.female().to_string(), "👩");
+        assert_eq!(
+            Person::Child.with(SkinTone::Dark, Gender::Male).to_string(),
+            "👦🏿"
+        );
+    }
+
+    #[test]
+    fn test_emoji_predicates() {
+        let elf = Person::Elf.female();
+        assert!(elf.is_person());
+        assert!(!elf.is_creature());
+        assert_eq!(elf.as_creature(), None);
+
+        let emojis = [
+            Emoji::Creature(Creature::Dragon),
+            Emoji::Location(Location::Castle),
+            Emoji::Item(Item::SwordsCrossed),
+            Emoji::Flora(Flora::Rose),
+            Emoji::BodyPart(BodyPart::Eye),
+            Emoji::Symbol(Symbol::Sparkles),
+        ];
+        for emoji in emojis {
+            assert!(!emoji.is_person());
+        }
+        assert_eq!(emojis[0].as_creature(), Some(Creature::Dragon));
+        assert_eq!(emojis[1].as_location(), Some(Location::Castle));
+        assert_eq!(emojis[2].as_item(), Some(Item::SwordsCrossed));
+        assert_eq!(emojis[3].as_flora(), Some(Flora::Rose));
+        assert_eq!(emojis[4].as_body_part(), Some(BodyPart::Eye));
+        assert_eq!(emojis.iter().filter(|emoji| emoji.is_location()).count(), 1);
+        assert_eq!(emojis[5].as_symbol(), Some(Symbol::Sparkles));
+        assert!(emojis[2].is_item() && emojis[3].is_flora() && emojis[4].is_body_part());
+        assert!(emojis[5].is_symbol());
+        assert_eq!(Emoji::from(Symbol::Fire), Emoji::Symbol(Symbol::Fire));
+        assert_eq!(Emoji::from(Symbol::Fire).to_string(), "🔥");
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Person::iter().len(), Person::ALL.len());
+        assert_eq!(SkinTone::iter().next(), Some(SkinTone::Neutral));
+        assert_eq!(Gender::iter().next_back(), Some(Gender::Female));
+        assert!(Creature::iter().eq(Creature::ALL.iter().copied()));
+        assert!(Symbol::iter().eq(Symbol::ALL.iter().copied()));
+    }
+
+    #[test]
+    fn test_emoji_qualified() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Male);
+        assert_eq!(mage.qualified(Qualification::Full).to_string(), "🧙🏿‍♂️");
+        assert_eq!(mage.qualified(Qualification::Minimal).to_string(), "🧙🏿‍♂");
+
+        let artist = Emoji::Person(Person::Artist, SkinTone::Light, Gender::Neutral);
+        assert_eq!(artist.qualified(Qualification::Minimal).to_string(), "🧑🏻‍🎨");
+
+        let swords = Emoji::Item(Item::SwordsCrossed);
+        assert_eq!(swords.qualified(Qualification::Minimal).to_string(), "⚔️");
+    }
+
+    #[test]
+    #[cfg(feature = "async-graphql")]
+    fn test_async_graphql_enum() {
+        use async_graphql::{InputType, Name, Value};
+
+        assert_eq!(
+            Creature::Dragon.to_value(),
+            Value::Enum(Name::new("DRAGON"))
+        );
+        assert_eq!(
+            Location::parse(Some(Value::Enum(Name::new("TREE_EVERGREEN")))).ok(),
+            Some(Location::TreeEvergreen)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "utoipa")]
+    fn test_utoipa_schema() {
+        use utoipa::PartialSchema;
+
+        let schema = serde_json::to_value(Location::schema()).unwrap();
+        let values = schema["enum"].as_array().unwrap();
+        assert_eq!(values.len(), Location::ALL.len());
+        assert!(values.contains(&"tree-evergreen".into()));
+    }
+
+    #[test]
+    #[cfg(feature = "clap")]
+    fn test_clap_value_enum() {
+        assert_eq!(Creature::from_str("dragon", false), Ok(Creature::Dragon));
+        assert_eq!(Location::from_str("Castle", true), Ok(Location::Castle));
+        assert_eq!(
+            Item::from_str("swords-crossed", false),
+            Ok(Item::SwordsCrossed)
+        );
+        assert_eq!(
+            Person::from_str("heard-scarf-person", false),
+            Ok(Person::HeadScarfPerson)
+        );
+        assert!(Creature::from_str("wyvern", false).is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_person_deprecated_aliases() {
+        assert_eq!(Person::HeardScarfPerson, Person::HeadScarfPerson);
+        assert!(matches!(Person::HeadScarfPerson, Person::HeardScarfPerson));
+    }
+
+    #[test]
+    fn test_skin_tone() {
+        assert_eq!(SkinTone::Neutral.to_string(), "");
+        assert_eq!(SkinTone::Light.to_string(), "🏻");
+        assert_eq!(SkinTone::MediumLight.to_string(), "🏼");
+        assert_eq!(SkinTone::Medium.to_string(), "🏽");
+        assert_eq!(SkinTone::MediumDark.to_string(), "🏾");
+        assert_eq!(SkinTone::Dark.to_string(), "🏿");
+    }
+
+    #[test]
+    fn test_skin_tone_swatch() {
+        assert_eq!(SkinTone::Neutral.swatch(), "✋");
+        assert_eq!(SkinTone::Light.swatch(), "✋🏻");
+        assert_eq!(SkinTone::MediumLight.swatch(), "✋🏼");
+        assert_eq!(SkinTone::Medium.swatch(), "✋🏽");
+        assert_eq!(SkinTone::MediumDark.swatch(), "✋🏾");
+        assert_eq!(SkinTone::Dark.swatch(), "✋🏿");
+    }
+
+    #[test]
+    fn test_skin_tone_fitzpatrick() {
+        assert_eq!(SkinTone::from_fitzpatrick(0), None);
+        assert_eq!(SkinTone::from_fitzpatrick(1), Some(SkinTone::Light));
+        assert_eq!(SkinTone::from_fitzpatrick(2), Some(SkinTone::Light));
+        assert_eq!(SkinTone::from_fitzpatrick(3), Some(SkinTone::MediumLight));
+        assert_eq!(SkinTone::from_fitzpatrick(4), Some(SkinTone::Medium));
+        assert_eq!(SkinTone::from_fitzpatrick(5), Some(SkinTone::MediumDark));
+        assert_eq!(SkinTone::from_fitzpatrick(6), Some(SkinTone::Dark));
+        assert_eq!(SkinTone::from_fitzpatrick(7), None);
+
+        for fitzpatrick_type in 1..=6 {
+            let skin = SkinTone::from_fitzpatrick(fitzpatrick_type).unwrap();
+            assert_eq!(
+                SkinTone::from_fitzpatrick(skin.fitzpatrick().unwrap()),
+                Some(skin)
+            );
+        }
+    }
+
+    #[test]
+    fn test_gender() {
+        assert_eq!(Gender::Neutral.to_string(), "");
+        assert_eq!(Gender::Male.to_string(), "♂");
+        assert_eq!(Gender::Female.to_string(), "♀");
+    }
+
+    #[test]
+    fn test_gender_sign() {
+        assert_eq!(Gender::Neutral.sign(), "🧑");
+        assert_eq!(Gender::Male.sign(), "♂\u{fe0f}");
+        assert_eq!(Gender::Female.sign(), "♀\u{fe0f}");
+        assert_eq!(Gender::Male.sign(), Symbol::GenderMale.to_string());
+        assert_eq!(Gender::Female.sign(), Symbol::GenderFemale.to_string());
+    }
+
     #[test]
     fn test_creature() {
         assert_eq!(Creature::Ant.to_string(), "🐜");
+        assert_eq!(Creature::Badger.to_string(), "🦡");
         assert_eq!(Creature::Bat.to_string(), "🦇");
+        assert_eq!(Creature::Bear.to_string(), "🐻");
+        assert_eq!(Creature::Beaver.to_string(), "🦫");
         assert_eq!(Creature::Beetle.to_string(), "🐞");
         assert_eq!(Creature::Bison.to_string(), "🦬");
+        assert_eq!(Creature::BlackBird.to_string(), "🐦‍⬛");
+        assert_eq!(Creature::Blowfish.to_string(), "🐡");
         assert_eq!(Creature::Boar.to_string(), "🐗");
         assert_eq!(Creature::Bug.to_string(), "🐛");
         assert_eq!(Creature::Butterfly.to_string(), "🦋");
@@ -971,35 +2762,67 @@ mod tests {
         assert_eq!(Creature::Cockroach.to_string(), "🪳");
         assert_eq!(Creature::Cow.to_string(), "🐄");
         assert_eq!(Creature::Crab.to_string(), "🦀");
+        assert_eq!(Creature::Cricket.to_string(), "🦗");
         assert_eq!(Creature::Crocodile.to_string(), "🐊");
         assert_eq!(Creature::Deer.to_string(), "🦌");
         assert_eq!(Creature::Dog.to_string(), "🐕");
+        assert_eq!(Creature::Dolphin.to_string(), "🐬");
+        assert_eq!(Creature::Donkey.to_string(), "🫏");
+        assert_eq!(Creature::Dove.to_string(), "🕊️");
         assert_eq!(Creature::Dragon.to_string(), "🐉");
         assert_eq!(Creature::Eagle.to_string(), "🦅");
         assert_eq!(Creature::Elephant.to_string(), "🐘");
         assert_eq!(Creature::Fish.to_string(), "🐟");
+        assert_eq!(Creature::Fly.to_string(), "🪰");
+        assert_eq!(Creature::Fox.to_string(), "🦊");
+        assert_eq!(Creature::Frog.to_string(), "🐸");
         assert_eq!(Creature::Ghost.to_string(), "👻");
         assert_eq!(Creature::Goat.to_string(), "🐐");
         assert_eq!(Creature::Goblin.to_string(), "👺");
+        assert_eq!(Creature::Gorilla.to_string(), "🦍");
+        assert_eq!(Creature::Hedgehog.to_string(), "🦔");
         assert_eq!(Creature::Honeybee.to_string(), "🐝");
         assert_eq!(Creature::Horse.to_string(), "🐎");
+        assert_eq!(Creature::Jellyfish.to_string(), "🪼");
         assert_eq!(Creature::Leopard.to_string(), "🐆");
+        assert_eq!(Creature::Lion.to_string(), "🦁");
+        assert_eq!(Creature::Lizard.to_string(), "🦎");
         assert_eq!(Creature::Llama.to_string(), "🦙");
         assert_eq!(Creature::Mammoth.to_string(), "🦣");
+        assert_eq!(Creature::Monkey.to_string(), "🐒");
+        assert_eq!(Creature::Moose.to_string(), "🫎");
+        assert_eq!(Creature::Mosquito.to_string(), "🦟");
         assert_eq!(Creature::Mouse.to_string(), "🐁");
+        assert_eq!(Creature::Octopus.to_string(), "🐙");
         assert_eq!(Creature::Ogre.to_string(), "👹");
+        assert_eq!(Creature::Otter.to_string(), "🦦");
+        assert_eq!(Creature::Owl.to_string(), "🦉");
+        assert_eq!(Creature::Ox.to_string(), "🐂");
+        assert_eq!(Creature::Parrot.to_string(), "🦜");
+        assert_eq!(Creature::Peacock.to_string(), "🦚");
         assert_eq!(Creature::Pig.to_string(), "🐖");
         assert_eq!(Creature::Rabbit.to_string(), "🐇");
+        assert_eq!(Creature::Raccoon.to_string(), "🦝");
         assert_eq!(Creature::Ram.to_string(), "🐏");
         assert_eq!(Creature::Rat.to_string(), "🐀");
         assert_eq!(Creature::Rhinoceros.to_string(), "🦏");
+        assert_eq!(Creature::Rooster.to_string(), "🐓");
         assert_eq!(Creature::Scorpion.to_string(), "🦂");
+        assert_eq!(Creature::Seal.to_string(), "🦭");
         assert_eq!(Creature::Shark.to_string(), "🦈");
+        assert_eq!(Creature::Sheep.to_string(), "🐑");
+        assert_eq!(Creature::Snail.to_string(), "🐌");
         assert_eq!(Creature::Snake.to_string(), "🐍");
         assert_eq!(Creature::Spider.to_string(), "🕷");
+        assert_eq!(Creature::Squid.to_string(), "🦑");
+        assert_eq!(Creature::Swan.to_string(), "🦢");
         assert_eq!(Creature::Tiger.to_string(), "🐅");
         assert_eq!(Creature::TropicalFish.to_string(), "🐠");
+        assert_eq!(Creature::Turkey.to_string(), "🦃");
+        assert_eq!(Creature::Turtle.to_string(), "🐢");
         assert_eq!(Creature::WaterBuffalo.to_string(), "🐃");
+        assert_eq!(Creature::Whale.to_string(), "🐋");
         assert_eq!(Creature::Wolf.to_string(), "🐺");
+        assert_eq!(Creature::Worm.to_string(), "🪱");
     }
 }
@@ -0,0 +1,177 @@
+//! Loot tables, which map [`Item`]s (or any emoji) to weighted chances of being dropped.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::loot::LootTable;
+//! use mythoji::Item;
+//!
+//! let goblin = LootTable::new()
+//!     .with(Item::Coin, 6)
+//!     .with(Item::Dagger, 3)
+//!     .with(Item::GemStone, 1)
+//!     .nothing(10);
+//!
+//! assert_eq!(goblin.to_string(), "🪙 30%, 🗡 15%, 💎 5%");
+//!
+//! # #[cfg(feature = "rand")]
+//! if let Some(item) = goblin.roll(&mut rand::thread_rng()) {
+//!     println!("The goblin dropped {}", item);
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use crate::Item;
+
+/// A table of weighted drops.
+///
+/// The chance of dropping an entry is its weight divided by the sum of all weights, including the
+/// weight of dropping [nothing](LootTable::nothing).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LootTable<T = Item> {
+    entries: Vec<(T, u32)>,
+    nothing: u32,
+}
+
+impl<T> Default for LootTable<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            nothing: 0,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> LootTable<T> {
+    /// Creates an empty loot table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the table with `drop` added, or its weight replaced if it was already present.
+    pub fn with(mut self, drop: T, weight: u32) -> Self {
+        self.insert(drop, weight);
+        self
+    }
+
+    /// Returns the table with the weight of dropping nothing set to `weight`.
+    pub fn nothing(mut self, weight: u32) -> Self {
+        self.nothing = weight;
+        self
+    }
+
+    /// Adds `drop` to the table, or replaces its weight if it was already present.
+    pub fn insert(&mut self, drop: T, weight: u32) {
+        match self.entries.iter_mut().find(|(d, _)| d == &drop) {
+            Some((_, w)) => *w = weight,
+            None => self.entries.push((drop, weight)),
+        }
+    }
+
+    /// Removes `drop` from the table, returning its weight if it was present.
+    pub fn remove(&mut self, drop: T) -> Option<u32> {
+        let index = self.entries.iter().position(|(d, _)| d == &drop)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns the weight of `drop`, or `0` if it is not in the table.
+    pub fn weight(&self, drop: T) -> u32 {
+        self.entries
+            .iter()
+            .find(|(d, _)| d == &drop)
+            .map_or(0, |(_, w)| *w)
+    }
+
+    /// Returns the chance, from `0.0` to `1.0`, of dropping `drop`.
+    pub fn chance(&self, drop: T) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => f64::from(self.weight(drop)) / total as f64,
+        }
+    }
+
+    /// Returns an iterator over the drops and their weights, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (T, u32)> + '_ {
+        self.entries.iter().copied()
+    }
+
+    /// Rolls the table, returning the drop, or `None` if nothing was dropped.
+    #[cfg(feature = "rand")]
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        let nothing = self.nothing;
+        let entries = self.entries.iter().map(|(d, w)| (Some(*d), *w));
+        let weights: Vec<_> = entries.chain([(None, nothing)]).collect();
+        crate::random::pick(&weights, rng).flatten()
+    }
+
+    fn total(&self) -> u64 {
+        let total: u64 = self.entries.iter().map(|(_, w)| u64::from(*w)).sum();
+        total + u64::from(self.nothing)
+    }
+}
+
+impl<T: Copy + PartialEq + Display> Display for LootTable<T> {
+    /// Displays each drop with its chance of being dropped, e.g. "🪙 30%, 💎 5%".
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, (drop, _)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {:.0}%", drop, self.chance(*drop) * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Creature, Emoji};
+
+    use super::*;
+
+    #[test]
+    fn test_loot_table_weights() {
+        let mut table = LootTable::new().with(Item::Coin, 3).with(Item::Key, 1);
+        assert_eq!(table.chance(Item::Coin), 0.75);
+        assert_eq!(table.chance(Item::Crown), 0.0);
+
+        table.insert(Item::Coin, 1);
+        assert_eq!(table.weight(Item::Coin), 1);
+        assert_eq!(table.remove(Item::Key), Some(1));
+        assert_eq!(table.remove(Item::Key), None);
+        assert_eq!(table.iter().collect::<Vec<_>>(), [(Item::Coin, 1)]);
+
+        assert_eq!(LootTable::<Item>::new().chance(Item::Coin), 0.0);
+    }
+
+    #[test]
+    fn test_loot_table_display() {
+        let table = LootTable::new()
+            .with(Emoji::Item(Item::Bomb), 1)
+            .with(Emoji::Creature(Creature::Rat), 1)
+            .nothing(2);
+        assert_eq!(table.to_string(), "💣 25%, 🐀 25%");
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_loot_table_roll() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(LootTable::<Item>::new().roll(&mut rng), None);
+
+        let table = LootTable::new().with(Item::Coin, 1).with(Item::Key, 0);
+        for _ in 0..100 {
+            assert_eq!(table.roll(&mut rng), Some(Item::Coin));
+        }
+
+        let table = table.nothing(1);
+        let coins = (0..1000).filter(|_| table.roll(&mut rng).is_some()).count();
+        assert!((400..600).contains(&coins), "{}", coins);
+    }
+}
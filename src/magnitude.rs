@@ -0,0 +1,112 @@
+//! Magnitudes, which express an intensity by repeating an emoji, e.g. "🔥🔥🔥" for a strong spell.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::magnitude::Magnitude;
+//! use mythoji::Symbol;
+//!
+//! assert_eq!(Symbol::Fire.repeat(2), "🔥🔥");
+//!
+//! // A spell with 50 out of 100 power, out of a maximum of 3 flames.
+//! let power = Magnitude::new(Symbol::Fire, 50, 100);
+//! assert_eq!(power.to_string(), "🔥🔥");
+//! assert_eq!(power.max(5).to_string(), "🔥🔥🔥");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Emoji, Symbol};
+
+/// Renders a value as a number of repeated glyphs, scaled to a maximum number of repetitions.
+///
+/// The number of glyphs is rounded up, so any value above zero is rendered with at least one glyph.
+/// Values above the range are clamped to the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Magnitude<T = Symbol> {
+    glyph: T,
+    value: u32,
+    of: u32,
+    max: u32,
+}
+
+impl<T: Display> Magnitude<T> {
+    /// Creates a magnitude of `value` out of `of`, rendered with up to 3 of the given glyph.
+    pub const fn new(glyph: T, value: u32, of: u32) -> Self {
+        Self {
+            glyph,
+            value,
+            of,
+            max: 3,
+        }
+    }
+
+    /// Renders the maximum value with the given number of glyphs.
+    pub const fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Returns the number of glyphs rendered.
+    pub fn count(&self) -> u32 {
+        if self.of == 0 {
+            return 0;
+        }
+        let value = u64::from(self.value.min(self.of));
+        (value * u64::from(self.max)).div_ceil(u64::from(self.of)) as u32
+    }
+}
+
+impl<T: Display> Display for Magnitude<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for _ in 0..self.count() {
+            write!(f, "{}", self.glyph)?;
+        }
+        Ok(())
+    }
+}
+
+impl Emoji {
+    /// Returns this emoji repeated the given number of times, e.g. "🐺🐺🐺" for a pack of wolves.
+    pub fn repeat(&self, count: usize) -> String {
+        self.to_string().repeat(count)
+    }
+}
+
+impl Symbol {
+    /// Returns this symbol repeated the given number of times, e.g. "🔥🔥🔥" for a strong spell.
+    pub fn repeat(&self, count: usize) -> String {
+        self.to_string().repeat(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Creature;
+
+    #[test]
+    fn test_magnitude_count() {
+        let counts: Vec<_> = [0, 1, 33, 34, 66, 67, 99, 100, 150]
+            .into_iter()
+            .map(|value| Magnitude::new(Symbol::Zzz, value, 100).count())
+            .collect();
+        assert_eq!(counts, [0, 1, 1, 2, 2, 3, 3, 3, 3]);
+
+        assert_eq!(Magnitude::new(Symbol::Zzz, 5, 0).count(), 0);
+        assert_eq!(
+            Magnitude::new(Symbol::Zzz, u32::MAX, u32::MAX)
+                .max(u32::MAX)
+                .count(),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_magnitude_display() {
+        let danger = Magnitude::new(Emoji::Creature(Creature::Dragon), 2, 4).max(4);
+        assert_eq!(danger.to_string(), "🐉🐉");
+        assert_eq!(Emoji::Creature(Creature::Wolf).repeat(3), "🐺🐺🐺");
+        assert_eq!(Symbol::Sparkles.repeat(0), "");
+    }
+}
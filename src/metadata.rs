@@ -0,0 +1,1483 @@
+//! Descriptive metadata for every emoji, suitable for editor tooling and emoji pickers.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{Category, Creature, Emoji, UnicodeVersion};
+//!
+//! let dragon = Emoji::Creature(Creature::Dragon).metadata();
+//! assert_eq!(dragon.name, "Dragon");
+//! assert_eq!(dragon.glyph, "🐉");
+//! assert_eq!(dragon.category, Category::Creature);
+//! assert_eq!(dragon.unicode_version, UnicodeVersion::V6);
+//! assert!(dragon.keywords.contains(&"fire"));
+//! ```
+
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+/// The top-level grouping an emoji belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Category {
+    /// See [`Person`].
+    Person,
+
+    /// See [`Creature`].
+    Creature,
+
+    /// See [`Location`].
+    Location,
+
+    /// See [`Item`].
+    Item,
+
+    /// See [`Flora`].
+    Flora,
+
+    /// See [`BodyPart`].
+    BodyPart,
+
+    /// See [`Symbol`].
+    Symbol,
+}
+
+/// The version of the Unicode standard that introduced an emoji.
+///
+/// Emoji introduced before Unicode 6.0, the first release with broad emoji support, are reported as
+/// [`UnicodeVersion::V6`]. Minor releases (e.g. Emoji 12.1) are folded into their major version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnicodeVersion {
+    /// Unicode 6.0 (2010) or earlier.
+    V6,
+
+    /// Unicode 7.0 (2014).
+    V7,
+
+    /// Unicode 8.0 (2015).
+    V8,
+
+    /// Unicode 9.0 (2016).
+    V9,
+
+    /// Unicode 10.0 (2017).
+    V10,
+
+    /// Unicode 11.0 (2018).
+    V11,
+
+    /// Unicode 12.0 (2019).
+    V12,
+
+    /// Unicode 13.0 (2020).
+    V13,
+
+    /// Unicode 14.0 (2021).
+    V14,
+
+    /// Unicode 15.0 (2022).
+    V15,
+
+    /// Unicode 16.0 (2024).
+    V16,
+}
+
+/// Describes an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Metadata {
+    /// The name of the emoji, which matches the name of its variant, e.g. "Dragon".
+    pub name: &'static str,
+
+    /// The glyph of the emoji, e.g. "🐉".
+    ///
+    /// For an [`Emoji::Person`], this is the glyph _without_ skin tone or gender modifiers.
+    pub glyph: &'static str,
+
+    /// The category of the emoji.
+    pub category: Category,
+
+    /// The version of the Unicode standard required to display the emoji.
+    pub unicode_version: UnicodeVersion,
+
+    /// Lowercase keywords that describe the emoji, e.g. `["wyrm", "fire", "boss"]`.
+    pub keywords: &'static [&'static str],
+
+    /// A short description of the emoji, e.g. "A dragon".
+    pub description: &'static str,
+
+    /// Former names of the emoji that were renamed, e.g. to fix a typo.
+    ///
+    /// Each alias is also available as a deprecated associated constant, so code and data written
+    /// against an older name keeps working, e.g. `Person::HeardScarfPerson`.
+    pub aliases: &'static [&'static str],
+}
+
+impl Emoji {
+    /// Returns metadata describing this emoji.
+    ///
+    /// For [`Emoji::Person`], the [`Metadata::unicode_version`] accounts for the skin tone and
+    /// gender modifiers, which may require a more recent version than the base emoji.
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Person, SkinTone, UnicodeVersion};
+    ///
+    /// let baby = Emoji::Person(Person::Baby, SkinTone::Neutral, Gender::Neutral);
+    /// assert_eq!(baby.metadata().unicode_version, UnicodeVersion::V6);
+    ///
+    /// let baby = Emoji::Person(Person::Baby, SkinTone::Dark, Gender::Neutral);
+    /// assert_eq!(baby.metadata().unicode_version, UnicodeVersion::V8);
+    /// ```
+    pub fn metadata(&self) -> Metadata {
+        match self {
+            Emoji::Person(person, skin, gender) => {
+                let mut metadata = person.metadata();
+
+                // Skin tone modifiers were introduced in Unicode 8.0, and gendered sequences were
+                // introduced (at the latest) in Unicode 10.0, so this errs on the side of caution.
+                // Generic persons are gendered by distinct codepoints instead, from Unicode 6.0.
+                if person.gendered_glyph(gender).is_some() {
+                    metadata.unicode_version = UnicodeVersion::V6;
+                } else if gender != &Gender::Neutral && person.supports_gender() {
+                    metadata.unicode_version = metadata.unicode_version.max(match person {
+                        Person::BeardedPerson => UnicodeVersion::V13,
+                        _ => UnicodeVersion::V10,
+                    });
+                }
+                if skin != &SkinTone::Neutral && person.supports_skin_tone() {
+                    metadata.unicode_version = metadata.unicode_version.max(UnicodeVersion::V8);
+                }
+
+                metadata
+            }
+            Emoji::Creature(creature) => creature.metadata(),
+            Emoji::Location(location) => location.metadata(),
+            Emoji::Item(item) => item.metadata(),
+            Emoji::Flora(flora) => flora.metadata(),
+            Emoji::BodyPart(body_part) => body_part.metadata(),
+            Emoji::Symbol(symbol) => symbol.metadata(),
+        }
+    }
+}
+
+impl Person {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Person,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Artist => "Artist",
+            Self::Baby => "Baby",
+            Self::BaldPerson => "BaldPerson",
+            Self::BeardedPerson => "BeardedPerson",
+            Self::Child => "Child",
+            Self::Fairy => "Fairy",
+            Self::Elf => "Elf",
+            Self::Genie => "Genie",
+            Self::HeadScarfPerson => "HeadScarfPerson",
+            Self::Mage => "Mage",
+            Self::MerPerson => "MerPerson",
+            Self::OldPerson => "OldPerson",
+            Self::Person => "Person",
+            Self::Royalty => "Royalty",
+            Self::SkullCapPerson => "SkullCapPerson",
+            Self::TurbanPerson => "TurbanPerson",
+            Self::Vampire => "Vampire",
+            Self::Zombie => "Zombie",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Artist => UnicodeVersion::V12,
+            Self::Baby => UnicodeVersion::V6,
+            Self::BaldPerson => UnicodeVersion::V12,
+            Self::BeardedPerson => UnicodeVersion::V10,
+            Self::Child => UnicodeVersion::V10,
+            Self::Fairy => UnicodeVersion::V10,
+            Self::Elf => UnicodeVersion::V10,
+            Self::Genie => UnicodeVersion::V10,
+            Self::HeadScarfPerson => UnicodeVersion::V10,
+            Self::Mage => UnicodeVersion::V10,
+            Self::MerPerson => UnicodeVersion::V10,
+            Self::OldPerson => UnicodeVersion::V10,
+            Self::Person => UnicodeVersion::V10,
+            Self::Royalty => UnicodeVersion::V9,
+            Self::SkullCapPerson => UnicodeVersion::V6,
+            Self::TurbanPerson => UnicodeVersion::V6,
+            Self::Vampire => UnicodeVersion::V10,
+            Self::Zombie => UnicodeVersion::V10,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Artist => &["painter", "craft"],
+            Self::Baby => &["infant", "young"],
+            Self::BaldPerson => &["bald", "hairless"],
+            Self::BeardedPerson => &["beard", "dwarf"],
+            Self::Child => &["young", "kid"],
+            Self::Fairy => &["fae", "sprite", "magic"],
+            Self::Elf => &["fae", "magic", "ranger"],
+            Self::Genie => &["djinn", "wish", "magic"],
+            Self::HeadScarfPerson => &["hijab", "headscarf"],
+            Self::Mage => &["wizard", "witch", "sorcerer", "magic"],
+            Self::MerPerson => &["mermaid", "merman", "sea"],
+            Self::OldPerson => &["elder", "old", "sage"],
+            Self::Person => &["human", "commoner", "adult"],
+            Self::Royalty => &["king", "queen", "prince", "princess", "noble"],
+            Self::SkullCapPerson => &["cap", "hat"],
+            Self::TurbanPerson => &["turban", "traveler"],
+            Self::Vampire => &["undead", "dracula", "blood"],
+            Self::Zombie => &["undead", "walking dead"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Artist => "An artist",
+            Self::Baby => "A baby",
+            Self::BaldPerson => "A bald person",
+            Self::BeardedPerson => "A person with a beard",
+            Self::Child => "A child",
+            Self::Fairy => "A fairy",
+            Self::Elf => "An elf",
+            Self::Genie => "A genie",
+            Self::HeadScarfPerson => "A person with a head scarf",
+            Self::Mage => "A mage",
+            Self::MerPerson => "A mer-person",
+            Self::OldPerson => "An old person",
+            Self::Person => "A person",
+            Self::Royalty => "A person of royalty",
+            Self::SkullCapPerson => "A person with a skull cap",
+            Self::TurbanPerson => "A person with a turban",
+            Self::Vampire => "A vampire",
+            Self::Zombie => "A zombie",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::HeadScarfPerson => &["HeardScarfPerson"],
+            _ => &[],
+        }
+    }
+}
+
+impl Creature {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Creature,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Ant => "Ant",
+            Self::Badger => "Badger",
+            Self::Bat => "Bat",
+            Self::Bear => "Bear",
+            Self::Beaver => "Beaver",
+            Self::Beetle => "Beetle",
+            Self::Bison => "Bison",
+            Self::BlackBird => "BlackBird",
+            Self::Blowfish => "Blowfish",
+            Self::Boar => "Boar",
+            Self::Bug => "Bug",
+            Self::Butterfly => "Butterfly",
+            Self::Camel => "Camel",
+            Self::Cat => "Cat",
+            Self::Cockroach => "Cockroach",
+            Self::Cow => "Cow",
+            Self::Crab => "Crab",
+            Self::Cricket => "Cricket",
+            Self::Crocodile => "Crocodile",
+            Self::Deer => "Deer",
+            Self::Dog => "Dog",
+            Self::Dolphin => "Dolphin",
+            Self::Donkey => "Donkey",
+            Self::Dove => "Dove",
+            Self::Dragon => "Dragon",
+            Self::Eagle => "Eagle",
+            Self::Elephant => "Elephant",
+            Self::Fish => "Fish",
+            Self::Fly => "Fly",
+            Self::Fox => "Fox",
+            Self::Frog => "Frog",
+            Self::Ghost => "Ghost",
+            Self::Goat => "Goat",
+            Self::Goblin => "Goblin",
+            Self::Gorilla => "Gorilla",
+            Self::Hedgehog => "Hedgehog",
+            Self::Honeybee => "Honeybee",
+            Self::Horse => "Horse",
+            Self::Jellyfish => "Jellyfish",
+            Self::Leopard => "Leopard",
+            Self::Lion => "Lion",
+            Self::Lizard => "Lizard",
+            Self::Llama => "Llama",
+            Self::Mammoth => "Mammoth",
+            Self::Monkey => "Monkey",
+            Self::Moose => "Moose",
+            Self::Mosquito => "Mosquito",
+            Self::Mouse => "Mouse",
+            Self::Octopus => "Octopus",
+            Self::Ogre => "Ogre",
+            Self::Otter => "Otter",
+            Self::Owl => "Owl",
+            Self::Ox => "Ox",
+            Self::Parrot => "Parrot",
+            Self::Peacock => "Peacock",
+            Self::Pig => "Pig",
+            Self::Rabbit => "Rabbit",
+            Self::Raccoon => "Raccoon",
+            Self::Ram => "Ram",
+            Self::Rat => "Rat",
+            Self::Rhinoceros => "Rhinoceros",
+            Self::Rooster => "Rooster",
+            Self::Scorpion => "Scorpion",
+            Self::Seal => "Seal",
+            Self::Shark => "Shark",
+            Self::Sheep => "Sheep",
+            Self::Snail => "Snail",
+            Self::Snake => "Snake",
+            Self::Spider => "Spider",
+            Self::Squid => "Squid",
+            Self::Swan => "Swan",
+            Self::Tiger => "Tiger",
+            Self::TropicalFish => "TropicalFish",
+            Self::Turkey => "Turkey",
+            Self::Turtle => "Turtle",
+            Self::WaterBuffalo => "WaterBuffalo",
+            Self::Whale => "Whale",
+            Self::Wolf => "Wolf",
+            Self::Worm => "Worm",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Ant => UnicodeVersion::V6,
+            Self::Badger => UnicodeVersion::V11,
+            Self::Bat => UnicodeVersion::V9,
+            Self::Bear => UnicodeVersion::V6,
+            Self::Beaver => UnicodeVersion::V13,
+            Self::Beetle => UnicodeVersion::V6,
+            Self::Bison => UnicodeVersion::V13,
+            Self::BlackBird => UnicodeVersion::V15,
+            Self::Blowfish => UnicodeVersion::V6,
+            Self::Boar => UnicodeVersion::V6,
+            Self::Bug => UnicodeVersion::V6,
+            Self::Butterfly => UnicodeVersion::V9,
+            Self::Camel => UnicodeVersion::V6,
+            Self::Cat => UnicodeVersion::V6,
+            Self::Cockroach => UnicodeVersion::V13,
+            Self::Cow => UnicodeVersion::V6,
+            Self::Crab => UnicodeVersion::V8,
+            Self::Cricket => UnicodeVersion::V10,
+            Self::Crocodile => UnicodeVersion::V6,
+            Self::Deer => UnicodeVersion::V9,
+            Self::Dog => UnicodeVersion::V6,
+            Self::Dolphin => UnicodeVersion::V6,
+            Self::Donkey => UnicodeVersion::V15,
+            Self::Dove => UnicodeVersion::V7,
+            Self::Dragon => UnicodeVersion::V6,
+            Self::Eagle => UnicodeVersion::V9,
+            Self::Elephant => UnicodeVersion::V6,
+            Self::Fish => UnicodeVersion::V6,
+            Self::Fly => UnicodeVersion::V13,
+            Self::Fox => UnicodeVersion::V9,
+            Self::Frog => UnicodeVersion::V6,
+            Self::Ghost => UnicodeVersion::V6,
+            Self::Goat => UnicodeVersion::V6,
+            Self::Goblin => UnicodeVersion::V6,
+            Self::Gorilla => UnicodeVersion::V9,
+            Self::Hedgehog => UnicodeVersion::V10,
+            Self::Honeybee => UnicodeVersion::V6,
+            Self::Horse => UnicodeVersion::V6,
+            Self::Jellyfish => UnicodeVersion::V15,
+            Self::Leopard => UnicodeVersion::V6,
+            Self::Lion => UnicodeVersion::V8,
+            Self::Lizard => UnicodeVersion::V9,
+            Self::Llama => UnicodeVersion::V11,
+            Self::Mammoth => UnicodeVersion::V13,
+            Self::Monkey => UnicodeVersion::V6,
+            Self::Moose => UnicodeVersion::V15,
+            Self::Mosquito => UnicodeVersion::V11,
+            Self::Mouse => UnicodeVersion::V6,
+            Self::Octopus => UnicodeVersion::V6,
+            Self::Ogre => UnicodeVersion::V6,
+            Self::Otter => UnicodeVersion::V12,
+            Self::Owl => UnicodeVersion::V9,
+            Self::Ox => UnicodeVersion::V6,
+            Self::Parrot => UnicodeVersion::V11,
+            Self::Peacock => UnicodeVersion::V11,
+            Self::Pig => UnicodeVersion::V6,
+            Self::Rabbit => UnicodeVersion::V6,
+            Self::Raccoon => UnicodeVersion::V11,
+            Self::Ram => UnicodeVersion::V6,
+            Self::Rat => UnicodeVersion::V6,
+            Self::Rhinoceros => UnicodeVersion::V9,
+            Self::Rooster => UnicodeVersion::V6,
+            Self::Scorpion => UnicodeVersion::V8,
+            Self::Seal => UnicodeVersion::V13,
+            Self::Shark => UnicodeVersion::V9,
+            Self::Sheep => UnicodeVersion::V6,
+            Self::Snail => UnicodeVersion::V6,
+            Self::Snake => UnicodeVersion::V6,
+            Self::Spider => UnicodeVersion::V7,
+            Self::Squid => UnicodeVersion::V9,
+            Self::Swan => UnicodeVersion::V11,
+            Self::Tiger => UnicodeVersion::V6,
+            Self::TropicalFish => UnicodeVersion::V6,
+            Self::Turkey => UnicodeVersion::V8,
+            Self::Turtle => UnicodeVersion::V6,
+            Self::WaterBuffalo => UnicodeVersion::V6,
+            Self::Whale => UnicodeVersion::V6,
+            Self::Wolf => UnicodeVersion::V6,
+            Self::Worm => UnicodeVersion::V13,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Ant => &["insect", "colony"],
+            Self::Badger => &["forest", "burrow", "fierce"],
+            Self::Bat => &["cave", "night", "vampire"],
+            Self::Bear => &["forest", "mountain", "beast"],
+            Self::Beaver => &["river", "dam", "wood"],
+            Self::Beetle => &["insect", "ladybug"],
+            Self::Bison => &["buffalo", "plains"],
+            Self::BlackBird => &["raven", "crow", "omen"],
+            Self::Blowfish => &["fish", "sea", "poison"],
+            Self::Boar => &["pig", "wild", "hunt"],
+            Self::Bug => &["insect", "caterpillar", "worm"],
+            Self::Butterfly => &["insect", "pretty"],
+            Self::Camel => &["desert", "mount"],
+            Self::Cat => &["pet", "feline"],
+            Self::Cockroach => &["insect", "pest", "vermin"],
+            Self::Cow => &["farm", "livestock", "cattle"],
+            Self::Crab => &["sea", "beach", "shellfish"],
+            Self::Cricket => &["insect", "grasshopper", "night"],
+            Self::Crocodile => &["swamp", "reptile", "alligator"],
+            Self::Deer => &["forest", "stag", "hunt"],
+            Self::Dog => &["pet", "hound", "canine"],
+            Self::Dolphin => &["sea", "swim", "friendly"],
+            Self::Donkey => &["farm", "mount", "caravan"],
+            Self::Dove => &["bird", "peace", "messenger"],
+            Self::Dragon => &["wyrm", "fire", "boss"],
+            Self::Eagle => &["bird", "sky", "hunt"],
+            Self::Elephant => &["beast", "large"],
+            Self::Fish => &["sea", "water", "food"],
+            Self::Fly => &["insect", "pest", "rot"],
+            Self::Fox => &["forest", "cunning", "trickster"],
+            Self::Frog => &["swamp", "toad", "ingredient"],
+            Self::Ghost => &["undead", "spirit", "haunt"],
+            Self::Goat => &["farm", "livestock", "mountain"],
+            Self::Goblin => &["monster", "tengu", "imp"],
+            Self::Gorilla => &["jungle", "ape", "strong"],
+            Self::Hedgehog => &["forest", "spiky", "small"],
+            Self::Honeybee => &["insect", "bee", "honey"],
+            Self::Horse => &["mount", "steed", "ride"],
+            Self::Jellyfish => &["sea", "sting", "poison"],
+            Self::Leopard => &["cat", "predator", "jungle"],
+            Self::Lion => &["savanna", "king", "pride"],
+            Self::Lizard => &["reptile", "gecko", "ingredient"],
+            Self::Llama => &["alpaca", "mount", "pack"],
+            Self::Mammoth => &["extinct", "tusk", "ice"],
+            Self::Monkey => &["jungle", "mischief", "climb"],
+            Self::Moose => &["forest", "antlers", "mount"],
+            Self::Mosquito => &["insect", "swamp", "disease"],
+            Self::Mouse => &["rodent", "vermin"],
+            Self::Octopus => &["sea", "tentacle", "kraken"],
+            Self::Ogre => &["monster", "oni", "troll"],
+            Self::Otter => &["river", "swim", "playful"],
+            Self::Owl => &["bird", "night", "familiar", "wisdom"],
+            Self::Ox => &["farm", "livestock", "plow"],
+            Self::Parrot => &["bird", "pirate", "talk"],
+            Self::Peacock => &["bird", "proud", "feather"],
+            Self::Pig => &["farm", "livestock", "swine"],
+            Self::Rabbit => &["bunny", "hare"],
+            Self::Raccoon => &["forest", "thief", "night"],
+            Self::Ram => &["sheep", "farm", "horns"],
+            Self::Rat => &["rodent", "vermin", "sewer"],
+            Self::Rhinoceros => &["beast", "horn"],
+            Self::Rooster => &["bird", "farm", "chicken", "dawn"],
+            Self::Scorpion => &["desert", "poison", "sting"],
+            Self::Seal => &["sea", "ice", "selkie"],
+            Self::Shark => &["sea", "predator"],
+            Self::Sheep => &["farm", "livestock", "ewe", "wool"],
+            Self::Snail => &["slow", "shell", "slime"],
+            Self::Snake => &["serpent", "poison", "reptile"],
+            Self::Spider => &["arachnid", "web", "poison"],
+            Self::Squid => &["sea", "tentacle", "ink"],
+            Self::Swan => &["bird", "lake", "grace"],
+            Self::Tiger => &["cat", "predator", "jungle"],
+            Self::TropicalFish => &["fish", "sea", "reef"],
+            Self::Turkey => &["farm", "bird", "feast"],
+            Self::Turtle => &["sea", "shell", "slow"],
+            Self::WaterBuffalo => &["buffalo", "farm", "ox"],
+            Self::Whale => &["sea", "leviathan", "large"],
+            Self::Wolf => &["canine", "pack", "werewolf"],
+            Self::Worm => &["dirt", "bait", "ingredient"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Ant => "An ant",
+            Self::Badger => "A badger",
+            Self::Bat => "A bat",
+            Self::Bear => "A bear",
+            Self::Beaver => "A beaver",
+            Self::Beetle => "A beetle",
+            Self::Bison => "A bison",
+            Self::BlackBird => "A black bird",
+            Self::Blowfish => "A blowfish",
+            Self::Boar => "A boar",
+            Self::Bug => "A bug",
+            Self::Butterfly => "A butterfly",
+            Self::Camel => "A camel",
+            Self::Cat => "A cat",
+            Self::Cockroach => "A cockroach",
+            Self::Cow => "A cow",
+            Self::Crab => "A crab",
+            Self::Cricket => "A cricket",
+            Self::Crocodile => "A crocodile",
+            Self::Deer => "A deer",
+            Self::Dog => "A dog",
+            Self::Dolphin => "A dolphin",
+            Self::Donkey => "A donkey",
+            Self::Dove => "A dove",
+            Self::Dragon => "A dragon",
+            Self::Eagle => "An eagle",
+            Self::Elephant => "An elephant",
+            Self::Fish => "A fish",
+            Self::Fly => "A fly",
+            Self::Fox => "A fox",
+            Self::Frog => "A frog",
+            Self::Ghost => "A ghost",
+            Self::Goat => "A goat",
+            Self::Goblin => "A goblin",
+            Self::Gorilla => "A gorilla",
+            Self::Hedgehog => "A hedgehog",
+            Self::Honeybee => "A honeybee",
+            Self::Horse => "A horse",
+            Self::Jellyfish => "A jellyfish",
+            Self::Leopard => "A leopard",
+            Self::Lion => "A lion",
+            Self::Lizard => "A lizard",
+            Self::Llama => "A llama",
+            Self::Mammoth => "A mammoth",
+            Self::Monkey => "A monkey",
+            Self::Moose => "A moose",
+            Self::Mosquito => "A mosquito",
+            Self::Mouse => "A mouse",
+            Self::Octopus => "An octopus",
+            Self::Ogre => "An ogre",
+            Self::Otter => "An otter",
+            Self::Owl => "An owl",
+            Self::Ox => "An ox",
+            Self::Parrot => "A parrot",
+            Self::Peacock => "A peacock",
+            Self::Pig => "A pig",
+            Self::Rabbit => "A rabbit",
+            Self::Raccoon => "A raccoon",
+            Self::Ram => "A ram",
+            Self::Rat => "A rat",
+            Self::Rhinoceros => "A rhinoceros",
+            Self::Rooster => "A rooster",
+            Self::Scorpion => "A scorpion",
+            Self::Seal => "A seal",
+            Self::Shark => "A shark",
+            Self::Sheep => "A sheep",
+            Self::Snail => "A snail",
+            Self::Snake => "A snake",
+            Self::Spider => "A spider",
+            Self::Squid => "A squid",
+            Self::Swan => "A swan",
+            Self::Tiger => "A tiger",
+            Self::TropicalFish => "A tropical fish",
+            Self::Turkey => "A turkey",
+            Self::Turtle => "A turtle",
+            Self::WaterBuffalo => "A water buffalo",
+            Self::Whale => "A whale",
+            Self::Wolf => "A wolf",
+            Self::Worm => "A worm",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl Location {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Location,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::BoatSail => "BoatSail",
+            Self::BuildingClassic => "BuildingClassic",
+            Self::Campsite => "Campsite",
+            Self::Canoe => "Canoe",
+            Self::Castle => "Castle",
+            Self::CastleJapanese => "CastleJapanese",
+            Self::Cave => "Cave",
+            Self::Desert => "Desert",
+            Self::Hut => "Hut",
+            Self::Mountain => "Mountain",
+            Self::MountainSnow => "MountainSnow",
+            Self::Oasis => "Oasis",
+            Self::Palace => "Palace",
+            Self::Tent => "Tent",
+            Self::TreeDeciduous => "TreeDeciduous",
+            Self::TreeEvergreen => "TreeEvergreen",
+            Self::TreeLeafless => "TreeLeafless",
+            Self::TreePalm => "TreePalm",
+            Self::Volcano => "Volcano",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::BoatSail => UnicodeVersion::V6,
+            Self::BuildingClassic => UnicodeVersion::V7,
+            Self::Campsite => UnicodeVersion::V7,
+            Self::Canoe => UnicodeVersion::V9,
+            Self::Castle => UnicodeVersion::V6,
+            Self::CastleJapanese => UnicodeVersion::V6,
+            Self::Cave => UnicodeVersion::V7,
+            Self::Desert => UnicodeVersion::V7,
+            Self::Hut => UnicodeVersion::V13,
+            Self::Mountain => UnicodeVersion::V6,
+            Self::MountainSnow => UnicodeVersion::V7,
+            Self::Oasis => UnicodeVersion::V7,
+            Self::Palace => UnicodeVersion::V6,
+            Self::Tent => UnicodeVersion::V6,
+            Self::TreeDeciduous => UnicodeVersion::V6,
+            Self::TreeEvergreen => UnicodeVersion::V6,
+            Self::TreeLeafless => UnicodeVersion::V16,
+            Self::TreePalm => UnicodeVersion::V6,
+            Self::Volcano => UnicodeVersion::V6,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::BoatSail => &["ship", "sea", "sailboat"],
+            Self::BuildingClassic => &["temple", "ruins", "columns"],
+            Self::Campsite => &["camp", "rest", "wilderness"],
+            Self::Canoe => &["boat", "river"],
+            Self::Castle => &["fortress", "keep", "kingdom"],
+            Self::CastleJapanese => &["castle", "fortress", "keep"],
+            Self::Cave => &["hole", "dungeon", "pit"],
+            Self::Desert => &["sand", "dunes", "arid"],
+            Self::Hut => &["house", "village", "shack"],
+            Self::Mountain => &["peak", "hill"],
+            Self::MountainSnow => &["mountain", "snow", "peak"],
+            Self::Oasis => &["desert", "water", "rest"],
+            Self::Palace => &["castle", "royal", "court"],
+            Self::Tent => &["camp", "rest"],
+            Self::TreeDeciduous => &["tree", "forest", "woods"],
+            Self::TreeEvergreen => &["tree", "forest", "pine"],
+            Self::TreeLeafless => &["tree", "dead", "blight", "winter"],
+            Self::TreePalm => &["tree", "island", "beach"],
+            Self::Volcano => &["mountain", "lava", "fire"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::BoatSail => "A sailboat",
+            Self::BuildingClassic => "A classic building",
+            Self::Campsite => "A campsite",
+            Self::Canoe => "A canoe",
+            Self::Castle => "A castle",
+            Self::CastleJapanese => "A Japanese-style castle",
+            Self::Cave => "A cave",
+            Self::Desert => "A desert",
+            Self::Hut => "A hut",
+            Self::Mountain => "A mountain",
+            Self::MountainSnow => "A mountain in the snow",
+            Self::Oasis => "An oasis",
+            Self::Palace => "A palace",
+            Self::Tent => "A tent",
+            Self::TreeDeciduous => "A deciduous tree",
+            Self::TreeEvergreen => "An evergreen tree",
+            Self::TreeLeafless => "A leafless tree",
+            Self::TreePalm => "A palm tree",
+            Self::Volcano => "A volcano",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl Item {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Item,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Amulet => "Amulet",
+            Self::Axe => "Axe",
+            Self::Bag => "Bag",
+            Self::Bandage => "Bandage",
+            Self::Bed => "Bed",
+            Self::Beer => "Beer",
+            Self::BloodDrop => "BloodDrop",
+            Self::Bomb => "Bomb",
+            Self::BookClosed => "BookClosed",
+            Self::BookOpen => "BookOpen",
+            Self::Boomerang => "Boomerang",
+            Self::BowAndArrow => "BowAndArrow",
+            Self::Brick => "Brick",
+            Self::Candle => "Candle",
+            Self::Coat => "Coat",
+            Self::Coffin => "Coffin",
+            Self::Coin => "Coin",
+            Self::Crown => "Crown",
+            Self::CrystalBall => "CrystalBall",
+            Self::Dagger => "Dagger",
+            Self::Dart => "Dart",
+            Self::DiyaLamp => "DiyaLamp",
+            Self::Door => "Door",
+            Self::FlagBlack => "FlagBlack",
+            Self::FlagCheckered => "FlagCheckered",
+            Self::FlagTriangle => "FlagTriangle",
+            Self::Firecracker => "Firecracker",
+            Self::FlagsCrossed => "FlagsCrossed",
+            Self::Flashlight => "Flashlight",
+            Self::GemStone => "GemStone",
+            Self::Grave => "Grave",
+            Self::Hammer => "Hammer",
+            Self::HammerAndPick => "HammerAndPick",
+            Self::Harp => "Harp",
+            Self::HeartRed => "HeartRed",
+            Self::HourglassDone => "HourglassDone",
+            Self::HourglassNotDone => "HourglassNotDone",
+            Self::Jar => "Jar",
+            Self::Key => "Key",
+            Self::Leaf => "Leaf",
+            Self::LeafFallen => "LeafFallen",
+            Self::LeafMaple => "LeafMaple",
+            Self::LightBulb => "LightBulb",
+            Self::Map => "Map",
+            Self::MeatOnBone => "MeatOnBone",
+            Self::MeatCut => "MeatCut",
+            Self::MouseTrap => "MouseTrap",
+            Self::Pick => "Pick",
+            Self::PoultryLeg => "PoultryLeg",
+            Self::PrayerBeads => "PrayerBeads",
+            Self::RedEnvelope => "RedEnvelope",
+            Self::RedLantern => "RedLantern",
+            Self::Rock => "Rock",
+            Self::Scroll => "Scroll",
+            Self::Shield => "Shield",
+            Self::Shovel => "Shovel",
+            Self::SpiderWeb => "SpiderWeb",
+            Self::SwordsCrossed => "SwordsCrossed",
+            Self::Trident => "Trident",
+            Self::Urn => "Urn",
+            Self::Wand => "Wand",
+            Self::WaterDrop => "WaterDrop",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Amulet => UnicodeVersion::V11,
+            Self::Axe => UnicodeVersion::V12,
+            Self::Bag => UnicodeVersion::V6,
+            Self::Bandage => UnicodeVersion::V12,
+            Self::Bed => UnicodeVersion::V7,
+            Self::Beer => UnicodeVersion::V6,
+            Self::BloodDrop => UnicodeVersion::V12,
+            Self::Bomb => UnicodeVersion::V6,
+            Self::BookClosed => UnicodeVersion::V6,
+            Self::BookOpen => UnicodeVersion::V6,
+            Self::Boomerang => UnicodeVersion::V13,
+            Self::BowAndArrow => UnicodeVersion::V8,
+            Self::Brick => UnicodeVersion::V11,
+            Self::Candle => UnicodeVersion::V7,
+            Self::Coat => UnicodeVersion::V10,
+            Self::Coffin => UnicodeVersion::V6,
+            Self::Coin => UnicodeVersion::V13,
+            Self::Crown => UnicodeVersion::V6,
+            Self::CrystalBall => UnicodeVersion::V6,
+            Self::Dagger => UnicodeVersion::V7,
+            Self::Dart => UnicodeVersion::V6,
+            Self::DiyaLamp => UnicodeVersion::V12,
+            Self::Door => UnicodeVersion::V6,
+            Self::FlagBlack => UnicodeVersion::V7,
+            Self::FlagCheckered => UnicodeVersion::V6,
+            Self::FlagTriangle => UnicodeVersion::V6,
+            Self::Firecracker => UnicodeVersion::V11,
+            Self::FlagsCrossed => UnicodeVersion::V6,
+            Self::Flashlight => UnicodeVersion::V6,
+            Self::GemStone => UnicodeVersion::V6,
+            Self::Grave => UnicodeVersion::V13,
+            Self::Hammer => UnicodeVersion::V6,
+            Self::HammerAndPick => UnicodeVersion::V6,
+            Self::Harp => UnicodeVersion::V16,
+            Self::HeartRed => UnicodeVersion::V6,
+            Self::HourglassDone => UnicodeVersion::V6,
+            Self::HourglassNotDone => UnicodeVersion::V6,
+            Self::Jar => UnicodeVersion::V8,
+            Self::Key => UnicodeVersion::V7,
+            Self::Leaf => UnicodeVersion::V6,
+            Self::LeafFallen => UnicodeVersion::V6,
+            Self::LeafMaple => UnicodeVersion::V6,
+            Self::LightBulb => UnicodeVersion::V6,
+            Self::Map => UnicodeVersion::V7,
+            Self::MeatOnBone => UnicodeVersion::V6,
+            Self::MeatCut => UnicodeVersion::V10,
+            Self::MouseTrap => UnicodeVersion::V13,
+            Self::Pick => UnicodeVersion::V6,
+            Self::PoultryLeg => UnicodeVersion::V6,
+            Self::PrayerBeads => UnicodeVersion::V8,
+            Self::RedEnvelope => UnicodeVersion::V11,
+            Self::RedLantern => UnicodeVersion::V6,
+            Self::Rock => UnicodeVersion::V13,
+            Self::Scroll => UnicodeVersion::V6,
+            Self::Shield => UnicodeVersion::V7,
+            Self::Shovel => UnicodeVersion::V16,
+            Self::SpiderWeb => UnicodeVersion::V7,
+            Self::SwordsCrossed => UnicodeVersion::V6,
+            Self::Trident => UnicodeVersion::V6,
+            Self::Urn => UnicodeVersion::V6,
+            Self::Wand => UnicodeVersion::V13,
+            Self::WaterDrop => UnicodeVersion::V6,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Amulet => &["charm", "nazar", "protection"],
+            Self::Axe => &["weapon", "tool", "wood"],
+            Self::Bag => &["backpack", "inventory", "pack"],
+            Self::Bandage => &["heal", "medicine"],
+            Self::Bed => &["rest", "sleep", "inn"],
+            Self::Beer => &["drink", "ale", "tavern"],
+            Self::BloodDrop => &["blood", "wound"],
+            Self::Bomb => &["explosive", "weapon"],
+            Self::BookClosed => &["book", "tome"],
+            Self::BookOpen => &["book", "spellbook", "read"],
+            Self::Boomerang => &["weapon", "throw"],
+            Self::BowAndArrow => &["weapon", "archery", "ranged"],
+            Self::Brick => &["wall", "building"],
+            Self::Candle => &["light", "wax"],
+            Self::Coat => &["armor", "clothing", "cloak"],
+            Self::Coffin => &["death", "undead", "burial"],
+            Self::Coin => &["gold", "money", "currency"],
+            Self::Crown => &["royalty", "king", "queen"],
+            Self::CrystalBall => &["magic", "fortune", "scry"],
+            Self::Dagger => &["weapon", "knife", "blade"],
+            Self::Dart => &["target", "bullseye"],
+            Self::DiyaLamp => &["light", "lamp", "oil"],
+            Self::Door => &["entrance", "exit"],
+            Self::FlagBlack => &["flag", "pirate"],
+            Self::FlagCheckered => &["flag", "race", "finish"],
+            Self::FlagTriangle => &["flag", "marker"],
+            Self::Firecracker => &["explosive", "dynamite"],
+            Self::FlagsCrossed => &["flag", "duel", "faction"],
+            Self::Flashlight => &["light", "torch"],
+            Self::GemStone => &["gem", "jewel", "diamond", "treasure"],
+            Self::Grave => &["death", "tombstone", "burial"],
+            Self::Hammer => &["tool", "weapon", "smith"],
+            Self::HammerAndPick => &["tool", "mining", "smith"],
+            Self::Harp => &["instrument", "bard", "music"],
+            Self::HeartRed => &["health", "love", "life"],
+            Self::HourglassDone => &["time", "wait"],
+            Self::HourglassNotDone => &["time", "wait"],
+            Self::Jar => &["amphora", "pottery", "vase"],
+            Self::Key => &["lock", "unlock", "door"],
+            Self::Leaf => &["plant", "nature"],
+            Self::LeafFallen => &["plant", "autumn"],
+            Self::LeafMaple => &["plant", "autumn"],
+            Self::LightBulb => &["light", "idea"],
+            Self::Map => &["world", "treasure", "navigation"],
+            Self::MeatOnBone => &["food", "meat"],
+            Self::MeatCut => &["food", "meat", "steak"],
+            Self::MouseTrap => &["trap", "snare", "bait"],
+            Self::Pick => &["tool", "mining"],
+            Self::PoultryLeg => &["food", "meat", "chicken"],
+            Self::PrayerBeads => &["religion", "holy", "rosary"],
+            Self::RedEnvelope => &["gift", "money", "letter"],
+            Self::RedLantern => &["light", "lantern"],
+            Self::Rock => &["stone", "boulder"],
+            Self::Scroll => &["spell", "paper", "quest"],
+            Self::Shield => &["armor", "defense", "block"],
+            Self::Shovel => &["tool", "dig", "grave"],
+            Self::SpiderWeb => &["web", "cobweb", "trap"],
+            Self::SwordsCrossed => &["weapon", "sword", "battle", "combat"],
+            Self::Trident => &["weapon", "spear", "sea"],
+            Self::Urn => &["death", "ashes", "funeral"],
+            Self::Wand => &["magic", "spell"],
+            Self::WaterDrop => &["water", "mana", "sweat"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Amulet => "An amulet",
+            Self::Axe => "An axe",
+            Self::Bag => "A bag",
+            Self::Bandage => "A bandage",
+            Self::Bed => "A bed",
+            Self::Beer => "A beer",
+            Self::BloodDrop => "A drop of blood",
+            Self::Bomb => "A bomb",
+            Self::BookClosed => "A closed book",
+            Self::BookOpen => "An open book",
+            Self::Boomerang => "A boomerang",
+            Self::BowAndArrow => "A bow and arrow",
+            Self::Brick => "A brick",
+            Self::Candle => "A candle",
+            Self::Coat => "A coat",
+            Self::Coffin => "A coffin",
+            Self::Coin => "A coin",
+            Self::Crown => "A crown",
+            Self::CrystalBall => "A crystal ball",
+            Self::Dagger => "A dagger",
+            Self::Dart => "A dart",
+            Self::DiyaLamp => "A small oil lamp",
+            Self::Door => "A door",
+            Self::FlagBlack => "A black flag",
+            Self::FlagCheckered => "A chequered flag",
+            Self::FlagTriangle => "A triangular flag",
+            Self::Firecracker => "A firecracker",
+            Self::FlagsCrossed => "Two crossed flags",
+            Self::Flashlight => "A handheld light, a stand-in for a torch",
+            Self::GemStone => "A gemstone",
+            Self::Grave => "A grave",
+            Self::Hammer => "A hammer",
+            Self::HammerAndPick => "A hammer and pick",
+            Self::Harp => "A harp",
+            Self::HeartRed => "A red heart",
+            Self::HourglassDone => "A hourglass that is done",
+            Self::HourglassNotDone => "A hourglass that is not done",
+            Self::Jar => "A jar",
+            Self::Key => "A key",
+            Self::Leaf => "A leaf",
+            Self::LeafFallen => "A fallen leaf",
+            Self::LeafMaple => "A maple leaf",
+            Self::LightBulb => "A light bulb",
+            Self::Map => "A map",
+            Self::MeatOnBone => "A meat on a bone",
+            Self::MeatCut => "A cut of meat",
+            Self::MouseTrap => "A mouse trap",
+            Self::Pick => "A pickaxe",
+            Self::PoultryLeg => "A poultry leg",
+            Self::PrayerBeads => "Prayer beads",
+            Self::RedEnvelope => "A red envelope",
+            Self::RedLantern => "A red lantern",
+            Self::Rock => "A rock",
+            Self::Scroll => "A scroll",
+            Self::Shield => "A shield",
+            Self::Shovel => "A shovel",
+            Self::SpiderWeb => "A spider web",
+            Self::SwordsCrossed => "Swords crossed",
+            Self::Trident => "A trident",
+            Self::Urn => "An urn",
+            Self::Wand => "A wand",
+            Self::WaterDrop => "A water drop",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl Flora {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Flora,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Cactus => "Cactus",
+            Self::Clover => "Clover",
+            Self::FlowerWilted => "FlowerWilted",
+            Self::Herb => "Herb",
+            Self::Mushroom => "Mushroom",
+            Self::Rose => "Rose",
+            Self::Sheaf => "Sheaf",
+            Self::Sunflower => "Sunflower",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Cactus => UnicodeVersion::V6,
+            Self::Clover => UnicodeVersion::V6,
+            Self::FlowerWilted => UnicodeVersion::V9,
+            Self::Herb => UnicodeVersion::V6,
+            Self::Mushroom => UnicodeVersion::V6,
+            Self::Rose => UnicodeVersion::V6,
+            Self::Sheaf => UnicodeVersion::V6,
+            Self::Sunflower => UnicodeVersion::V6,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Cactus => &["desert", "spiny", "succulent"],
+            Self::Clover => &["luck", "four", "leaf"],
+            Self::FlowerWilted => &["wilted", "dead", "decay"],
+            Self::Herb => &["herb", "leaf", "healing"],
+            Self::Mushroom => &["fungus", "toadstool", "spore"],
+            Self::Rose => &["flower", "love", "thorn"],
+            Self::Sheaf => &["grain", "wheat", "harvest"],
+            Self::Sunflower => &["flower", "sun", "seed"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Cactus => "A cactus",
+            Self::Clover => "A four leaf clover",
+            Self::FlowerWilted => "A wilted flower",
+            Self::Herb => "An herb",
+            Self::Mushroom => "A mushroom",
+            Self::Rose => "A rose",
+            Self::Sheaf => "A sheaf of grain",
+            Self::Sunflower => "A sunflower",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl BodyPart {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::BodyPart,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Brain => "Brain",
+            Self::Ear => "Ear",
+            Self::Eye => "Eye",
+            Self::Footprints => "Footprints",
+            Self::Lips => "Lips",
+            Self::Muscle => "Muscle",
+            Self::Tooth => "Tooth",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Brain => UnicodeVersion::V10,
+            Self::Ear => UnicodeVersion::V6,
+            Self::Eye => UnicodeVersion::V7,
+            Self::Footprints => UnicodeVersion::V6,
+            Self::Lips => UnicodeVersion::V6,
+            Self::Muscle => UnicodeVersion::V6,
+            Self::Tooth => UnicodeVersion::V11,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Brain => &["mind", "intellect", "organ"],
+            Self::Ear => &["hear", "listen", "sound"],
+            Self::Eye => &["see", "watch", "sight"],
+            Self::Footprints => &["track", "trail", "clue"],
+            Self::Lips => &["mouth", "kiss", "speak"],
+            Self::Muscle => &["strength", "arm", "flex"],
+            Self::Tooth => &["fang", "bite", "trophy"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Brain => "A brain",
+            Self::Ear => "An ear",
+            Self::Eye => "An eye",
+            Self::Footprints => "Footprints",
+            Self::Lips => "Lips",
+            Self::Muscle => "A flexed muscle",
+            Self::Tooth => "A tooth",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl Symbol {
+    /// Returns metadata describing this emoji.
+    pub const fn metadata(&self) -> Metadata {
+        Metadata {
+            name: self.name(),
+            glyph: self.glyph(),
+            category: Category::Symbol,
+            unicode_version: self.unicode_version(),
+            keywords: self.keywords(),
+            description: self.description(),
+            aliases: self.aliases(),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Anger => "Anger",
+            Self::Comet => "Comet",
+            Self::Cyclone => "Cyclone",
+            Self::Fingerprint => "Fingerprint",
+            Self::Fire => "Fire",
+            Self::Electricity => "Electricity",
+            Self::ExclamationDouble => "ExclamationDouble",
+            Self::ExclamationWithQuestion => "ExclamationWithQuestion",
+            Self::ExclamationRed => "ExclamationRed",
+            Self::ExclamationWhite => "ExclamationWhite",
+            Self::GenderFemale => "GenderFemale",
+            Self::GenderMale => "GenderMale",
+            Self::QuestionRed => "QuestionRed",
+            Self::QuestionWhite => "QuestionWhite",
+            Self::Silhouette => "Silhouette",
+            Self::Silhouettes => "Silhouettes",
+            Self::Sparkles => "Sparkles",
+            Self::SpeechBubble => "SpeechBubble",
+            Self::SpeechBubbleAngry => "SpeechBubbleAngry",
+            Self::Snowflake => "Snowflake",
+            Self::SpeechBubbleLeft => "SpeechBubbleLeft",
+            Self::Splatter => "Splatter",
+            Self::ThoughtBubble => "ThoughtBubble",
+            Self::Zzz => "Zzz",
+        }
+    }
+
+    const fn unicode_version(&self) -> UnicodeVersion {
+        match self {
+            Self::Anger => UnicodeVersion::V6,
+            Self::Comet => UnicodeVersion::V6,
+            Self::Cyclone => UnicodeVersion::V6,
+            Self::Fingerprint => UnicodeVersion::V16,
+            Self::Fire => UnicodeVersion::V6,
+            Self::Electricity => UnicodeVersion::V6,
+            Self::ExclamationDouble => UnicodeVersion::V6,
+            Self::ExclamationWithQuestion => UnicodeVersion::V6,
+            Self::ExclamationRed => UnicodeVersion::V6,
+            Self::ExclamationWhite => UnicodeVersion::V6,
+            Self::GenderFemale => UnicodeVersion::V6,
+            Self::GenderMale => UnicodeVersion::V6,
+            Self::QuestionRed => UnicodeVersion::V6,
+            Self::QuestionWhite => UnicodeVersion::V6,
+            Self::Silhouette => UnicodeVersion::V6,
+            Self::Silhouettes => UnicodeVersion::V6,
+            Self::Sparkles => UnicodeVersion::V6,
+            Self::SpeechBubble => UnicodeVersion::V6,
+            Self::SpeechBubbleAngry => UnicodeVersion::V7,
+            Self::Snowflake => UnicodeVersion::V6,
+            Self::SpeechBubbleLeft => UnicodeVersion::V7,
+            Self::Splatter => UnicodeVersion::V16,
+            Self::ThoughtBubble => UnicodeVersion::V6,
+            Self::Zzz => UnicodeVersion::V6,
+        }
+    }
+
+    const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Anger => &["angry", "rage"],
+            Self::Comet => &["space", "meteor", "magic"],
+            Self::Cyclone => &["storm", "wind", "dizzy"],
+            Self::Fingerprint => &["clue", "mystery", "identity"],
+            Self::Fire => &["flame", "burn", "hot"],
+            Self::Electricity => &["lightning", "shock", "zap"],
+            Self::ExclamationDouble => &["exclamation", "alert"],
+            Self::ExclamationWithQuestion => &["exclamation", "question", "surprise"],
+            Self::ExclamationRed => &["exclamation", "alert", "quest"],
+            Self::ExclamationWhite => &["exclamation", "alert"],
+            Self::GenderFemale => &["female", "woman", "gender"],
+            Self::GenderMale => &["male", "man", "gender"],
+            Self::QuestionRed => &["question", "unknown", "quest"],
+            Self::QuestionWhite => &["question", "unknown"],
+            Self::Silhouette => &["unknown", "hidden", "stealth"],
+            Self::Silhouettes => &["party", "group", "unknown"],
+            Self::Sparkles => &["magic", "shiny", "stars"],
+            Self::SpeechBubble => &["talk", "dialogue", "chat"],
+            Self::SpeechBubbleAngry => &["shout", "dialogue", "angry"],
+            Self::Snowflake => &["cold", "ice", "frost"],
+            Self::SpeechBubbleLeft => &["talk", "dialogue", "off-screen"],
+            Self::Splatter => &["splash", "stain", "mess"],
+            Self::ThoughtBubble => &["think", "dialogue", "monologue"],
+            Self::Zzz => &["sleep", "rest", "tired"],
+        }
+    }
+
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::Anger => "A symbol of anger",
+            Self::Comet => "A symbol of a comet",
+            Self::Cyclone => "A symbol of a cyclone",
+            Self::Fingerprint => "A fingerprint",
+            Self::Fire => "A symbol of fire",
+            Self::Electricity => "A symbol of electricity",
+            Self::ExclamationDouble => "A symbol of two exclamations",
+            Self::ExclamationWithQuestion => "A symbol of an exclamation and a question mark",
+            Self::ExclamationRed => "A symbol of a red exclamation",
+            Self::ExclamationWhite => "A symbol of a white exclamation",
+            Self::GenderFemale => "A symbol of a female",
+            Self::GenderMale => "A symbol of a male",
+            Self::QuestionRed => "A symbol of a red question",
+            Self::QuestionWhite => "A symbol of a white question",
+            Self::Silhouette => "A silhouette of a bust",
+            Self::Silhouettes => "Silhouettes of two busts",
+            Self::Sparkles => "A symbol of sparkles",
+            Self::SpeechBubble => "A speech bubble",
+            Self::SpeechBubbleAngry => "A speech bubble with an angry face",
+            Self::Snowflake => "A snowflake",
+            Self::SpeechBubbleLeft => "A speech bubble pointing left",
+            Self::Splatter => "A splatter",
+            Self::ThoughtBubble => "A thought bubble",
+            Self::Zzz => "A \"zzz\" symbol",
+        }
+    }
+
+    const fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Converts a `PascalCase` name, e.g. [`Metadata::name`], to `kebab-case`.
+pub(crate) fn kebab_case(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len() + 4);
+    for (i, c) in name.char_indices() {
+        if c.is_ascii_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.push(c.to_ascii_lowercase());
+    }
+    kebab
+}
+
+/// Genre vocabulary for existing emojis, sorted by word, e.g. `"wyvern"` for [`Creature::Dragon`].
+///
+/// Each word is a single lowercase word that is not the name or alias of any emoji.
+const SYNONYMS: &[(&str, Emoji)] = &[
+    ("citadel", Emoji::Location(Location::Castle)),
+    ("djinn", person(Person::Genie)),
+    ("drake", Emoji::Creature(Creature::Dragon)),
+    ("fortress", Emoji::Location(Location::Castle)),
+    ("ghoul", person(Person::Zombie)),
+    ("grimoire", Emoji::Item(Item::BookClosed)),
+    ("hound", Emoji::Creature(Creature::Dog)),
+    ("imp", Emoji::Creature(Creature::Goblin)),
+    ("keep", Emoji::Location(Location::Castle)),
+    ("merfolk", person(Person::MerPerson)),
+    ("monarch", person(Person::Royalty)),
+    ("phantom", Emoji::Creature(Creature::Ghost)),
+    ("pixie", person(Person::Fairy)),
+    ("serpent", Emoji::Creature(Creature::Snake)),
+    ("sorcerer", person(Person::Mage)),
+    ("specter", Emoji::Creature(Creature::Ghost)),
+    ("sprite", person(Person::Fairy)),
+    ("steed", Emoji::Creature(Creature::Horse)),
+    ("troll", Emoji::Creature(Creature::Ogre)),
+    ("warlock", person(Person::Mage)),
+    ("wizard", person(Person::Mage)),
+    ("wraith", Emoji::Creature(Creature::Ghost)),
+    ("wyrm", Emoji::Creature(Creature::Dragon)),
+    ("wyvern", Emoji::Creature(Creature::Dragon)),
+];
+
+const fn person(person: Person) -> Emoji {
+    Emoji::Person(person, SkinTone::Neutral, Gender::Neutral)
+}
+
+impl Emoji {
+    /// Returns genre vocabulary that also refers to this emoji, e.g. `["drake", "wyrm", "wyvern"]`
+    /// for [`Creature::Dragon`].
+    ///
+    /// Synonyms are recognized by the `:name:` tokens of [`stream`](crate::stream) and by
+    /// [`EmojiQuery::keyword`](crate::EmojiQuery::keyword), so content written with genre
+    /// vocabulary resolves to existing emojis. Persons are matched regardless of their skin tone and
+    /// gender.
+    ///
+    /// ```
+    /// use mythoji::{Creature, Emoji};
+    ///
+    /// let dragon = Emoji::Creature(Creature::Dragon);
+    /// assert_eq!(dragon.synonyms().collect::<Vec<_>>(), ["drake", "wyrm", "wyvern"]);
+    /// ```
+    pub fn synonyms(&self) -> impl Iterator<Item = &'static str> {
+        let base = self.base();
+        SYNONYMS
+            .iter()
+            .filter(move |(_, emoji)| *emoji == base)
+            .map(|(word, _)| *word)
+    }
+}
+
+/// Returns the emoji with the given `kebab-case` name, alias, or synonym, e.g. `"swords-crossed"`.
+///
+/// Persons are returned with a neutral skin tone and gender.
+pub(crate) fn from_name(name: &str) -> Option<Emoji> {
+    crate::glyph::bases()
+        .find(|emoji| {
+            let metadata = emoji.metadata();
+            kebab_case(metadata.name) == name
+                || metadata.aliases.iter().any(|a| kebab_case(a) == name)
+        })
+        .or_else(|| from_synonym(name))
+}
+
+/// Returns the emoji with the given synonym, e.g. `"wyvern"`.
+fn from_synonym(word: &str) -> Option<Emoji> {
+    SYNONYMS
+        .binary_search_by_key(&word, |(synonym, _)| synonym)
+        .ok()
+        .map(|i| SYNONYMS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synonyms() {
+        assert!(SYNONYMS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (word, emoji) in SYNONYMS {
+            assert_eq!(from_name(word), Some(*emoji), "{}", word);
+            assert!(crate::glyph::bases().all(|emoji| kebab_case(emoji.metadata().name) != *word));
+            assert!(emoji.synonyms().any(|synonym| synonym == *word));
+        }
+        assert_eq!(from_name("keep"), Some(Emoji::Location(Location::Castle)));
+        assert_eq!(from_name("castle"), Some(Emoji::Location(Location::Castle)));
+        assert_eq!(from_name("dragon-slayer"), None);
+
+        let wizard = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Female);
+        assert_eq!(
+            wizard.synonyms().collect::<Vec<_>>(),
+            ["sorcerer", "warlock", "wizard"]
+        );
+        assert_eq!(Emoji::Item(Item::Coin).synonyms().count(), 0);
+    }
+
+    #[test]
+    fn test_metadata_person() {
+        let elf = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Neutral).metadata();
+        assert_eq!(elf.name, "Elf");
+        assert_eq!(elf.glyph, "🧝");
+        assert_eq!(elf.category, Category::Person);
+        assert_eq!(elf.unicode_version, UnicodeVersion::V10);
+        assert_eq!(elf.description, "An elf");
+
+        let royalty = Emoji::Person(Person::Royalty, SkinTone::Light, Gender::Female).metadata();
+        assert_eq!(royalty.glyph, "🤴");
+        assert_eq!(royalty.unicode_version, UnicodeVersion::V9);
+
+        let beard = Emoji::Person(Person::BeardedPerson, SkinTone::Light, Gender::Male);
+        assert_eq!(beard.metadata().unicode_version, UnicodeVersion::V13);
+
+        let woman = Emoji::Person(Person::Person, SkinTone::Neutral, Gender::Female);
+        assert_eq!(woman.metadata().glyph, "🧑");
+        assert_eq!(woman.metadata().unicode_version, UnicodeVersion::V6);
+        let boy = Emoji::Person(Person::Child, SkinTone::Dark, Gender::Male);
+        assert_eq!(boy.metadata().unicode_version, UnicodeVersion::V8);
+
+        let scarf = Person::HeadScarfPerson.metadata();
+        assert_eq!(scarf.name, "HeadScarfPerson");
+        assert_eq!(scarf.aliases, &["HeardScarfPerson"]);
+    }
+
+    #[test]
+    fn test_metadata_simple() {
+        assert_eq!(
+            Emoji::Location(Location::Castle).metadata(),
+            Location::Castle.metadata()
+        );
+        assert_eq!(Item::Coin.metadata().unicode_version, UnicodeVersion::V13);
+        assert_eq!(Item::Coin.metadata().category, Category::Item);
+        assert_eq!(
+            Creature::Donkey.metadata().unicode_version,
+            UnicodeVersion::V15
+        );
+        assert_eq!(
+            Creature::Parrot.metadata().unicode_version,
+            UnicodeVersion::V11
+        );
+        assert_eq!(
+            Flora::FlowerWilted.metadata().unicode_version,
+            UnicodeVersion::V9
+        );
+        assert_eq!(Flora::Herb.metadata().category, Category::Flora);
+        assert_eq!(BodyPart::Eye.metadata().glyph, "👁️");
+        assert_eq!(
+            BodyPart::Tooth.metadata().unicode_version,
+            UnicodeVersion::V11
+        );
+        assert_eq!(Symbol::Fire.metadata().glyph, "🔥");
+        assert_eq!(Symbol::Fire.metadata().category, Category::Symbol);
+    }
+}
@@ -0,0 +1,195 @@
+//! Minimaps, which downsample a larger [`Grid`] into an overview with markers and a legend.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::grid::Grid;
+//! use mythoji::minimap::Minimap;
+//! use mythoji::{Emoji, Gender, Location, Person, SkinTone};
+//!
+//! let mut world = Grid::new(4, 2);
+//! world.fill(Emoji::Location(Location::TreeEvergreen));
+//! for (x, y) in [(2, 0), (3, 0), (3, 1)] {
+//!     world.set(x, y, Emoji::Location(Location::Castle));
+//! }
+//!
+//! let hero = Emoji::Person(Person::Elf, SkinTone::Neutral, Gender::Female);
+//! let overview = Minimap::new(2).marker(0, 0, hero, "You").render(&world);
+//! assert_eq!(overview.map.to_string(), "🧝‍♀️🏰");
+//! assert_eq!(overview.legend.to_string(), "🧝‍♀️ You\n🏰 A castle");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{grid::Grid, Emoji};
+
+/// Renders an overview of a [`Grid`], where each cell summarizes a square block of the grid.
+///
+/// Each cell of the overview shows the most common emoji in its block (ties are broken by the
+/// first in row-major order), unless a marker is placed within the block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Minimap {
+    scale: usize,
+    markers: Vec<(usize, usize, Emoji, String)>,
+}
+
+impl Minimap {
+    /// Creates a minimap where each cell summarizes a `scale`×`scale` block of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is `0`.
+    pub fn new(scale: usize) -> Self {
+        assert!(scale > 0, "scale must be at least 1");
+        Self {
+            scale,
+            markers: Vec::new(),
+        }
+    }
+
+    /// Places a marker, e.g. the player or a point of interest, at `(x, y)` of the grid.
+    ///
+    /// Markers take precedence over the cells of the grid, and over markers placed before them.
+    /// They are listed first in the legend, with the given label.
+    pub fn marker(mut self, x: usize, y: usize, emoji: Emoji, label: impl Into<String>) -> Self {
+        self.markers.push((x, y, emoji, label.into()));
+        self
+    }
+
+    /// Renders an overview of the given grid, and a legend of the emojis it uses.
+    pub fn render(&self, grid: &Grid<Emoji>) -> Overview {
+        let mut map = Grid::new(
+            grid.width().div_ceil(self.scale),
+            grid.height().div_ceil(self.scale),
+        );
+        let mut counts: Vec<(Emoji, usize)> = Vec::new();
+        for y in 0..map.height() {
+            for x in 0..map.width() {
+                counts.clear();
+                for gy in y * self.scale..((y + 1) * self.scale).min(grid.height()) {
+                    for gx in x * self.scale..((x + 1) * self.scale).min(grid.width()) {
+                        if let Some(&emoji) = grid.get(gx, gy) {
+                            match counts.iter_mut().find(|(e, _)| *e == emoji) {
+                                Some((_, count)) => *count += 1,
+                                None => counts.push((emoji, 1)),
+                            }
+                        }
+                    }
+                }
+                // Iterate in reverse, as max_by_key returns the last of equal elements.
+                if let Some(&(emoji, _)) = counts.iter().rev().max_by_key(|(_, count)| *count) {
+                    map.set(x, y, emoji);
+                }
+            }
+        }
+
+        let mut legend = Legend::default();
+        let cell = |(x, y, _, _): &(usize, usize, Emoji, String)| (x / self.scale, y / self.scale);
+        for (i, marker) in self.markers.iter().enumerate() {
+            let (x, y) = cell(marker);
+            let hidden = self.markers[i + 1..].iter().any(|m| cell(m) == (x, y));
+            if map.contains(x, y) && !hidden {
+                map.set(x, y, marker.2);
+                legend.push(marker.2, marker.3.clone());
+            }
+        }
+        for (_, _, emoji) in map.iter() {
+            legend.push(*emoji, emoji.metadata().description.to_string());
+        }
+        Overview { map, legend }
+    }
+}
+
+/// An overview rendered by a [`Minimap`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Overview {
+    /// The downsampled grid, including markers.
+    pub map: Grid<Emoji>,
+
+    /// The emojis used in the map, and what they represent.
+    pub legend: Legend,
+}
+
+impl Display for Overview {
+    /// Displays the map, followed by an empty line, followed by the legend.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}\n\n{}", self.map, self.legend)
+    }
+}
+
+/// A list of emojis, and what they represent.
+///
+/// Each emoji is listed once, with the first label it was given.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Legend {
+    entries: Vec<(Emoji, String)>,
+}
+
+impl Legend {
+    /// Returns the entries of the legend, in order.
+    pub fn entries(&self) -> &[(Emoji, String)] {
+        &self.entries
+    }
+
+    fn push(&mut self, emoji: Emoji, label: String) {
+        if !self.entries.iter().any(|(e, _)| *e == emoji) {
+            self.entries.push((emoji, label));
+        }
+    }
+}
+
+impl Display for Legend {
+    /// Displays each entry on its own line, e.g. "🏰 A castle".
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, (emoji, label)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{} {}", emoji, label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Item, Location};
+
+    #[test]
+    fn test_minimap_downsample() {
+        let tree = Emoji::Location(Location::TreeDeciduous);
+        let mountain = Emoji::Location(Location::Mountain);
+        let mut grid = Grid::new(5, 3);
+        grid.fill(tree);
+        for x in 2..5 {
+            grid.set(x, 0, mountain);
+            grid.set(x, 1, mountain);
+        }
+        grid.remove(4, 2);
+
+        let overview = Minimap::new(2).render(&grid);
+        assert_eq!(overview.map.to_string(), "🌳⛰⛰\n🌳🌳  ");
+        assert_eq!(
+            overview.legend.entries(),
+            [
+                (tree, "A deciduous tree".to_string()),
+                (mountain, "A mountain".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_minimap_markers() {
+        let mut grid = Grid::new(4, 4);
+        grid.fill(Emoji::Location(Location::Cave));
+
+        let overview = Minimap::new(2)
+            .marker(3, 3, Emoji::Item(Item::Key), "Hidden by the boss")
+            .marker(2, 2, Emoji::Creature(Creature::Dragon), "Boss")
+            .marker(9, 9, Emoji::Item(Item::Coin), "Out of bounds")
+            .render(&grid);
+        assert_eq!(overview.map.to_string(), "🕳🕳\n🕳🐉");
+        assert_eq!(overview.to_string(), "🕳🕳\n🕳🐉\n\n🐉 Boss\n🕳 A cave");
+    }
+}
@@ -0,0 +1,198 @@
+//! Verifies that every emitted sequence is NFC-normalized, and in canonical component order.
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Qualification, SkinTone};
+
+use crate::components::{SKIN_TONE_DARK, SKIN_TONE_LIGHT, VS15, VS16, ZWJ};
+
+/// An emitted sequence that is not normalized, returned by [`verify_normalization`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizationError {
+    sequence: String,
+    reason: &'static str,
+}
+
+impl NormalizationError {
+    /// Returns the sequence that is not normalized.
+    pub fn sequence(&self) -> &str {
+        &self.sequence
+    }
+}
+
+impl Display for NormalizationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?} is not normalized: {}", self.sequence, self.reason)
+    }
+}
+
+impl std::error::Error for NormalizationError {}
+
+/// Verifies that every sequence this crate emits is normalized, or returns the first that is not.
+///
+/// Every emitted sequence (every emoji, in every [`Qualification`], and every symbol and skin tone
+/// swatch) is guaranteed to be:
+///
+/// - NFC-normalized, i.e. unchanged by [Unicode Normalization Form C][nfc], so sequences can be
+///   compared and stored by their bytes, even after passing through systems that normalize text;
+/// - in canonical component order, as defined by [Unicode Technical Standard #51][uts51]: a skin
+///   tone modifier directly follows its base, a variation selector directly follows the character
+///   it qualifies, and zero-width joiners only join two components.
+///
+/// This is tested by the crate itself, but can also be asserted by downstream crates, e.g. in their
+/// own CI, against the version of the crate they compile.
+///
+/// [nfc]: https://unicode.org/reports/tr15/
+/// [uts51]: https://unicode.org/reports/tr51/#Emoji_Sequences
+///
+/// ```
+/// assert_eq!(mythoji::verify_normalization(), Ok(()));
+/// ```
+pub fn verify_normalization() -> std::result::Result<(), NormalizationError> {
+    for sequence in sequences() {
+        if let Err(reason) = check(&sequence) {
+            return Err(NormalizationError { sequence, reason });
+        }
+    }
+    Ok(())
+}
+
+/// Returns every sequence this crate emits.
+pub(crate) fn sequences() -> impl Iterator<Item = String> {
+    let emojis = crate::glyph::all().flat_map(|emoji| {
+        [Qualification::Full, Qualification::Minimal]
+            .map(|qualification| emoji.qualified(qualification).to_string())
+    });
+    emojis.chain(SkinTone::ALL.iter().map(|skin| skin.swatch().to_string()))
+}
+
+/// Checks a single sequence, returning why it is not normalized, if it is not.
+fn check(sequence: &str) -> std::result::Result<(), &'static str> {
+    if sequence.is_empty() {
+        return Err("the sequence is empty");
+    }
+    let mut previous: Option<char> = None;
+    for c in sequence.chars() {
+        if !is_nfc_stable(c) {
+            return Err("contains a character that may change under NFC");
+        }
+        let follows_base = previous.is_some_and(|p| !is_modifier(p) && p != ZWJ);
+        if is_skin_tone(c) && !follows_base {
+            return Err("a skin tone modifier does not directly follow its base");
+        }
+        if is_variation_selector(c) && !follows_base {
+            return Err("a variation selector does not directly follow a character");
+        }
+        if c == ZWJ && previous.is_none_or(|p| p == ZWJ) {
+            return Err("a zero-width joiner does not follow a component");
+        }
+        previous = Some(c);
+    }
+    if previous == Some(ZWJ) {
+        return Err("a zero-width joiner ends the sequence");
+    }
+    Ok(())
+}
+
+fn is_skin_tone(c: char) -> bool {
+    (SKIN_TONE_LIGHT..=SKIN_TONE_DARK).contains(&c)
+}
+
+fn is_variation_selector(c: char) -> bool {
+    c == VS15 || c == VS16
+}
+
+fn is_modifier(c: char) -> bool {
+    is_skin_tone(c) || is_variation_selector(c)
+}
+
+/// Returns whether `c` is unchanged by NFC in any context, i.e. it has no canonical decomposition,
+/// has a canonical combining class of `0`, and never composes with a preceding character.
+///
+/// This is only exhaustive for the blocks that emojis are drawn from; any other character is
+/// conservatively treated as unstable.
+fn is_nfc_stable(c: char) -> bool {
+    match c {
+        // Keycap bases, and the copyright and registered signs.
+        '#' | '*' | '0'..='9' | '\u{a9}' | '\u{ae}' => true,
+        // Joiners, variation selectors, and tags.
+        ZWJ | VS15 | VS16 | '\u{e0020}'..='\u{e007f}' => true,
+        // Combining marks for symbols.
+        '\u{20d0}'..='\u{20ff}' => false,
+        // Characters with canonical decompositions in the symbol blocks.
+        '\u{2000}'..='\u{2001}'
+        | '\u{2126}'
+        | '\u{212a}'..='\u{212b}'
+        | '\u{219a}'..='\u{219b}'
+        | '\u{21ae}'
+        | '\u{21cd}'..='\u{21cf}'
+        | '\u{2204}'
+        | '\u{2209}'
+        | '\u{220c}'
+        | '\u{2224}'
+        | '\u{2226}'
+        | '\u{2241}'
+        | '\u{2244}'
+        | '\u{2247}'
+        | '\u{2249}'
+        | '\u{2260}'
+        | '\u{2262}'
+        | '\u{226d}'..='\u{2271}'
+        | '\u{2274}'..='\u{2275}'
+        | '\u{2278}'..='\u{2279}'
+        | '\u{2280}'..='\u{2281}'
+        | '\u{2284}'..='\u{2285}'
+        | '\u{2288}'..='\u{2289}'
+        | '\u{22ac}'..='\u{22af}'
+        | '\u{22e0}'..='\u{22e3}'
+        | '\u{22ea}'..='\u{22ed}'
+        | '\u{2329}'..='\u{232a}'
+        | '\u{2adc}' => false,
+        // Symbol blocks, CJK symbols used as emojis, and the supplementary emoji blocks.
+        '\u{2000}'..='\u{2bff}'
+        | '\u{3030}'
+        | '\u{303d}'
+        | '\u{3297}'
+        | '\u{3299}'
+        | '\u{1f000}'..='\u{1faff}' => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_normalization::UnicodeNormalization;
+
+    #[test]
+    fn test_verify_normalization() {
+        assert_eq!(verify_normalization(), Ok(()));
+        for sequence in sequences() {
+            assert_eq!(sequence.nfc().collect::<String>(), sequence);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("🧝🏿\u{200d}♀\u{fe0f}"), Ok(()));
+        assert!(check("").is_err());
+        assert!(check("🏿🧝").is_err());
+        assert!(check("🧝\u{200d}🏿").is_err());
+        assert!(check("🧝🏿\u{fe0f}").is_err());
+        assert!(check("\u{200d}🧝").is_err());
+        assert!(check("🧝\u{200d}\u{200d}♀").is_err());
+        assert!(check("🧝\u{200d}").is_err());
+        assert!(check("\u{2126}").is_err());
+        assert!(check("e\u{301}").is_err());
+
+        let error = NormalizationError {
+            sequence: "🏿🧝".to_string(),
+            reason: "a skin tone modifier does not directly follow its base",
+        };
+        assert_eq!(
+            error.to_string(),
+            "\"🏿🧝\" is not normalized: a skin tone modifier does not directly follow its base"
+        );
+        assert_eq!(error.sequence(), "🏿🧝");
+    }
+}
@@ -0,0 +1,356 @@
+//! Emoji packs, which extend the catalog at runtime with definitions loaded from TOML or JSON,
+//! enabled by the `pack` feature.
+//!
+//! Custom emojis can't be [`Emoji`] values, so an [`EmojiPack`] looks up, searches, and renders
+//! both built-in and custom emojis as a [`PackEmoji`].
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::fallback::Fallback;
+//! use mythoji::pack::{EmojiPack, PackEmoji};
+//! use mythoji::render::Renderer;
+//! use mythoji::{Category, Creature, Emoji, EmojiQuery};
+//!
+//! let pack = EmojiPack::from_toml(
+//!     r#"
+//!     [[emoji]]
+//!     name = "Phoenix"
+//!     glyph = "🐦‍🔥"
+//!     category = "creature"
+//!     keywords = ["fire", "rebirth"]
+//!     fallback = "Ph"
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! let phoenix = pack.get("phoenix").unwrap();
+//! assert_eq!(phoenix.to_string(), "🐦‍🔥");
+//! assert_eq!(pack.get("dragon"), Some(PackEmoji::Builtin(Emoji::Creature(Creature::Dragon))));
+//!
+//! let fiery = pack.query(&EmojiQuery::new().category(Category::Creature).keyword("fire"));
+//! assert_eq!(fiery, [PackEmoji::Builtin(Emoji::Creature(Creature::Dragon)), phoenix]);
+//!
+//! assert_eq!(phoenix.render(&Renderer::text(Fallback::Ascii)), "Ph");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::fallback::FallbackProvider;
+use crate::metadata::{from_name, kebab_case};
+use crate::render::Renderer;
+use crate::{Category, Emoji, EmojiQuery};
+
+/// An emoji defined by an [`EmojiPack`], rather than by this crate.
+///
+/// In TOML or JSON, a definition has a `name` (e.g. `"Phoenix"`), a fully-qualified `glyph`, a
+/// `category` in `kebab-case` (e.g. `"creature"`), and optionally `keywords` and a plain text
+/// `fallback`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomEmoji {
+    name: String,
+    glyph: String,
+    category: Category,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    keywords: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fallback: Option<String>,
+}
+
+impl CustomEmoji {
+    /// Creates an emoji with the given `PascalCase` name, glyph, and category.
+    pub fn new(name: impl Into<String>, glyph: impl Into<String>, category: Category) -> Self {
+        Self {
+            name: name.into(),
+            glyph: glyph.into(),
+            category,
+            keywords: Vec::new(),
+            fallback: None,
+        }
+    }
+
+    /// Adds a keyword, e.g. for an [`EmojiQuery`].
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keywords.push(keyword.into());
+        self
+    }
+
+    /// Renders the emoji as the given plain text, when emojis are not supported.
+    pub fn fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Returns the name, e.g. `"Phoenix"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the glyph, e.g. "🐦‍🔥".
+    pub fn glyph(&self) -> &str {
+        &self.glyph
+    }
+
+    /// Returns the category.
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Returns the keywords.
+    pub fn keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the plain text fallback, which is the first letter of the name unless configured
+    /// otherwise, as in [`Fallback::Ascii`](crate::fallback::Fallback::Ascii).
+    pub fn fallback_text(&self) -> &str {
+        match &self.fallback {
+            Some(fallback) => fallback,
+            None => self
+                .name
+                .chars()
+                .next()
+                .map_or("", |c| &self.name[..c.len_utf8()]),
+        }
+    }
+}
+
+/// Either a built-in or a custom emoji, as returned by an [`EmojiPack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackEmoji<'a> {
+    /// An emoji defined by this crate.
+    Builtin(Emoji),
+
+    /// An emoji defined by an [`EmojiPack`].
+    Custom(&'a CustomEmoji),
+}
+
+impl PackEmoji<'_> {
+    /// Renders the emoji with the given renderer.
+    ///
+    /// Custom emojis are assumed to be supported, unless the renderer only renders plain text, in
+    /// which case they are rendered by [`FallbackProvider::fallback_custom`], e.g. as `"[phoenix]"`
+    /// in the [`Fallback::Name`](crate::fallback::Fallback::Name) style, and as their
+    /// [fallback text](CustomEmoji::fallback_text) in every other style.
+    pub fn render<F: FallbackProvider>(&self, renderer: &Renderer<F>) -> Cow<'_, str> {
+        match self {
+            Self::Builtin(emoji) => renderer.render(*emoji),
+            Self::Custom(emoji) => {
+                renderer.render_custom(emoji.name(), emoji.glyph(), emoji.fallback_text())
+            }
+        }
+    }
+}
+
+impl Display for PackEmoji<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Builtin(emoji) => write!(f, "{}", emoji),
+            Self::Custom(emoji) => f.write_str(emoji.glyph()),
+        }
+    }
+}
+
+/// An error returned when loading or merging an [`EmojiPack`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackError {
+    /// The definitions are not valid TOML or JSON, or a definition is missing a required field.
+    Syntax(String),
+
+    /// A custom emoji has the same name as a built-in emoji, or another custom emoji.
+    DuplicateName(String),
+}
+
+impl Display for PackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(message) => write!(f, "invalid emoji pack: {}", message),
+            Self::DuplicateName(name) => write!(f, "emoji {:?} is already defined", name),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
+
+/// A collection of custom emojis, which extends the built-in catalog.
+///
+/// In TOML, each emoji is an `[[emoji]]` table, and in JSON, each emoji is an element of an
+/// `"emoji"` array. Deserializing a pack fails if a name is already defined, as by
+/// [`EmojiPack::emoji`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedPack")]
+pub struct EmojiPack {
+    #[serde(rename = "emoji")]
+    emojis: Vec<CustomEmoji>,
+}
+
+/// The definitions of an [`EmojiPack`], before checking that every name is unique.
+#[derive(Deserialize)]
+struct UncheckedPack {
+    #[serde(default, rename = "emoji")]
+    emojis: Vec<CustomEmoji>,
+}
+
+impl TryFrom<UncheckedPack> for EmojiPack {
+    type Error = PackError;
+
+    fn try_from(value: UncheckedPack) -> Result<Self, Self::Error> {
+        value.emojis.into_iter().try_fold(Self::new(), Self::emoji)
+    }
+}
+
+impl EmojiPack {
+    /// Creates a pack without emojis.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a pack from TOML.
+    pub fn from_toml(toml: &str) -> Result<Self, PackError> {
+        let pack: UncheckedPack =
+            toml::from_str(toml).map_err(|e| PackError::Syntax(e.to_string()))?;
+        pack.try_into()
+    }
+
+    /// Loads a pack from JSON.
+    pub fn from_json(json: &str) -> Result<Self, PackError> {
+        let pack: UncheckedPack =
+            serde_json::from_str(json).map_err(|e| PackError::Syntax(e.to_string()))?;
+        pack.try_into()
+    }
+
+    /// Adds a custom emoji, failing if its name is already defined.
+    pub fn emoji(mut self, emoji: CustomEmoji) -> Result<Self, PackError> {
+        let name = kebab_case(&emoji.name);
+        if from_name(&name).is_some() || self.custom(&name).is_some() {
+            return Err(PackError::DuplicateName(emoji.name));
+        }
+        self.emojis.push(emoji);
+        Ok(self)
+    }
+
+    /// Adds every custom emoji of the given pack, e.g. to combine several mods.
+    pub fn merge(self, other: EmojiPack) -> Result<Self, PackError> {
+        other.emojis.into_iter().try_fold(self, Self::emoji)
+    }
+
+    /// Returns the custom emojis, in the order they were added.
+    pub fn emojis(&self) -> &[CustomEmoji] {
+        &self.emojis
+    }
+
+    /// Returns the built-in or custom emoji with the given name, e.g. `"phoenix"`.
+    ///
+    /// Built-in emojis are found by `kebab-case` name, alias, or synonym, as in
+    /// [`Emoji::synonyms`], and custom emojis by name in either `PascalCase` or `kebab-case`.
+    pub fn get(&self, name: &str) -> Option<PackEmoji<'_>> {
+        let name = kebab_case(name);
+        from_name(&name)
+            .map(PackEmoji::Builtin)
+            .or_else(|| self.custom(&name).map(PackEmoji::Custom))
+    }
+
+    /// Returns every built-in and custom emoji that matches the given query.
+    ///
+    /// Built-in emojis are returned first, as by [`EmojiQuery::run`], followed by custom emojis.
+    /// Custom emojis have no Unicode version, so they are matched regardless of it.
+    pub fn query(&self, query: &EmojiQuery) -> Vec<PackEmoji<'_>> {
+        let custom = self
+            .emojis
+            .iter()
+            .filter(|emoji| query.matches_custom(emoji));
+        query
+            .run()
+            .into_iter()
+            .map(PackEmoji::Builtin)
+            .chain(custom.map(PackEmoji::Custom))
+            .collect()
+    }
+
+    fn custom(&self, name: &str) -> Option<&CustomEmoji> {
+        self.emojis
+            .iter()
+            .find(|emoji| kebab_case(&emoji.name) == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fallback::Fallback;
+    use crate::Creature;
+
+    #[test]
+    fn test_emoji_pack() {
+        let json = r#"{"emoji": [{"name": "Unicorn", "glyph": "🦄", "category": "creature"}]}"#;
+        let pack = EmojiPack::from_json(json).unwrap();
+        let unicorn = &pack.emojis()[0];
+        assert_eq!(unicorn.fallback_text(), "U");
+        assert_eq!(pack.get("Unicorn"), Some(PackEmoji::Custom(unicorn)));
+        assert_eq!(pack.get("unicorn"), Some(PackEmoji::Custom(unicorn)));
+        assert_eq!(pack.get("pegasus"), None);
+
+        let unicorn = PackEmoji::Custom(unicorn);
+        assert_eq!(unicorn.render(&Renderer::new()), "🦄");
+        assert_eq!(unicorn.render(&Renderer::text(Fallback::Ascii)), "U");
+        assert_eq!(unicorn.render(&Renderer::text(Fallback::AsciiArt)), "U");
+        assert_eq!(unicorn.render(&Renderer::text(Fallback::Name)), "[unicorn]");
+        assert_eq!(unicorn.render(&Renderer::text(Fallback::TextSymbol)), "U");
+
+        let pack = pack
+            .emoji(CustomEmoji::new("Kraken", "🦑", Category::Creature).keyword("Sea"))
+            .unwrap();
+        let sea = pack.query(
+            &EmojiQuery::new()
+                .keyword("sea")
+                .category(Category::Creature),
+        );
+        assert_eq!(sea.last().unwrap().to_string(), "🦑");
+        assert!(sea.contains(&PackEmoji::Builtin(Emoji::Creature(Creature::Shark))));
+
+        let dragon = CustomEmoji::new("Dragon", "🐲", Category::Creature);
+        assert_eq!(
+            EmojiPack::new().emoji(dragon),
+            Err(PackError::DuplicateName("Dragon".to_string()))
+        );
+        assert_eq!(
+            pack.clone().merge(pack),
+            Err(PackError::DuplicateName("Unicorn".to_string()))
+        );
+        assert!(matches!(
+            EmojiPack::from_toml("[[emoji]]\nname = \"Unicorn\""),
+            Err(PackError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_fallback_text() {
+        let emoji = CustomEmoji::new("Ëlf", "🧝", Category::Person);
+        assert_eq!(emoji.fallback_text(), "Ë");
+        let emoji = CustomEmoji::new("龍", "🐉", Category::Creature);
+        assert_eq!(emoji.fallback_text(), "龍");
+        assert_eq!(emoji.fallback("D").fallback_text(), "D");
+    }
+
+    #[test]
+    fn test_deserialize_checks_names() {
+        let unicorn = r#"{"name": "Unicorn", "glyph": "🦄", "category": "creature"}"#;
+        let json = format!(r#"{{"emoji": [{}, {}]}}"#, unicorn, unicorn);
+        assert_eq!(
+            EmojiPack::from_json(&json),
+            Err(PackError::DuplicateName("Unicorn".to_string()))
+        );
+        let error = serde_json::from_str::<EmojiPack>(&json).unwrap_err();
+        assert!(error.to_string().contains("already defined"), "{}", error);
+
+        let dragon = r#"{"emoji": [{"name": "Dragon", "glyph": "🐲", "category": "creature"}]}"#;
+        assert!(serde_json::from_str::<EmojiPack>(dragon).is_err());
+
+        let pack = EmojiPack::from_json(&format!(r#"{{"emoji": [{}]}}"#, unicorn)).unwrap();
+        let saved = serde_json::to_string(&pack).unwrap();
+        assert_eq!(serde_json::from_str::<EmojiPack>(&saved).unwrap(), pack);
+    }
+}
@@ -0,0 +1,266 @@
+//! Parsing emojis by the names of their variants, e.g. in game configuration files.
+
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+
+use crate::metadata::{from_name, kebab_case};
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+/// The maximum number of close matches listed by a [`ParseNameError`].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// An error returned when parsing a name that is not the name of any variant.
+///
+/// ```
+/// use mythoji::Creature;
+///
+/// let error = "Dargon".parse::<Creature>().unwrap_err();
+/// assert_eq!(error.name(), "Dargon");
+/// assert_eq!(error.suggestions(), ["Dragon"]);
+/// assert_eq!(error.to_string(), r#"unknown name "Dargon", did you mean "Dragon"?"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseNameError {
+    name: String,
+    suggestions: Vec<String>,
+}
+
+impl ParseNameError {
+    /// Returns the name that failed to parse.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the names of the closest variants, closest first, which may be empty.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+impl Display for ParseNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "unknown name {:?}", self.name)?;
+        for (i, suggestion) in self.suggestions.iter().enumerate() {
+            let separator = match i {
+                0 => ", did you mean ",
+                _ if i + 1 == self.suggestions.len() => ", or ",
+                _ => ", ",
+            };
+            write!(f, "{}{:?}", separator, suggestion)?;
+        }
+        if !self.suggestions.is_empty() {
+            f.write_str("?")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseNameError {}
+
+/// Returns the resolved variant, or an error that suggests the closest of the given names.
+fn parse<T>(
+    name: &str,
+    resolved: Option<T>,
+    names: impl Iterator<Item = String>,
+) -> std::result::Result<T, ParseNameError> {
+    if let Some(variant) = resolved {
+        return Ok(variant);
+    }
+    let kebab = kebab_case(name);
+    let mut candidates: Vec<_> = names
+        .map(|name| (distance(&kebab, &kebab_case(&name)), name))
+        .filter(|(distance, _)| *distance <= (kebab.len() / 3).max(2))
+        .collect();
+    candidates.sort_by_key(|(distance, _)| *distance);
+    Err(ParseNameError {
+        name: name.to_string(),
+        suggestions: candidates
+            .into_iter()
+            .map(|(_, name)| name)
+            .take(MAX_SUGGESTIONS)
+            .collect(),
+    })
+}
+
+/// Returns the Levenshtein distance between two strings, in characters.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+macro_rules! impl_try_from_str {
+    ($($ty:ty),*) => {$(
+        impl TryFrom<&str> for $ty {
+            type Error = ParseNameError;
+
+            fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+    )*};
+}
+
+impl_try_from_str!(
+    Person, SkinTone, Gender, Creature, Location, Item, Flora, BodyPart, Symbol, Emoji
+);
+
+macro_rules! impl_from_str {
+    ($($ty:ty => $as:expr),*) => {$(
+        /// Parses the `PascalCase` name of a variant (e.g. `"SwordsCrossed"`), or its `kebab-case`
+        /// form (e.g. `"swords-crossed"`), including former names and synonyms (e.g. `"wyvern"`),
+        /// as [`Emoji`] does.
+        impl FromStr for $ty {
+            type Err = ParseNameError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                let resolved = from_name(&kebab_case(s)).and_then($as);
+                parse(s, resolved, Self::ALL.iter().map(|v| format!("{:?}", v)))
+            }
+        }
+    )*};
+}
+
+impl_from_str!(
+    Person => |emoji| match emoji {
+        Emoji::Person(person, ..) => Some(person),
+        _ => None,
+    },
+    Creature => |emoji: Emoji| emoji.as_creature(),
+    Location => |emoji: Emoji| emoji.as_location(),
+    Item => |emoji: Emoji| emoji.as_item(),
+    Flora => |emoji: Emoji| emoji.as_flora(),
+    BodyPart => |emoji: Emoji| emoji.as_body_part(),
+    Symbol => |emoji: Emoji| emoji.as_symbol()
+);
+
+macro_rules! impl_from_str_modifier {
+    ($($ty:ty),*) => {$(
+        /// Parses the `PascalCase` name of a variant (e.g. `"MediumDark"`), or its `kebab-case`
+        /// form (e.g. `"medium-dark"`).
+        impl FromStr for $ty {
+            type Err = ParseNameError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                let name = kebab_case(s);
+                let resolved = Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|v| kebab_case(&format!("{:?}", v)) == name);
+                parse(s, resolved, Self::ALL.iter().map(|v| format!("{:?}", v)))
+            }
+        }
+    )*};
+}
+
+impl_from_str_modifier!(SkinTone, Gender);
+
+/// Parses the `PascalCase` name of a variant of any category (e.g. `"Elf"` or `"SwordsCrossed"`),
+/// or its `kebab-case` form (e.g. `"swords-crossed"`), including former names (see
+/// [`Metadata::aliases`](crate::Metadata::aliases)) and genre synonyms (e.g. `"wyvern"`).
+///
+/// Persons are returned with a neutral skin tone and gender.
+///
+/// ```
+/// use mythoji::{Emoji, Item, Location, Person};
+///
+/// assert_eq!("Elf".parse(), Ok(Person::Elf.neutral()));
+/// assert_eq!("castle".parse(), Ok(Emoji::Location(Location::Castle)));
+/// assert_eq!(Emoji::try_from("SwordsCrossed"), Ok(Emoji::Item(Item::SwordsCrossed)));
+/// ```
+impl FromStr for Emoji {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse(
+            s,
+            from_name(&kebab_case(s)),
+            crate::glyph::bases().map(|emoji| emoji.metadata().name.to_string()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("elf", ""), 3);
+        assert_eq!(distance("dragon", "dargon"), 2);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_from_str() {
+        for emoji in crate::glyph::bases() {
+            let name = emoji.metadata().name;
+            assert_eq!(name.parse::<Emoji>().map(|e| e.metadata().name), Ok(name));
+            assert_eq!(kebab_case(name).parse::<Emoji>(), name.parse());
+        }
+        fn assert_parses<T: FromStr<Err = ParseNameError> + std::fmt::Debug + PartialEq>(
+            all: &[T],
+        ) {
+            for variant in all {
+                assert_eq!(format!("{:?}", variant).parse().as_ref(), Ok(variant));
+            }
+        }
+        assert_parses(Person::ALL);
+        assert_parses(Creature::ALL);
+        assert_parses(Location::ALL);
+        assert_parses(Item::ALL);
+        assert_parses(Flora::ALL);
+        assert_parses(BodyPart::ALL);
+        assert_parses(Symbol::ALL);
+        assert_eq!("medium-dark".parse(), Ok(SkinTone::MediumDark));
+        assert_eq!(Gender::try_from("Female"), Ok(Gender::Female));
+        assert_eq!("Fire".parse(), Ok(Symbol::Fire));
+        assert_eq!("Elf".parse(), Ok(Person::Elf));
+    }
+
+    #[test]
+    fn test_from_str_aliases_and_synonyms() {
+        assert_eq!("HeardScarfPerson".parse(), Ok(Person::HeadScarfPerson));
+        assert_eq!("heard-scarf-person".parse(), Ok(Person::HeadScarfPerson));
+        assert_eq!(
+            "HeardScarfPerson".parse(),
+            Ok(Person::HeadScarfPerson.neutral())
+        );
+
+        assert_eq!("wyvern".parse(), Ok(Creature::Dragon));
+        assert_eq!("Wyvern".parse(), Ok(Emoji::Creature(Creature::Dragon)));
+        assert_eq!("keep".parse(), Ok(Emoji::Location(Location::Castle)));
+
+        // Names of another category are not accepted.
+        assert!("keep".parse::<Creature>().is_err());
+    }
+
+    #[test]
+    fn test_parse_name_error() {
+        let error = "Castlr".parse::<Emoji>().unwrap_err();
+        assert_eq!(error.suggestions()[0], "Castle");
+
+        let error = "xyzzy".parse::<Item>().unwrap_err();
+        assert_eq!(error.suggestions(), [] as [String; 0]);
+        assert_eq!(error.to_string(), r#"unknown name "xyzzy""#);
+
+        let error = ParseNameError {
+            name: "a".to_string(),
+            suggestions: vec!["B".to_string(), "C".to_string(), "D".to_string()],
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"unknown name "a", did you mean "B", "C", or "D"?"#
+        );
+    }
+}
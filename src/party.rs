@@ -0,0 +1,100 @@
+//! Parties, which render a group of characters where some members may be unknown or hidden.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::party::Party;
+//! use mythoji::{Creature, Emoji, Person};
+//!
+//! // A stealthed enemy, and a party slot that has not been filled yet.
+//! let party = Party::new()
+//!     .member(Person::Elf.female())
+//!     .member(Emoji::Creature(Creature::Wolf))
+//!     .unknown()
+//!     .unknown();
+//! assert_eq!(party.to_string(), "🧝‍♀️🐺👤👤");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{Emoji, Qualification, Symbol};
+
+/// A group of characters, where unknown members are masked by a silhouette, e.g. "🧝‍♀️🐺👤".
+///
+/// Members are rendered in order, without spaces between them. Unknown members are rendered as
+/// [`Symbol::Silhouette`], e.g. for unidentified NPCs, stealthed enemies, or empty party slots.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Party {
+    members: Vec<Option<Emoji>>,
+    qualification: Qualification,
+}
+
+impl Party {
+    /// Creates a party without members.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a known member.
+    pub fn member(mut self, member: Emoji) -> Self {
+        self.members.push(Some(member));
+        self
+    }
+
+    /// Adds a member that is unknown or hidden, which is masked by a silhouette.
+    pub fn unknown(mut self) -> Self {
+        self.members.push(None);
+        self
+    }
+
+    /// Displays every known member with the given [`Qualification`].
+    pub fn qualification(mut self, qualification: Qualification) -> Self {
+        self.qualification = qualification;
+        self
+    }
+
+    /// Returns the members, where `None` is an unknown member.
+    pub fn members(&self) -> &[Option<Emoji>] {
+        &self.members
+    }
+}
+
+impl FromIterator<Option<Emoji>> for Party {
+    fn from_iter<T: IntoIterator<Item = Option<Emoji>>>(iter: T) -> Self {
+        Self {
+            members: iter.into_iter().collect(),
+            qualification: Qualification::Full,
+        }
+    }
+}
+
+impl Display for Party {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for member in &self.members {
+            match member {
+                Some(member) => write!(f, "{}", member.qualified(self.qualification))?,
+                None => write!(f, "{}", Symbol::Silhouette)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Person;
+
+    #[test]
+    fn test_party() {
+        let mage = Person::Mage.male();
+        let party: Party = [None, Some(mage), None].into_iter().collect();
+        assert_eq!(party.to_string(), "👤🧙‍♂️👤");
+        assert_eq!(party.members(), [None, Some(mage), None]);
+        assert_eq!(
+            party.qualification(Qualification::Minimal).to_string(),
+            "👤🧙‍♂👤"
+        );
+        assert_eq!(Party::new().to_string(), "");
+    }
+}
@@ -0,0 +1,72 @@
+//! A single import for small games, with the common types and semantic constants.
+//!
+//! The constants are the default emojis of each [`Role`], e.g. [`HERO`] for [`Role::Hero`]. A game
+//! that reskins them uses a [`Theme`] instead, e.g. `theme.hero()`.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::prelude::*;
+//!
+//! println!("{} {} {}", HERO, VS, VILLAIN);
+//! assert_eq!(format!("{}×3 {}", HP, GOLD), "❤️×3 🪙");
+//!
+//! let theme = Theme::default().with(Role::Hero, Person::Mage.female());
+//! assert_eq!(theme.hero().to_string(), "🧙‍♀️");
+//! assert_eq!(theme.camp(), CAMP);
+//! ```
+
+pub use crate::fallback::Fallback;
+pub use crate::grid::Grid;
+pub use crate::render::Renderer;
+pub use crate::theme::{Role, Theme};
+pub use crate::{
+    BodyPart, Category, Creature, Emoji, EmojiQuery, Flora, Gender, Item, Location, Person,
+    Qualification, SkinTone, Symbol,
+};
+
+/// The player character, e.g. "🧝"; see [`Role::Hero`].
+pub const HERO: Emoji = Role::Hero.default_emoji();
+
+/// The main antagonist, e.g. "🧛"; see [`Role::Villain`].
+pub const VILLAIN: Emoji = Role::Villain.default_emoji();
+
+/// A character that buys and sells items, e.g. "🧑"; see [`Role::Merchant`].
+pub const MERCHANT: Emoji = Role::Merchant.default_emoji();
+
+/// A character that restores health, e.g. "🧚"; see [`Role::Healer`].
+pub const HEALER: Emoji = Role::Healer.default_emoji();
+
+/// A powerful enemy, e.g. "🐉"; see [`Role::Boss`].
+pub const BOSS: Emoji = Role::Boss.default_emoji();
+
+/// Money, e.g. "🪙"; see [`Role::Currency`].
+pub const GOLD: Emoji = Role::Currency.default_emoji();
+
+/// A hazard or a warning, e.g. "💣"; see [`Role::Danger`].
+pub const DANGER: Emoji = Role::Danger.default_emoji();
+
+/// Health or hit points, e.g. "❤️"; see [`Role::Health`].
+pub const HP: Emoji = Role::Health.default_emoji();
+
+/// A place to rest, e.g. "🏕"; see [`Role::Camp`].
+pub const CAMP: Emoji = Role::Camp.default_emoji();
+
+/// A fight between two sides, e.g. "⚔️".
+pub const VS: Emoji = Emoji::Item(Item::SwordsCrossed);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prelude() {
+        let theme = Theme::default();
+        let roles: Vec<_> = Role::iter().map(|role| theme.get(role)).collect();
+        assert_eq!(
+            roles,
+            [HERO, VILLAIN, MERCHANT, HEALER, BOSS, GOLD, DANGER, HP, CAMP]
+        );
+        assert_eq!(VS.to_string(), "⚔️");
+    }
+}
@@ -0,0 +1,275 @@
+//! Protocol buffer definitions for every emoji, enabled by the `prost` feature.
+//!
+//! The types in this module are generated by `prost-build` from `proto/mythoji/v1/mythoji.proto`,
+//! which is published with the crate so that servers and clients in other languages can share the
+//! same catalog. Each protobuf enum value is the [stable identifier][crate::Creature::id] of the
+//! variant plus one, as `0` is reserved for the `*_UNSPECIFIED` value.
+//!
+//! Every enum in this crate converts into its protobuf counterpart with [`From`], and back with
+//! [`TryFrom`], which fails with [`UnknownValue`] for unspecified values, or values added by a
+//! newer version of the crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{proto, Creature, Emoji};
+//! use prost::Message;
+//!
+//! let message = proto::Emoji::from(Emoji::Creature(Creature::Dragon));
+//! let bytes = message.encode_to_vec();
+//!
+//! let decoded = proto::Emoji::decode(bytes.as_slice()).unwrap();
+//! assert_eq!(Emoji::try_from(decoded), Ok(Emoji::Creature(Creature::Dragon)));
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+include!("proto/mythoji.v1.rs");
+
+/// An error returned when converting an unspecified or unknown protobuf value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownValue;
+
+impl Display for UnknownValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("unspecified or unknown protobuf value")
+    }
+}
+
+impl std::error::Error for UnknownValue {}
+
+macro_rules! impl_proto {
+    ($($name:ident),*) => {$(
+        impl From<crate::$name> for $name {
+            fn from(value: crate::$name) -> Self {
+                Self::try_from(i32::from(value.id()) + 1).expect("every variant has a protobuf value")
+            }
+        }
+
+        impl TryFrom<$name> for crate::$name {
+            type Error = UnknownValue;
+
+            fn try_from(value: $name) -> std::result::Result<Self, Self::Error> {
+                u16::try_from(value as i32 - 1)
+                    .ok()
+                    .and_then(Self::from_id)
+                    .ok_or(UnknownValue)
+            }
+        }
+    )*};
+}
+
+impl_proto!(Person, SkinTone, Gender, Creature, Location, Item, Flora, BodyPart, Symbol);
+
+/// Converts a raw protobuf enum field into an enum of this crate.
+fn decode<P, T>(value: i32) -> std::result::Result<T, UnknownValue>
+where
+    P: TryFrom<i32>,
+    T: TryFrom<P, Error = UnknownValue>,
+{
+    P::try_from(value).map_err(|_| UnknownValue)?.try_into()
+}
+
+impl From<crate::Emoji> for Emoji {
+    fn from(value: crate::Emoji) -> Self {
+        let kind = match value {
+            crate::Emoji::Person(person, skin_tone, gender) => emoji::Kind::Person(PersonEmoji {
+                person: Person::from(person).into(),
+                skin_tone: SkinTone::from(skin_tone).into(),
+                gender: Gender::from(gender).into(),
+            }),
+            crate::Emoji::Creature(creature) => {
+                emoji::Kind::Creature(Creature::from(creature).into())
+            }
+            crate::Emoji::Location(location) => {
+                emoji::Kind::Location(Location::from(location).into())
+            }
+            crate::Emoji::Item(item) => emoji::Kind::Item(Item::from(item).into()),
+            crate::Emoji::Flora(flora) => emoji::Kind::Flora(Flora::from(flora).into()),
+            crate::Emoji::BodyPart(body_part) => {
+                emoji::Kind::BodyPart(BodyPart::from(body_part).into())
+            }
+            crate::Emoji::Symbol(symbol) => emoji::Kind::Symbol(Symbol::from(symbol).into()),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+/// Converts a protobuf emoji, treating an unspecified skin tone or gender as neutral.
+impl TryFrom<Emoji> for crate::Emoji {
+    type Error = UnknownValue;
+
+    fn try_from(value: Emoji) -> std::result::Result<Self, Self::Error> {
+        Ok(match value.kind.ok_or(UnknownValue)? {
+            emoji::Kind::Person(person) => crate::Emoji::Person(
+                decode::<Person, _>(person.person)?,
+                match person.skin_tone {
+                    0 => crate::SkinTone::Neutral,
+                    value => decode::<SkinTone, _>(value)?,
+                },
+                match person.gender {
+                    0 => crate::Gender::Neutral,
+                    value => decode::<Gender, _>(value)?,
+                },
+            ),
+            emoji::Kind::Creature(creature) => {
+                crate::Emoji::Creature(decode::<Creature, _>(creature)?)
+            }
+            emoji::Kind::Location(location) => {
+                crate::Emoji::Location(decode::<Location, _>(location)?)
+            }
+            emoji::Kind::Item(item) => crate::Emoji::Item(decode::<Item, _>(item)?),
+            emoji::Kind::Flora(flora) => crate::Emoji::Flora(decode::<Flora, _>(flora)?),
+            emoji::Kind::BodyPart(body_part) => {
+                crate::Emoji::BodyPart(decode::<BodyPart, _>(body_part)?)
+            }
+            emoji::Kind::Symbol(symbol) => crate::Emoji::Symbol(decode::<Symbol, _>(symbol)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    /// Returns the `SCREAMING_SNAKE_CASE` name of a protobuf value for the given variant.
+    fn proto_name(prefix: &str, variant: &str) -> String {
+        let mut name = prefix.to_string();
+        for c in variant.chars() {
+            if c.is_uppercase() {
+                name.push('_');
+            }
+            name.push(c.to_ascii_uppercase());
+        }
+        name
+    }
+
+    #[test]
+    fn test_enums_match_stable_ids() {
+        for &creature in crate::Creature::ALL {
+            let proto = Creature::from(creature);
+            assert_eq!(proto as i32, i32::from(creature.id()) + 1);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("CREATURE", &format!("{creature:?}"))
+            );
+            assert_eq!(crate::Creature::try_from(proto), Ok(creature));
+        }
+        for &person in crate::Person::ALL {
+            let proto = Person::from(person);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("PERSON", &format!("{person:?}"))
+            );
+            assert_eq!(crate::Person::try_from(proto), Ok(person));
+        }
+        for &skin_tone in crate::SkinTone::ALL {
+            let proto = SkinTone::from(skin_tone);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("SKIN_TONE", &format!("{skin_tone:?}"))
+            );
+            assert_eq!(crate::SkinTone::try_from(proto), Ok(skin_tone));
+        }
+        for &gender in crate::Gender::ALL {
+            let proto = Gender::from(gender);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("GENDER", &format!("{gender:?}"))
+            );
+            assert_eq!(crate::Gender::try_from(proto), Ok(gender));
+        }
+        for &location in crate::Location::ALL {
+            let proto = Location::from(location);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("LOCATION", &format!("{location:?}"))
+            );
+            assert_eq!(crate::Location::try_from(proto), Ok(location));
+        }
+        for &item in crate::Item::ALL {
+            let proto = Item::from(item);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("ITEM", &format!("{item:?}"))
+            );
+            assert_eq!(crate::Item::try_from(proto), Ok(item));
+        }
+        for &flora in crate::Flora::ALL {
+            let proto = Flora::from(flora);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("FLORA", &format!("{flora:?}"))
+            );
+            assert_eq!(crate::Flora::try_from(proto), Ok(flora));
+        }
+        for &body_part in crate::BodyPart::ALL {
+            let proto = BodyPart::from(body_part);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("BODY_PART", &format!("{body_part:?}"))
+            );
+            assert_eq!(crate::BodyPart::try_from(proto), Ok(body_part));
+        }
+        for &symbol in crate::Symbol::ALL {
+            let proto = Symbol::from(symbol);
+            assert_eq!(
+                proto.as_str_name(),
+                proto_name("SYMBOL", &format!("{symbol:?}"))
+            );
+            assert_eq!(crate::Symbol::try_from(proto), Ok(symbol));
+        }
+        assert_eq!(
+            crate::Creature::try_from(Creature::Unspecified),
+            Err(UnknownValue)
+        );
+    }
+
+    #[test]
+    fn test_emoji_round_trip() {
+        let emojis = [
+            crate::Emoji::Person(
+                crate::Person::Elf,
+                crate::SkinTone::Dark,
+                crate::Gender::Female,
+            ),
+            crate::Emoji::Creature(crate::Creature::Dragon),
+            crate::Emoji::Location(crate::Location::Castle),
+            crate::Emoji::Item(crate::Item::Coin),
+            crate::Emoji::Flora(crate::Flora::Mushroom),
+            crate::Emoji::BodyPart(crate::BodyPart::Tooth),
+            crate::Emoji::Symbol(crate::Symbol::Anger),
+            crate::Emoji::Symbol(crate::Symbol::Zzz),
+        ];
+        for emoji in emojis {
+            let bytes = Emoji::from(emoji).encode_to_vec();
+            let decoded = Emoji::decode(bytes.as_slice()).unwrap();
+            assert_eq!(crate::Emoji::try_from(decoded), Ok(emoji));
+        }
+    }
+
+    #[test]
+    fn test_emoji_invalid() {
+        assert_eq!(crate::Emoji::try_from(Emoji::default()), Err(UnknownValue));
+        assert_eq!(
+            crate::Emoji::try_from(Emoji {
+                kind: Some(emoji::Kind::Creature(1000)),
+            }),
+            Err(UnknownValue)
+        );
+        assert_eq!(
+            crate::Emoji::try_from(Emoji {
+                kind: Some(emoji::Kind::Person(PersonEmoji {
+                    person: Person::Mage.into(),
+                    ..Default::default()
+                })),
+            }),
+            Ok(crate::Emoji::Person(
+                crate::Person::Mage,
+                crate::SkinTone::Neutral,
+                crate::Gender::Neutral
+            ))
+        );
+    }
+}
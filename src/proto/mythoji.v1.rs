@@ -0,0 +1,922 @@
+// This file is @generated by prost-build.
+/// A person, with an optional skin tone and gender.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PersonEmoji {
+    #[prost(enumeration = "Person", tag = "1")]
+    pub person: i32,
+    #[prost(enumeration = "SkinTone", tag = "2")]
+    pub skin_tone: i32,
+    #[prost(enumeration = "Gender", tag = "3")]
+    pub gender: i32,
+}
+/// Any emoji in the catalog.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Emoji {
+    #[prost(oneof = "emoji::Kind", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub kind: ::core::option::Option<emoji::Kind>,
+}
+/// Nested message and enum types in `Emoji`.
+pub mod emoji {
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Person(super::PersonEmoji),
+        #[prost(enumeration = "super::Creature", tag = "2")]
+        Creature(i32),
+        #[prost(enumeration = "super::Location", tag = "3")]
+        Location(i32),
+        #[prost(enumeration = "super::Item", tag = "4")]
+        Item(i32),
+        #[prost(enumeration = "super::Flora", tag = "5")]
+        Flora(i32),
+        #[prost(enumeration = "super::BodyPart", tag = "6")]
+        BodyPart(i32),
+        #[prost(enumeration = "super::Symbol", tag = "7")]
+        Symbol(i32),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Person {
+    Unspecified = 0,
+    Artist = 1,
+    Baby = 2,
+    BaldPerson = 3,
+    BeardedPerson = 4,
+    Child = 5,
+    Fairy = 6,
+    Elf = 7,
+    Genie = 8,
+    HeadScarfPerson = 9,
+    Mage = 10,
+    MerPerson = 11,
+    OldPerson = 12,
+    Person = 13,
+    Royalty = 14,
+    SkullCapPerson = 15,
+    TurbanPerson = 16,
+    Vampire = 17,
+    Zombie = 18,
+}
+impl Person {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PERSON_UNSPECIFIED",
+            Self::Artist => "PERSON_ARTIST",
+            Self::Baby => "PERSON_BABY",
+            Self::BaldPerson => "PERSON_BALD_PERSON",
+            Self::BeardedPerson => "PERSON_BEARDED_PERSON",
+            Self::Child => "PERSON_CHILD",
+            Self::Fairy => "PERSON_FAIRY",
+            Self::Elf => "PERSON_ELF",
+            Self::Genie => "PERSON_GENIE",
+            Self::HeadScarfPerson => "PERSON_HEAD_SCARF_PERSON",
+            Self::Mage => "PERSON_MAGE",
+            Self::MerPerson => "PERSON_MER_PERSON",
+            Self::OldPerson => "PERSON_OLD_PERSON",
+            Self::Person => "PERSON_PERSON",
+            Self::Royalty => "PERSON_ROYALTY",
+            Self::SkullCapPerson => "PERSON_SKULL_CAP_PERSON",
+            Self::TurbanPerson => "PERSON_TURBAN_PERSON",
+            Self::Vampire => "PERSON_VAMPIRE",
+            Self::Zombie => "PERSON_ZOMBIE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PERSON_UNSPECIFIED" => Some(Self::Unspecified),
+            "PERSON_ARTIST" => Some(Self::Artist),
+            "PERSON_BABY" => Some(Self::Baby),
+            "PERSON_BALD_PERSON" => Some(Self::BaldPerson),
+            "PERSON_BEARDED_PERSON" => Some(Self::BeardedPerson),
+            "PERSON_CHILD" => Some(Self::Child),
+            "PERSON_FAIRY" => Some(Self::Fairy),
+            "PERSON_ELF" => Some(Self::Elf),
+            "PERSON_GENIE" => Some(Self::Genie),
+            "PERSON_HEAD_SCARF_PERSON" => Some(Self::HeadScarfPerson),
+            "PERSON_MAGE" => Some(Self::Mage),
+            "PERSON_MER_PERSON" => Some(Self::MerPerson),
+            "PERSON_OLD_PERSON" => Some(Self::OldPerson),
+            "PERSON_PERSON" => Some(Self::Person),
+            "PERSON_ROYALTY" => Some(Self::Royalty),
+            "PERSON_SKULL_CAP_PERSON" => Some(Self::SkullCapPerson),
+            "PERSON_TURBAN_PERSON" => Some(Self::TurbanPerson),
+            "PERSON_VAMPIRE" => Some(Self::Vampire),
+            "PERSON_ZOMBIE" => Some(Self::Zombie),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SkinTone {
+    Unspecified = 0,
+    Neutral = 1,
+    Light = 2,
+    MediumLight = 3,
+    Medium = 4,
+    MediumDark = 5,
+    Dark = 6,
+}
+impl SkinTone {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SKIN_TONE_UNSPECIFIED",
+            Self::Neutral => "SKIN_TONE_NEUTRAL",
+            Self::Light => "SKIN_TONE_LIGHT",
+            Self::MediumLight => "SKIN_TONE_MEDIUM_LIGHT",
+            Self::Medium => "SKIN_TONE_MEDIUM",
+            Self::MediumDark => "SKIN_TONE_MEDIUM_DARK",
+            Self::Dark => "SKIN_TONE_DARK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SKIN_TONE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SKIN_TONE_NEUTRAL" => Some(Self::Neutral),
+            "SKIN_TONE_LIGHT" => Some(Self::Light),
+            "SKIN_TONE_MEDIUM_LIGHT" => Some(Self::MediumLight),
+            "SKIN_TONE_MEDIUM" => Some(Self::Medium),
+            "SKIN_TONE_MEDIUM_DARK" => Some(Self::MediumDark),
+            "SKIN_TONE_DARK" => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Gender {
+    Unspecified = 0,
+    Neutral = 1,
+    Male = 2,
+    Female = 3,
+}
+impl Gender {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "GENDER_UNSPECIFIED",
+            Self::Neutral => "GENDER_NEUTRAL",
+            Self::Male => "GENDER_MALE",
+            Self::Female => "GENDER_FEMALE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GENDER_UNSPECIFIED" => Some(Self::Unspecified),
+            "GENDER_NEUTRAL" => Some(Self::Neutral),
+            "GENDER_MALE" => Some(Self::Male),
+            "GENDER_FEMALE" => Some(Self::Female),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Creature {
+    Unspecified = 0,
+    Ant = 1,
+    Bat = 2,
+    Beetle = 3,
+    Bison = 4,
+    Boar = 5,
+    Bug = 6,
+    Butterfly = 7,
+    Camel = 8,
+    Cat = 9,
+    Cockroach = 10,
+    Cow = 11,
+    Crab = 12,
+    Crocodile = 13,
+    Deer = 14,
+    Dog = 15,
+    Dragon = 16,
+    Eagle = 17,
+    Elephant = 18,
+    Fish = 19,
+    Ghost = 20,
+    Goat = 21,
+    Goblin = 22,
+    Honeybee = 23,
+    Horse = 24,
+    Leopard = 25,
+    Llama = 26,
+    Mammoth = 27,
+    Mouse = 28,
+    Ogre = 29,
+    Pig = 30,
+    Rabbit = 31,
+    Ram = 32,
+    Rat = 33,
+    Rhinoceros = 34,
+    Scorpion = 35,
+    Shark = 36,
+    Snake = 37,
+    Spider = 38,
+    Tiger = 39,
+    TropicalFish = 40,
+    WaterBuffalo = 41,
+    Wolf = 42,
+    BlackBird = 43,
+    Dove = 44,
+    Owl = 45,
+    Parrot = 46,
+    Peacock = 47,
+    Rooster = 48,
+    Swan = 49,
+    Blowfish = 50,
+    Dolphin = 51,
+    Jellyfish = 52,
+    Octopus = 53,
+    Seal = 54,
+    Squid = 55,
+    Turtle = 56,
+    Whale = 57,
+    Cricket = 58,
+    Fly = 59,
+    Frog = 60,
+    Lizard = 61,
+    Mosquito = 62,
+    Snail = 63,
+    Worm = 64,
+    Badger = 65,
+    Bear = 66,
+    Beaver = 67,
+    Fox = 68,
+    Gorilla = 69,
+    Hedgehog = 70,
+    Lion = 71,
+    Monkey = 72,
+    Otter = 73,
+    Raccoon = 74,
+    Donkey = 75,
+    Moose = 76,
+    Ox = 77,
+    Sheep = 78,
+    Turkey = 79,
+}
+impl Creature {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CREATURE_UNSPECIFIED",
+            Self::Ant => "CREATURE_ANT",
+            Self::Bat => "CREATURE_BAT",
+            Self::Beetle => "CREATURE_BEETLE",
+            Self::Bison => "CREATURE_BISON",
+            Self::Boar => "CREATURE_BOAR",
+            Self::Bug => "CREATURE_BUG",
+            Self::Butterfly => "CREATURE_BUTTERFLY",
+            Self::Camel => "CREATURE_CAMEL",
+            Self::Cat => "CREATURE_CAT",
+            Self::Cockroach => "CREATURE_COCKROACH",
+            Self::Cow => "CREATURE_COW",
+            Self::Crab => "CREATURE_CRAB",
+            Self::Crocodile => "CREATURE_CROCODILE",
+            Self::Deer => "CREATURE_DEER",
+            Self::Dog => "CREATURE_DOG",
+            Self::Dragon => "CREATURE_DRAGON",
+            Self::Eagle => "CREATURE_EAGLE",
+            Self::Elephant => "CREATURE_ELEPHANT",
+            Self::Fish => "CREATURE_FISH",
+            Self::Ghost => "CREATURE_GHOST",
+            Self::Goat => "CREATURE_GOAT",
+            Self::Goblin => "CREATURE_GOBLIN",
+            Self::Honeybee => "CREATURE_HONEYBEE",
+            Self::Horse => "CREATURE_HORSE",
+            Self::Leopard => "CREATURE_LEOPARD",
+            Self::Llama => "CREATURE_LLAMA",
+            Self::Mammoth => "CREATURE_MAMMOTH",
+            Self::Mouse => "CREATURE_MOUSE",
+            Self::Ogre => "CREATURE_OGRE",
+            Self::Pig => "CREATURE_PIG",
+            Self::Rabbit => "CREATURE_RABBIT",
+            Self::Ram => "CREATURE_RAM",
+            Self::Rat => "CREATURE_RAT",
+            Self::Rhinoceros => "CREATURE_RHINOCEROS",
+            Self::Scorpion => "CREATURE_SCORPION",
+            Self::Shark => "CREATURE_SHARK",
+            Self::Snake => "CREATURE_SNAKE",
+            Self::Spider => "CREATURE_SPIDER",
+            Self::Tiger => "CREATURE_TIGER",
+            Self::TropicalFish => "CREATURE_TROPICAL_FISH",
+            Self::WaterBuffalo => "CREATURE_WATER_BUFFALO",
+            Self::Wolf => "CREATURE_WOLF",
+            Self::BlackBird => "CREATURE_BLACK_BIRD",
+            Self::Dove => "CREATURE_DOVE",
+            Self::Owl => "CREATURE_OWL",
+            Self::Parrot => "CREATURE_PARROT",
+            Self::Peacock => "CREATURE_PEACOCK",
+            Self::Rooster => "CREATURE_ROOSTER",
+            Self::Swan => "CREATURE_SWAN",
+            Self::Blowfish => "CREATURE_BLOWFISH",
+            Self::Dolphin => "CREATURE_DOLPHIN",
+            Self::Jellyfish => "CREATURE_JELLYFISH",
+            Self::Octopus => "CREATURE_OCTOPUS",
+            Self::Seal => "CREATURE_SEAL",
+            Self::Squid => "CREATURE_SQUID",
+            Self::Turtle => "CREATURE_TURTLE",
+            Self::Whale => "CREATURE_WHALE",
+            Self::Cricket => "CREATURE_CRICKET",
+            Self::Fly => "CREATURE_FLY",
+            Self::Frog => "CREATURE_FROG",
+            Self::Lizard => "CREATURE_LIZARD",
+            Self::Mosquito => "CREATURE_MOSQUITO",
+            Self::Snail => "CREATURE_SNAIL",
+            Self::Worm => "CREATURE_WORM",
+            Self::Badger => "CREATURE_BADGER",
+            Self::Bear => "CREATURE_BEAR",
+            Self::Beaver => "CREATURE_BEAVER",
+            Self::Fox => "CREATURE_FOX",
+            Self::Gorilla => "CREATURE_GORILLA",
+            Self::Hedgehog => "CREATURE_HEDGEHOG",
+            Self::Lion => "CREATURE_LION",
+            Self::Monkey => "CREATURE_MONKEY",
+            Self::Otter => "CREATURE_OTTER",
+            Self::Raccoon => "CREATURE_RACCOON",
+            Self::Donkey => "CREATURE_DONKEY",
+            Self::Moose => "CREATURE_MOOSE",
+            Self::Ox => "CREATURE_OX",
+            Self::Sheep => "CREATURE_SHEEP",
+            Self::Turkey => "CREATURE_TURKEY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CREATURE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CREATURE_ANT" => Some(Self::Ant),
+            "CREATURE_BAT" => Some(Self::Bat),
+            "CREATURE_BEETLE" => Some(Self::Beetle),
+            "CREATURE_BISON" => Some(Self::Bison),
+            "CREATURE_BOAR" => Some(Self::Boar),
+            "CREATURE_BUG" => Some(Self::Bug),
+            "CREATURE_BUTTERFLY" => Some(Self::Butterfly),
+            "CREATURE_CAMEL" => Some(Self::Camel),
+            "CREATURE_CAT" => Some(Self::Cat),
+            "CREATURE_COCKROACH" => Some(Self::Cockroach),
+            "CREATURE_COW" => Some(Self::Cow),
+            "CREATURE_CRAB" => Some(Self::Crab),
+            "CREATURE_CROCODILE" => Some(Self::Crocodile),
+            "CREATURE_DEER" => Some(Self::Deer),
+            "CREATURE_DOG" => Some(Self::Dog),
+            "CREATURE_DRAGON" => Some(Self::Dragon),
+            "CREATURE_EAGLE" => Some(Self::Eagle),
+            "CREATURE_ELEPHANT" => Some(Self::Elephant),
+            "CREATURE_FISH" => Some(Self::Fish),
+            "CREATURE_GHOST" => Some(Self::Ghost),
+            "CREATURE_GOAT" => Some(Self::Goat),
+            "CREATURE_GOBLIN" => Some(Self::Goblin),
+            "CREATURE_HONEYBEE" => Some(Self::Honeybee),
+            "CREATURE_HORSE" => Some(Self::Horse),
+            "CREATURE_LEOPARD" => Some(Self::Leopard),
+            "CREATURE_LLAMA" => Some(Self::Llama),
+            "CREATURE_MAMMOTH" => Some(Self::Mammoth),
+            "CREATURE_MOUSE" => Some(Self::Mouse),
+            "CREATURE_OGRE" => Some(Self::Ogre),
+            "CREATURE_PIG" => Some(Self::Pig),
+            "CREATURE_RABBIT" => Some(Self::Rabbit),
+            "CREATURE_RAM" => Some(Self::Ram),
+            "CREATURE_RAT" => Some(Self::Rat),
+            "CREATURE_RHINOCEROS" => Some(Self::Rhinoceros),
+            "CREATURE_SCORPION" => Some(Self::Scorpion),
+            "CREATURE_SHARK" => Some(Self::Shark),
+            "CREATURE_SNAKE" => Some(Self::Snake),
+            "CREATURE_SPIDER" => Some(Self::Spider),
+            "CREATURE_TIGER" => Some(Self::Tiger),
+            "CREATURE_TROPICAL_FISH" => Some(Self::TropicalFish),
+            "CREATURE_WATER_BUFFALO" => Some(Self::WaterBuffalo),
+            "CREATURE_WOLF" => Some(Self::Wolf),
+            "CREATURE_BLACK_BIRD" => Some(Self::BlackBird),
+            "CREATURE_DOVE" => Some(Self::Dove),
+            "CREATURE_OWL" => Some(Self::Owl),
+            "CREATURE_PARROT" => Some(Self::Parrot),
+            "CREATURE_PEACOCK" => Some(Self::Peacock),
+            "CREATURE_ROOSTER" => Some(Self::Rooster),
+            "CREATURE_SWAN" => Some(Self::Swan),
+            "CREATURE_BLOWFISH" => Some(Self::Blowfish),
+            "CREATURE_DOLPHIN" => Some(Self::Dolphin),
+            "CREATURE_JELLYFISH" => Some(Self::Jellyfish),
+            "CREATURE_OCTOPUS" => Some(Self::Octopus),
+            "CREATURE_SEAL" => Some(Self::Seal),
+            "CREATURE_SQUID" => Some(Self::Squid),
+            "CREATURE_TURTLE" => Some(Self::Turtle),
+            "CREATURE_WHALE" => Some(Self::Whale),
+            "CREATURE_CRICKET" => Some(Self::Cricket),
+            "CREATURE_FLY" => Some(Self::Fly),
+            "CREATURE_FROG" => Some(Self::Frog),
+            "CREATURE_LIZARD" => Some(Self::Lizard),
+            "CREATURE_MOSQUITO" => Some(Self::Mosquito),
+            "CREATURE_SNAIL" => Some(Self::Snail),
+            "CREATURE_WORM" => Some(Self::Worm),
+            "CREATURE_BADGER" => Some(Self::Badger),
+            "CREATURE_BEAR" => Some(Self::Bear),
+            "CREATURE_BEAVER" => Some(Self::Beaver),
+            "CREATURE_FOX" => Some(Self::Fox),
+            "CREATURE_GORILLA" => Some(Self::Gorilla),
+            "CREATURE_HEDGEHOG" => Some(Self::Hedgehog),
+            "CREATURE_LION" => Some(Self::Lion),
+            "CREATURE_MONKEY" => Some(Self::Monkey),
+            "CREATURE_OTTER" => Some(Self::Otter),
+            "CREATURE_RACCOON" => Some(Self::Raccoon),
+            "CREATURE_DONKEY" => Some(Self::Donkey),
+            "CREATURE_MOOSE" => Some(Self::Moose),
+            "CREATURE_OX" => Some(Self::Ox),
+            "CREATURE_SHEEP" => Some(Self::Sheep),
+            "CREATURE_TURKEY" => Some(Self::Turkey),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Location {
+    Unspecified = 0,
+    BoatSail = 1,
+    BuildingClassic = 2,
+    Campsite = 3,
+    Canoe = 4,
+    Castle = 5,
+    CastleJapanese = 6,
+    Cave = 7,
+    Desert = 8,
+    Hut = 9,
+    Mountain = 10,
+    MountainSnow = 11,
+    Oasis = 12,
+    Palace = 13,
+    Tent = 14,
+    TreeDeciduous = 15,
+    TreeEvergreen = 16,
+    TreePalm = 17,
+    Volcano = 18,
+    TreeLeafless = 19,
+}
+impl Location {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "LOCATION_UNSPECIFIED",
+            Self::BoatSail => "LOCATION_BOAT_SAIL",
+            Self::BuildingClassic => "LOCATION_BUILDING_CLASSIC",
+            Self::Campsite => "LOCATION_CAMPSITE",
+            Self::Canoe => "LOCATION_CANOE",
+            Self::Castle => "LOCATION_CASTLE",
+            Self::CastleJapanese => "LOCATION_CASTLE_JAPANESE",
+            Self::Cave => "LOCATION_CAVE",
+            Self::Desert => "LOCATION_DESERT",
+            Self::Hut => "LOCATION_HUT",
+            Self::Mountain => "LOCATION_MOUNTAIN",
+            Self::MountainSnow => "LOCATION_MOUNTAIN_SNOW",
+            Self::Oasis => "LOCATION_OASIS",
+            Self::Palace => "LOCATION_PALACE",
+            Self::Tent => "LOCATION_TENT",
+            Self::TreeDeciduous => "LOCATION_TREE_DECIDUOUS",
+            Self::TreeEvergreen => "LOCATION_TREE_EVERGREEN",
+            Self::TreePalm => "LOCATION_TREE_PALM",
+            Self::Volcano => "LOCATION_VOLCANO",
+            Self::TreeLeafless => "LOCATION_TREE_LEAFLESS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOCATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "LOCATION_BOAT_SAIL" => Some(Self::BoatSail),
+            "LOCATION_BUILDING_CLASSIC" => Some(Self::BuildingClassic),
+            "LOCATION_CAMPSITE" => Some(Self::Campsite),
+            "LOCATION_CANOE" => Some(Self::Canoe),
+            "LOCATION_CASTLE" => Some(Self::Castle),
+            "LOCATION_CASTLE_JAPANESE" => Some(Self::CastleJapanese),
+            "LOCATION_CAVE" => Some(Self::Cave),
+            "LOCATION_DESERT" => Some(Self::Desert),
+            "LOCATION_HUT" => Some(Self::Hut),
+            "LOCATION_MOUNTAIN" => Some(Self::Mountain),
+            "LOCATION_MOUNTAIN_SNOW" => Some(Self::MountainSnow),
+            "LOCATION_OASIS" => Some(Self::Oasis),
+            "LOCATION_PALACE" => Some(Self::Palace),
+            "LOCATION_TENT" => Some(Self::Tent),
+            "LOCATION_TREE_DECIDUOUS" => Some(Self::TreeDeciduous),
+            "LOCATION_TREE_EVERGREEN" => Some(Self::TreeEvergreen),
+            "LOCATION_TREE_PALM" => Some(Self::TreePalm),
+            "LOCATION_VOLCANO" => Some(Self::Volcano),
+            "LOCATION_TREE_LEAFLESS" => Some(Self::TreeLeafless),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Item {
+    Unspecified = 0,
+    Amulet = 1,
+    Axe = 2,
+    Bag = 3,
+    Bandage = 4,
+    Bed = 5,
+    Beer = 6,
+    BloodDrop = 7,
+    Bomb = 8,
+    BookClosed = 9,
+    BookOpen = 10,
+    Boomerang = 11,
+    BowAndArrow = 12,
+    Brick = 13,
+    Candle = 14,
+    Coat = 15,
+    Coffin = 16,
+    Coin = 17,
+    Crown = 18,
+    CrystalBall = 19,
+    Dagger = 20,
+    Dart = 21,
+    Door = 22,
+    FlagBlack = 23,
+    FlagTriangle = 24,
+    Firecracker = 25,
+    GemStone = 26,
+    Grave = 27,
+    Hammer = 28,
+    HammerAndPick = 29,
+    HeartRed = 30,
+    HourglassDone = 31,
+    HourglassNotDone = 32,
+    Jar = 33,
+    Key = 34,
+    Leaf = 35,
+    LeafFallen = 36,
+    LeafMaple = 37,
+    Map = 38,
+    MeatOnBone = 39,
+    MeatCut = 40,
+    Pick = 41,
+    PoultryLeg = 42,
+    PrayerBeads = 43,
+    RedEnvelope = 44,
+    RedLantern = 45,
+    Rock = 46,
+    Scroll = 47,
+    Shield = 48,
+    SwordsCrossed = 49,
+    Trident = 50,
+    Urn = 51,
+    Wand = 52,
+    WaterDrop = 53,
+    Harp = 54,
+    Shovel = 55,
+    MouseTrap = 56,
+    SpiderWeb = 57,
+    FlagCheckered = 58,
+    FlagsCrossed = 59,
+    DiyaLamp = 60,
+    Flashlight = 61,
+    LightBulb = 62,
+}
+impl Item {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ITEM_UNSPECIFIED",
+            Self::Amulet => "ITEM_AMULET",
+            Self::Axe => "ITEM_AXE",
+            Self::Bag => "ITEM_BAG",
+            Self::Bandage => "ITEM_BANDAGE",
+            Self::Bed => "ITEM_BED",
+            Self::Beer => "ITEM_BEER",
+            Self::BloodDrop => "ITEM_BLOOD_DROP",
+            Self::Bomb => "ITEM_BOMB",
+            Self::BookClosed => "ITEM_BOOK_CLOSED",
+            Self::BookOpen => "ITEM_BOOK_OPEN",
+            Self::Boomerang => "ITEM_BOOMERANG",
+            Self::BowAndArrow => "ITEM_BOW_AND_ARROW",
+            Self::Brick => "ITEM_BRICK",
+            Self::Candle => "ITEM_CANDLE",
+            Self::Coat => "ITEM_COAT",
+            Self::Coffin => "ITEM_COFFIN",
+            Self::Coin => "ITEM_COIN",
+            Self::Crown => "ITEM_CROWN",
+            Self::CrystalBall => "ITEM_CRYSTAL_BALL",
+            Self::Dagger => "ITEM_DAGGER",
+            Self::Dart => "ITEM_DART",
+            Self::Door => "ITEM_DOOR",
+            Self::FlagBlack => "ITEM_FLAG_BLACK",
+            Self::FlagTriangle => "ITEM_FLAG_TRIANGLE",
+            Self::Firecracker => "ITEM_FIRECRACKER",
+            Self::GemStone => "ITEM_GEM_STONE",
+            Self::Grave => "ITEM_GRAVE",
+            Self::Hammer => "ITEM_HAMMER",
+            Self::HammerAndPick => "ITEM_HAMMER_AND_PICK",
+            Self::HeartRed => "ITEM_HEART_RED",
+            Self::HourglassDone => "ITEM_HOURGLASS_DONE",
+            Self::HourglassNotDone => "ITEM_HOURGLASS_NOT_DONE",
+            Self::Jar => "ITEM_JAR",
+            Self::Key => "ITEM_KEY",
+            Self::Leaf => "ITEM_LEAF",
+            Self::LeafFallen => "ITEM_LEAF_FALLEN",
+            Self::LeafMaple => "ITEM_LEAF_MAPLE",
+            Self::Map => "ITEM_MAP",
+            Self::MeatOnBone => "ITEM_MEAT_ON_BONE",
+            Self::MeatCut => "ITEM_MEAT_CUT",
+            Self::Pick => "ITEM_PICK",
+            Self::PoultryLeg => "ITEM_POULTRY_LEG",
+            Self::PrayerBeads => "ITEM_PRAYER_BEADS",
+            Self::RedEnvelope => "ITEM_RED_ENVELOPE",
+            Self::RedLantern => "ITEM_RED_LANTERN",
+            Self::Rock => "ITEM_ROCK",
+            Self::Scroll => "ITEM_SCROLL",
+            Self::Shield => "ITEM_SHIELD",
+            Self::SwordsCrossed => "ITEM_SWORDS_CROSSED",
+            Self::Trident => "ITEM_TRIDENT",
+            Self::Urn => "ITEM_URN",
+            Self::Wand => "ITEM_WAND",
+            Self::WaterDrop => "ITEM_WATER_DROP",
+            Self::Harp => "ITEM_HARP",
+            Self::Shovel => "ITEM_SHOVEL",
+            Self::MouseTrap => "ITEM_MOUSE_TRAP",
+            Self::SpiderWeb => "ITEM_SPIDER_WEB",
+            Self::FlagCheckered => "ITEM_FLAG_CHECKERED",
+            Self::FlagsCrossed => "ITEM_FLAGS_CROSSED",
+            Self::DiyaLamp => "ITEM_DIYA_LAMP",
+            Self::Flashlight => "ITEM_FLASHLIGHT",
+            Self::LightBulb => "ITEM_LIGHT_BULB",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ITEM_UNSPECIFIED" => Some(Self::Unspecified),
+            "ITEM_AMULET" => Some(Self::Amulet),
+            "ITEM_AXE" => Some(Self::Axe),
+            "ITEM_BAG" => Some(Self::Bag),
+            "ITEM_BANDAGE" => Some(Self::Bandage),
+            "ITEM_BED" => Some(Self::Bed),
+            "ITEM_BEER" => Some(Self::Beer),
+            "ITEM_BLOOD_DROP" => Some(Self::BloodDrop),
+            "ITEM_BOMB" => Some(Self::Bomb),
+            "ITEM_BOOK_CLOSED" => Some(Self::BookClosed),
+            "ITEM_BOOK_OPEN" => Some(Self::BookOpen),
+            "ITEM_BOOMERANG" => Some(Self::Boomerang),
+            "ITEM_BOW_AND_ARROW" => Some(Self::BowAndArrow),
+            "ITEM_BRICK" => Some(Self::Brick),
+            "ITEM_CANDLE" => Some(Self::Candle),
+            "ITEM_COAT" => Some(Self::Coat),
+            "ITEM_COFFIN" => Some(Self::Coffin),
+            "ITEM_COIN" => Some(Self::Coin),
+            "ITEM_CROWN" => Some(Self::Crown),
+            "ITEM_CRYSTAL_BALL" => Some(Self::CrystalBall),
+            "ITEM_DAGGER" => Some(Self::Dagger),
+            "ITEM_DART" => Some(Self::Dart),
+            "ITEM_DOOR" => Some(Self::Door),
+            "ITEM_FLAG_BLACK" => Some(Self::FlagBlack),
+            "ITEM_FLAG_TRIANGLE" => Some(Self::FlagTriangle),
+            "ITEM_FIRECRACKER" => Some(Self::Firecracker),
+            "ITEM_GEM_STONE" => Some(Self::GemStone),
+            "ITEM_GRAVE" => Some(Self::Grave),
+            "ITEM_HAMMER" => Some(Self::Hammer),
+            "ITEM_HAMMER_AND_PICK" => Some(Self::HammerAndPick),
+            "ITEM_HEART_RED" => Some(Self::HeartRed),
+            "ITEM_HOURGLASS_DONE" => Some(Self::HourglassDone),
+            "ITEM_HOURGLASS_NOT_DONE" => Some(Self::HourglassNotDone),
+            "ITEM_JAR" => Some(Self::Jar),
+            "ITEM_KEY" => Some(Self::Key),
+            "ITEM_LEAF" => Some(Self::Leaf),
+            "ITEM_LEAF_FALLEN" => Some(Self::LeafFallen),
+            "ITEM_LEAF_MAPLE" => Some(Self::LeafMaple),
+            "ITEM_MAP" => Some(Self::Map),
+            "ITEM_MEAT_ON_BONE" => Some(Self::MeatOnBone),
+            "ITEM_MEAT_CUT" => Some(Self::MeatCut),
+            "ITEM_PICK" => Some(Self::Pick),
+            "ITEM_POULTRY_LEG" => Some(Self::PoultryLeg),
+            "ITEM_PRAYER_BEADS" => Some(Self::PrayerBeads),
+            "ITEM_RED_ENVELOPE" => Some(Self::RedEnvelope),
+            "ITEM_RED_LANTERN" => Some(Self::RedLantern),
+            "ITEM_ROCK" => Some(Self::Rock),
+            "ITEM_SCROLL" => Some(Self::Scroll),
+            "ITEM_SHIELD" => Some(Self::Shield),
+            "ITEM_SWORDS_CROSSED" => Some(Self::SwordsCrossed),
+            "ITEM_TRIDENT" => Some(Self::Trident),
+            "ITEM_URN" => Some(Self::Urn),
+            "ITEM_WAND" => Some(Self::Wand),
+            "ITEM_WATER_DROP" => Some(Self::WaterDrop),
+            "ITEM_HARP" => Some(Self::Harp),
+            "ITEM_SHOVEL" => Some(Self::Shovel),
+            "ITEM_MOUSE_TRAP" => Some(Self::MouseTrap),
+            "ITEM_SPIDER_WEB" => Some(Self::SpiderWeb),
+            "ITEM_FLAG_CHECKERED" => Some(Self::FlagCheckered),
+            "ITEM_FLAGS_CROSSED" => Some(Self::FlagsCrossed),
+            "ITEM_DIYA_LAMP" => Some(Self::DiyaLamp),
+            "ITEM_FLASHLIGHT" => Some(Self::Flashlight),
+            "ITEM_LIGHT_BULB" => Some(Self::LightBulb),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Flora {
+    Unspecified = 0,
+    Cactus = 1,
+    Clover = 2,
+    FlowerWilted = 3,
+    Herb = 4,
+    Mushroom = 5,
+    Rose = 6,
+    Sheaf = 7,
+    Sunflower = 8,
+}
+impl Flora {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "FLORA_UNSPECIFIED",
+            Self::Cactus => "FLORA_CACTUS",
+            Self::Clover => "FLORA_CLOVER",
+            Self::FlowerWilted => "FLORA_FLOWER_WILTED",
+            Self::Herb => "FLORA_HERB",
+            Self::Mushroom => "FLORA_MUSHROOM",
+            Self::Rose => "FLORA_ROSE",
+            Self::Sheaf => "FLORA_SHEAF",
+            Self::Sunflower => "FLORA_SUNFLOWER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FLORA_UNSPECIFIED" => Some(Self::Unspecified),
+            "FLORA_CACTUS" => Some(Self::Cactus),
+            "FLORA_CLOVER" => Some(Self::Clover),
+            "FLORA_FLOWER_WILTED" => Some(Self::FlowerWilted),
+            "FLORA_HERB" => Some(Self::Herb),
+            "FLORA_MUSHROOM" => Some(Self::Mushroom),
+            "FLORA_ROSE" => Some(Self::Rose),
+            "FLORA_SHEAF" => Some(Self::Sheaf),
+            "FLORA_SUNFLOWER" => Some(Self::Sunflower),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BodyPart {
+    Unspecified = 0,
+    Brain = 1,
+    Ear = 2,
+    Eye = 3,
+    Footprints = 4,
+    Lips = 5,
+    Muscle = 6,
+    Tooth = 7,
+}
+impl BodyPart {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "BODY_PART_UNSPECIFIED",
+            Self::Brain => "BODY_PART_BRAIN",
+            Self::Ear => "BODY_PART_EAR",
+            Self::Eye => "BODY_PART_EYE",
+            Self::Footprints => "BODY_PART_FOOTPRINTS",
+            Self::Lips => "BODY_PART_LIPS",
+            Self::Muscle => "BODY_PART_MUSCLE",
+            Self::Tooth => "BODY_PART_TOOTH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BODY_PART_UNSPECIFIED" => Some(Self::Unspecified),
+            "BODY_PART_BRAIN" => Some(Self::Brain),
+            "BODY_PART_EAR" => Some(Self::Ear),
+            "BODY_PART_EYE" => Some(Self::Eye),
+            "BODY_PART_FOOTPRINTS" => Some(Self::Footprints),
+            "BODY_PART_LIPS" => Some(Self::Lips),
+            "BODY_PART_MUSCLE" => Some(Self::Muscle),
+            "BODY_PART_TOOTH" => Some(Self::Tooth),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Symbol {
+    Unspecified = 0,
+    Anger = 1,
+    Comet = 2,
+    Cyclone = 3,
+    Fire = 4,
+    Electricity = 5,
+    ExclamationDouble = 6,
+    ExclamationWithQuestion = 7,
+    ExclamationRed = 8,
+    ExclamationWhite = 9,
+    GenderFemale = 10,
+    GenderMale = 11,
+    QuestionRed = 12,
+    QuestionWhite = 13,
+    Sparkles = 14,
+    SpeechBubble = 15,
+    SpeechBubbleAngry = 16,
+    Snowflake = 17,
+    Zzz = 18,
+    Fingerprint = 19,
+    Splatter = 20,
+    SpeechBubbleLeft = 21,
+    ThoughtBubble = 22,
+    Silhouette = 23,
+    Silhouettes = 24,
+}
+impl Symbol {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SYMBOL_UNSPECIFIED",
+            Self::Anger => "SYMBOL_ANGER",
+            Self::Comet => "SYMBOL_COMET",
+            Self::Cyclone => "SYMBOL_CYCLONE",
+            Self::Fire => "SYMBOL_FIRE",
+            Self::Electricity => "SYMBOL_ELECTRICITY",
+            Self::ExclamationDouble => "SYMBOL_EXCLAMATION_DOUBLE",
+            Self::ExclamationWithQuestion => "SYMBOL_EXCLAMATION_WITH_QUESTION",
+            Self::ExclamationRed => "SYMBOL_EXCLAMATION_RED",
+            Self::ExclamationWhite => "SYMBOL_EXCLAMATION_WHITE",
+            Self::GenderFemale => "SYMBOL_GENDER_FEMALE",
+            Self::GenderMale => "SYMBOL_GENDER_MALE",
+            Self::QuestionRed => "SYMBOL_QUESTION_RED",
+            Self::QuestionWhite => "SYMBOL_QUESTION_WHITE",
+            Self::Sparkles => "SYMBOL_SPARKLES",
+            Self::SpeechBubble => "SYMBOL_SPEECH_BUBBLE",
+            Self::SpeechBubbleAngry => "SYMBOL_SPEECH_BUBBLE_ANGRY",
+            Self::Snowflake => "SYMBOL_SNOWFLAKE",
+            Self::Zzz => "SYMBOL_ZZZ",
+            Self::Fingerprint => "SYMBOL_FINGERPRINT",
+            Self::Splatter => "SYMBOL_SPLATTER",
+            Self::SpeechBubbleLeft => "SYMBOL_SPEECH_BUBBLE_LEFT",
+            Self::ThoughtBubble => "SYMBOL_THOUGHT_BUBBLE",
+            Self::Silhouette => "SYMBOL_SILHOUETTE",
+            Self::Silhouettes => "SYMBOL_SILHOUETTES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SYMBOL_UNSPECIFIED" => Some(Self::Unspecified),
+            "SYMBOL_ANGER" => Some(Self::Anger),
+            "SYMBOL_COMET" => Some(Self::Comet),
+            "SYMBOL_CYCLONE" => Some(Self::Cyclone),
+            "SYMBOL_FIRE" => Some(Self::Fire),
+            "SYMBOL_ELECTRICITY" => Some(Self::Electricity),
+            "SYMBOL_EXCLAMATION_DOUBLE" => Some(Self::ExclamationDouble),
+            "SYMBOL_EXCLAMATION_WITH_QUESTION" => Some(Self::ExclamationWithQuestion),
+            "SYMBOL_EXCLAMATION_RED" => Some(Self::ExclamationRed),
+            "SYMBOL_EXCLAMATION_WHITE" => Some(Self::ExclamationWhite),
+            "SYMBOL_GENDER_FEMALE" => Some(Self::GenderFemale),
+            "SYMBOL_GENDER_MALE" => Some(Self::GenderMale),
+            "SYMBOL_QUESTION_RED" => Some(Self::QuestionRed),
+            "SYMBOL_QUESTION_WHITE" => Some(Self::QuestionWhite),
+            "SYMBOL_SPARKLES" => Some(Self::Sparkles),
+            "SYMBOL_SPEECH_BUBBLE" => Some(Self::SpeechBubble),
+            "SYMBOL_SPEECH_BUBBLE_ANGRY" => Some(Self::SpeechBubbleAngry),
+            "SYMBOL_SNOWFLAKE" => Some(Self::Snowflake),
+            "SYMBOL_ZZZ" => Some(Self::Zzz),
+            "SYMBOL_FINGERPRINT" => Some(Self::Fingerprint),
+            "SYMBOL_SPLATTER" => Some(Self::Splatter),
+            "SYMBOL_SPEECH_BUBBLE_LEFT" => Some(Self::SpeechBubbleLeft),
+            "SYMBOL_THOUGHT_BUBBLE" => Some(Self::ThoughtBubble),
+            "SYMBOL_SILHOUETTE" => Some(Self::Silhouette),
+            "SYMBOL_SILHOUETTES" => Some(Self::Silhouettes),
+            _ => None,
+        }
+    }
+}
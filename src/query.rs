@@ -0,0 +1,148 @@
+//! Searches the catalog by combining filters on metadata, e.g. for editor tooling and pickers.
+
+use crate::glyph::bases;
+use crate::{Category, Emoji, UnicodeVersion};
+
+/// Finds emojis that match every configured filter.
+///
+/// Persons are returned with a neutral skin tone and gender. An empty query matches every emoji.
+///
+/// # Examples
+///
+/// ```
+/// use mythoji::{Category, Creature, Emoji, EmojiQuery, UnicodeVersion};
+///
+/// let undead = EmojiQuery::new()
+///     .category(Category::Creature)
+///     .keyword("undead")
+///     .max_unicode(UnicodeVersion::V13)
+///     .run();
+/// assert_eq!(undead, [Emoji::Creature(Creature::Ghost)]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EmojiQuery {
+    categories: Vec<Category>,
+    keywords: Vec<String>,
+    max_unicode: Option<UnicodeVersion>,
+}
+
+impl EmojiQuery {
+    /// Creates a query that matches every emoji.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches emojis in the given category.
+    ///
+    /// If called multiple times, emojis in _any_ of the given categories are matched.
+    pub fn category(mut self, category: Category) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    /// Only matches emojis with the given keyword or [synonym](Emoji::synonyms), or whose name
+    /// contains it, ignoring case.
+    ///
+    /// If called multiple times, only emojis that match _every_ keyword are matched.
+    pub fn keyword(mut self, keyword: &str) -> Self {
+        self.keywords.push(keyword.to_lowercase());
+        self
+    }
+
+    /// Only matches emojis that were introduced in the given version of Unicode, or earlier.
+    pub fn max_unicode(mut self, version: UnicodeVersion) -> Self {
+        self.max_unicode = Some(version);
+        self
+    }
+
+    /// Returns whether the given emoji matches every filter.
+    pub fn matches(&self, emoji: &Emoji) -> bool {
+        let metadata = emoji.metadata();
+        if !self.categories.is_empty() && !self.categories.contains(&metadata.category) {
+            return false;
+        }
+        if self
+            .max_unicode
+            .is_some_and(|version| metadata.unicode_version > version)
+        {
+            return false;
+        }
+        let name = metadata.name.to_lowercase();
+        self.keywords.iter().all(|keyword| {
+            name.contains(keyword.as_str())
+                || metadata.keywords.contains(&keyword.as_str())
+                || emoji.synonyms().any(|synonym| synonym == keyword)
+        })
+    }
+
+    /// Returns whether the given custom emoji matches every filter, except the Unicode version.
+    #[cfg(feature = "pack")]
+    pub fn matches_custom(&self, emoji: &crate::pack::CustomEmoji) -> bool {
+        if !self.categories.is_empty() && !self.categories.contains(&emoji.category()) {
+            return false;
+        }
+        let name = emoji.name().to_lowercase();
+        self.keywords.iter().all(|keyword| {
+            name.contains(keyword.as_str())
+                || emoji
+                    .keywords()
+                    .iter()
+                    .any(|k| k.to_lowercase() == *keyword)
+        })
+    }
+
+    /// Returns every emoji that matches the query, in declaration order.
+    pub fn run(&self) -> Vec<Emoji> {
+        bases().filter(|emoji| self.matches(emoji)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Gender, Item, Person, SkinTone};
+
+    #[test]
+    fn test_emoji_query() {
+        assert_eq!(EmojiQuery::new().run().len(), bases().count());
+
+        let undead = EmojiQuery::new().keyword("UNDEAD").run();
+        assert!(undead.contains(&Emoji::Person(
+            Person::Zombie,
+            SkinTone::Neutral,
+            Gender::Neutral
+        )));
+        assert!(undead.contains(&Emoji::Item(Item::Coffin)));
+
+        let dragons = EmojiQuery::new().keyword("Wyvern").run();
+        assert_eq!(dragons, [Emoji::Creature(Creature::Dragon)]);
+
+        let swords = EmojiQuery::new().keyword("sword").run();
+        assert!(swords.contains(&Emoji::Item(Item::SwordsCrossed)));
+
+        let old = EmojiQuery::new()
+            .category(Category::Person)
+            .category(Category::Item)
+            .max_unicode(UnicodeVersion::V6)
+            .run();
+        assert!(!old.is_empty());
+        for emoji in old {
+            let metadata = emoji.metadata();
+            assert_eq!(metadata.unicode_version, UnicodeVersion::V6);
+            assert!(matches!(
+                metadata.category,
+                Category::Person | Category::Item
+            ));
+        }
+
+        assert!(EmojiQuery::new()
+            .keyword("undead")
+            .keyword("blood")
+            .matches(&Person::Vampire.neutral()));
+        assert!(EmojiQuery::new()
+            .keyword("undead")
+            .keyword("nope")
+            .run()
+            .is_empty());
+    }
+}
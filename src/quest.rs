@@ -0,0 +1,133 @@
+//! Quest markers, the classic indicator shown next to a quest giver or a quest location.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::quest::QuestMarker;
+//! use mythoji::{Emoji, Gender, Location, Person, SkinTone};
+//!
+//! let mage = Emoji::Person(Person::Mage, SkinTone::Neutral, Gender::Neutral);
+//! assert_eq!(QuestMarker::Available.mark(mage).to_string(), "🧙❗");
+//!
+//! let castle = Location::Castle;
+//! assert_eq!(QuestMarker::Complete.mark(castle).to_string(), "🏰✅");
+//! assert_eq!(QuestMarker::Hidden.mark(castle).to_string(), "🏰");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+/// The state of a quest, as shown to the player.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum QuestMarker {
+    /// The quest can be accepted, e.g. "❗".
+    Available,
+
+    /// The quest has been accepted, but is not complete, e.g. "❓".
+    InProgress,
+
+    /// The quest is complete, e.g. "✅".
+    Complete,
+
+    /// The quest has failed, e.g. "❌".
+    Failed,
+
+    /// The quest is not shown to the player, so there is no marker.
+    #[default]
+    Hidden,
+}
+
+impl_all!(
+    QuestMarker,
+    [Available, InProgress, Complete, Failed, Hidden]
+);
+
+impl QuestMarker {
+    /// Returns the fully-qualified emoji of this marker, or an empty string for
+    /// [`QuestMarker::Hidden`].
+    ///
+    /// Available and in-progress markers are
+    /// [`Symbol::ExclamationRed`](crate::Symbol::ExclamationRed) and
+    /// [`Symbol::QuestionRed`](crate::Symbol::QuestionRed), but complete ("✅") and failed ("❌")
+    /// markers have no variant in this crate's catalog.
+    pub const fn emoji(&self) -> &'static str {
+        match self {
+            Self::Available => "❗",
+            Self::InProgress => "❓",
+            Self::Complete => "✅",
+            Self::Failed => "❌",
+            Self::Hidden => "",
+        }
+    }
+
+    /// Returns a value that displays the given target, e.g. a person or location, followed by this
+    /// marker.
+    pub const fn mark<T: Display>(self, target: T) -> Marked<T> {
+        Marked {
+            target,
+            marker: self,
+        }
+    }
+}
+
+impl Display for QuestMarker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.emoji())
+    }
+}
+
+/// Displays a target followed by a [`QuestMarker`]; see [`QuestMarker::mark`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Marked<T> {
+    target: T,
+    marker: QuestMarker,
+}
+
+impl<T> Marked<T> {
+    /// Returns the marked target.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns the marker.
+    pub fn marker(&self) -> QuestMarker {
+        self.marker
+    }
+}
+
+impl<T: Display> Display for Marked<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}{}", self.target, self.marker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Emoji, Symbol};
+
+    #[test]
+    fn test_quest_marker() {
+        assert_eq!(
+            QuestMarker::Available.to_string(),
+            Symbol::ExclamationRed.to_string()
+        );
+        assert_eq!(
+            QuestMarker::InProgress.to_string(),
+            Symbol::QuestionRed.to_string()
+        );
+        assert_eq!(QuestMarker::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_marked() {
+        let marked = QuestMarker::Failed.mark(Emoji::Creature(Creature::Dragon));
+        assert_eq!(marked.to_string(), "🐉❌");
+        assert_eq!(marked.target(), &Emoji::Creature(Creature::Dragon));
+        assert_eq!(marked.marker(), QuestMarker::Failed);
+    }
+}
@@ -0,0 +1,212 @@
+//! Random generation of emojis, e.g. for populating a world procedurally.
+//!
+//! Requires the `rand` feature.
+
+use rand::{distributions::Distribution, Rng};
+
+use crate::{Emoji, Gender, Person, SkinTone};
+
+/// Generates random, valid [`Emoji::Person`] values, with configurable weights.
+///
+/// By default, every person, skin tone, and gender is equally likely. Skin tones and genders are
+/// only picked for persons that support them (see [`Person::supports_skin_tone`] and
+/// [`Person::supports_gender`]), otherwise the neutral variant is used.
+///
+/// # Examples
+///
+/// ```
+/// use mythoji::{Emoji, Person, RandomPerson};
+///
+/// // Mostly commoners, with the occasional mage or vampire.
+/// let town = RandomPerson::new().persons([
+///     (Person::Person, 20),
+///     (Person::Mage, 2),
+///     (Person::Vampire, 1),
+/// ]);
+///
+/// let mut rng = rand::thread_rng();
+/// for _ in 0..10 {
+///     let Emoji::Person(person, _, _) = town.sample(&mut rng) else {
+///         unreachable!();
+///     };
+///     assert!(matches!(person, Person::Person | Person::Mage | Person::Vampire));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomPerson {
+    persons: Vec<(Person, u32)>,
+    skin_tones: Vec<(SkinTone, u32)>,
+    genders: Vec<(Gender, u32)>,
+}
+
+impl Default for RandomPerson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomPerson {
+    /// Creates a generator where every person, skin tone, and gender is equally likely.
+    pub fn new() -> Self {
+        Self {
+            persons: Person::ALL.iter().map(|p| (*p, 1)).collect(),
+            skin_tones: SkinTone::ALL.iter().map(|s| (*s, 1)).collect(),
+            genders: Gender::ALL.iter().map(|g| (*g, 1)).collect(),
+        }
+    }
+
+    /// Sets the weight of a single person.
+    ///
+    /// A weight of `0` means the person is never generated.
+    pub fn person(mut self, person: Person, weight: u32) -> Self {
+        set_weight(&mut self.persons, person, weight);
+        self
+    }
+
+    /// Replaces the weights of every person; persons that are omitted are never generated.
+    pub fn persons(mut self, weights: impl IntoIterator<Item = (Person, u32)>) -> Self {
+        self.persons = weights.into_iter().collect();
+        self
+    }
+
+    /// Sets the weight of a single skin tone.
+    ///
+    /// A weight of `0` means the skin tone is never generated.
+    pub fn skin_tone(mut self, skin: SkinTone, weight: u32) -> Self {
+        set_weight(&mut self.skin_tones, skin, weight);
+        self
+    }
+
+    /// Replaces the weights of every skin tone; skin tones that are omitted are never generated.
+    pub fn skin_tones(mut self, weights: impl IntoIterator<Item = (SkinTone, u32)>) -> Self {
+        self.skin_tones = weights.into_iter().collect();
+        self
+    }
+
+    /// Sets the weight of a single gender.
+    ///
+    /// A weight of `0` means the gender is never generated.
+    pub fn gender(mut self, gender: Gender, weight: u32) -> Self {
+        set_weight(&mut self.genders, gender, weight);
+        self
+    }
+
+    /// Replaces the weights of every gender; genders that are omitted are never generated.
+    pub fn genders(mut self, weights: impl IntoIterator<Item = (Gender, u32)>) -> Self {
+        self.genders = weights.into_iter().collect();
+        self
+    }
+
+    /// Generates a random [`Emoji::Person`].
+    ///
+    /// # Panics
+    ///
+    /// If every person has a weight of `0`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Emoji {
+        let person = pick(&self.persons, rng).expect("at least one person must have a weight");
+        let skin = if person.supports_skin_tone() {
+            pick(&self.skin_tones, rng).unwrap_or_default()
+        } else {
+            SkinTone::Neutral
+        };
+        let gender = if person.supports_gender() {
+            pick(&self.genders, rng).unwrap_or_default()
+        } else {
+            Gender::Neutral
+        };
+        Emoji::Person(person, skin, gender)
+    }
+}
+
+impl Distribution<Emoji> for RandomPerson {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Emoji {
+        RandomPerson::sample(self, rng)
+    }
+}
+
+fn set_weight<T: PartialEq>(weights: &mut Vec<(T, u32)>, value: T, weight: u32) {
+    match weights.iter_mut().find(|(v, _)| v == &value) {
+        Some((_, w)) => *w = weight,
+        None => weights.push((value, weight)),
+    }
+}
+
+/// Picks a random value from a list of weighted values, or `None` if every weight is `0`.
+pub(crate) fn pick<T: Copy, R: Rng + ?Sized>(weights: &[(T, u32)], rng: &mut R) -> Option<T> {
+    let total: u64 = weights.iter().map(|(_, w)| u64::from(*w)).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut roll = rng.gen_range(0..total);
+    for (value, weight) in weights {
+        let weight = u64::from(*weight);
+        if roll < weight {
+            return Some(*value);
+        }
+        roll -= weight;
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_random_person_weights() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let generator = RandomPerson::new()
+            .persons([(Person::Elf, 1)])
+            .skin_tones([(SkinTone::Dark, 1)])
+            .gender(Gender::Neutral, 0)
+            .gender(Gender::Male, 0);
+
+        for _ in 0..100 {
+            assert_eq!(
+                generator.sample(&mut rng),
+                Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female)
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_person_unsupported_modifiers() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let generator = RandomPerson::new()
+            .persons([(Person::Baby, 1), (Person::Zombie, 1)])
+            .skin_tones([(SkinTone::Light, 1)])
+            .genders([(Gender::Female, 1)]);
+
+        for _ in 0..100 {
+            let emoji = generator.sample(&mut rng);
+            assert!(
+                emoji == Emoji::Person(Person::Baby, SkinTone::Light, Gender::Neutral)
+                    || emoji == Emoji::Person(Person::Zombie, SkinTone::Neutral, Gender::Female),
+                "{:?}",
+                emoji
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_person_distribution() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let generator = RandomPerson::new().persons([(Person::Person, 9), (Person::Mage, 1)]);
+
+        let mages = (0..1000)
+            .map(|_| rng.sample(&generator))
+            .filter(|e| matches!(e, Emoji::Person(Person::Mage, _, _)))
+            .count();
+        assert!((50..150).contains(&mages), "{}", mages);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_person_no_persons() {
+        RandomPerson::new()
+            .persons([])
+            .sample(&mut StdRng::seed_from_u64(0));
+    }
+}
@@ -0,0 +1,309 @@
+//! Rarity tiers for [`Item`]s and [`Creature`]s, e.g. to color-code and sort loot consistently.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::rarity::{Rarity, RarityRegistry};
+//! use mythoji::Item;
+//!
+//! let mut registry = RarityRegistry::new();
+//! assert_eq!(registry.get(Item::Crown), Rarity::Legendary);
+//!
+//! // In this game, coins are hard to come by.
+//! registry.set(Item::Coin, Rarity::Rare);
+//! assert_eq!(registry.get(Item::Coin), Rarity::Rare);
+//!
+//! let mut loot = vec![Item::Coin, Item::Crown, Item::Bag];
+//! loot.sort_by_key(|item| std::cmp::Reverse(registry.get(*item)));
+//! assert_eq!(loot, [Item::Crown, Item::Coin, Item::Bag]);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::{Creature, Item};
+
+/// How rare something is, from [`Rarity::Common`] to [`Rarity::Legendary`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Rarity {
+    /// Found everywhere, e.g. a coin.
+    #[default]
+    Common,
+
+    /// Found occasionally, e.g. a dagger.
+    Uncommon,
+
+    /// Found seldomly, e.g. a gemstone.
+    Rare,
+
+    /// Found very seldomly, e.g. a crystal ball.
+    Epic,
+
+    /// Found once in a lifetime, e.g. a crown.
+    Legendary,
+}
+
+impl_all!(Rarity, [Common, Uncommon, Rare, Epic, Legendary]);
+
+impl Rarity {
+    /// Returns a colored circle that represents this rarity, e.g. "🟣" for [`Rarity::Epic`].
+    pub const fn glyph(&self) -> &'static str {
+        match self {
+            Self::Common => "⚪",
+            Self::Uncommon => "🟢",
+            Self::Rare => "🔵",
+            Self::Epic => "🟣",
+            Self::Legendary => "🟠",
+        }
+    }
+
+    /// Returns the conventional color of this rarity, as an `(r, g, b)` tuple.
+    pub const fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Self::Common => (0xff, 0xff, 0xff),
+            Self::Uncommon => (0x1e, 0xff, 0x00),
+            Self::Rare => (0x00, 0x70, 0xdd),
+            Self::Epic => (0xa3, 0x35, 0xee),
+            Self::Legendary => (0xff, 0x80, 0x00),
+        }
+    }
+}
+
+/// A value that has a suggested, default, [`Rarity`].
+pub trait SuggestedRarity {
+    /// Returns the suggested rarity.
+    fn suggested_rarity(&self) -> Rarity;
+}
+
+impl SuggestedRarity for Item {
+    fn suggested_rarity(&self) -> Rarity {
+        match self {
+            Self::Bag
+            | Self::Bandage
+            | Self::Bed
+            | Self::Beer
+            | Self::BloodDrop
+            | Self::Brick
+            | Self::Candle
+            | Self::Coat
+            | Self::Coin
+            | Self::Dart
+            | Self::Door
+            | Self::FlagCheckered
+            | Self::FlagTriangle
+            | Self::Flashlight
+            | Self::Hammer
+            | Self::HourglassDone
+            | Self::HourglassNotDone
+            | Self::Jar
+            | Self::Leaf
+            | Self::LeafFallen
+            | Self::LeafMaple
+            | Self::LightBulb
+            | Self::MeatOnBone
+            | Self::MeatCut
+            | Self::MouseTrap
+            | Self::Pick
+            | Self::PoultryLeg
+            | Self::RedLantern
+            | Self::Rock
+            | Self::Shovel
+            | Self::SpiderWeb
+            | Self::WaterDrop => Rarity::Common,
+            Self::Axe
+            | Self::BookClosed
+            | Self::Boomerang
+            | Self::BowAndArrow
+            | Self::Coffin
+            | Self::Dagger
+            | Self::DiyaLamp
+            | Self::FlagBlack
+            | Self::Firecracker
+            | Self::FlagsCrossed
+            | Self::Grave
+            | Self::HammerAndPick
+            | Self::Harp
+            | Self::Key
+            | Self::Map
+            | Self::PrayerBeads
+            | Self::RedEnvelope
+            | Self::Scroll
+            | Self::Shield
+            | Self::Urn => Rarity::Uncommon,
+            Self::Amulet
+            | Self::Bomb
+            | Self::BookOpen
+            | Self::GemStone
+            | Self::HeartRed
+            | Self::SwordsCrossed
+            | Self::Wand => Rarity::Rare,
+            Self::CrystalBall | Self::Trident => Rarity::Epic,
+            Self::Crown => Rarity::Legendary,
+        }
+    }
+}
+
+impl SuggestedRarity for Creature {
+    fn suggested_rarity(&self) -> Rarity {
+        match self {
+            Self::Ant
+            | Self::Badger
+            | Self::Beaver
+            | Self::Beetle
+            | Self::Blowfish
+            | Self::Bug
+            | Self::Butterfly
+            | Self::Camel
+            | Self::Cat
+            | Self::Cockroach
+            | Self::Cow
+            | Self::Crab
+            | Self::Cricket
+            | Self::Dog
+            | Self::Dolphin
+            | Self::Donkey
+            | Self::Dove
+            | Self::Fish
+            | Self::Fly
+            | Self::Fox
+            | Self::Frog
+            | Self::Goat
+            | Self::Hedgehog
+            | Self::Honeybee
+            | Self::Horse
+            | Self::Jellyfish
+            | Self::Lizard
+            | Self::Llama
+            | Self::Monkey
+            | Self::Mosquito
+            | Self::Mouse
+            | Self::Otter
+            | Self::Ox
+            | Self::Parrot
+            | Self::Pig
+            | Self::Rabbit
+            | Self::Raccoon
+            | Self::Ram
+            | Self::Rat
+            | Self::Rooster
+            | Self::Seal
+            | Self::Sheep
+            | Self::Snail
+            | Self::Swan
+            | Self::TropicalFish
+            | Self::Turkey
+            | Self::Turtle
+            | Self::WaterBuffalo
+            | Self::Worm => Rarity::Common,
+            Self::Bat
+            | Self::Bear
+            | Self::Bison
+            | Self::BlackBird
+            | Self::Boar
+            | Self::Crocodile
+            | Self::Deer
+            | Self::Eagle
+            | Self::Goblin
+            | Self::Moose
+            | Self::Octopus
+            | Self::Owl
+            | Self::Peacock
+            | Self::Scorpion
+            | Self::Snake
+            | Self::Spider
+            | Self::Squid
+            | Self::Wolf => Rarity::Uncommon,
+            Self::Elephant
+            | Self::Ghost
+            | Self::Gorilla
+            | Self::Leopard
+            | Self::Lion
+            | Self::Rhinoceros
+            | Self::Shark
+            | Self::Tiger
+            | Self::Whale => Rarity::Rare,
+            Self::Mammoth | Self::Ogre => Rarity::Epic,
+            Self::Dragon => Rarity::Legendary,
+        }
+    }
+}
+
+/// Assigns a [`Rarity`] to values, defaulting to their [suggested rarity](SuggestedRarity).
+#[derive(Debug, Clone)]
+pub struct RarityRegistry<T> {
+    overrides: HashMap<T, Rarity>,
+}
+
+impl<T> Default for RarityRegistry<T> {
+    fn default() -> Self {
+        Self {
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl<T: SuggestedRarity + Eq + Hash> RarityRegistry<T> {
+    /// Creates a registry where every value has its suggested rarity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the registry with the rarity of `value` set to `rarity`.
+    pub fn with(mut self, value: T, rarity: Rarity) -> Self {
+        self.set(value, rarity);
+        self
+    }
+
+    /// Sets the rarity of `value` to `rarity`.
+    pub fn set(&mut self, value: T, rarity: Rarity) {
+        self.overrides.insert(value, rarity);
+    }
+
+    /// Resets the rarity of `value` to its suggested rarity.
+    pub fn reset(&mut self, value: &T) {
+        self.overrides.remove(value);
+    }
+
+    /// Returns the rarity of `value`.
+    pub fn get(&self, value: T) -> Rarity {
+        match self.overrides.get(&value) {
+            Some(rarity) => *rarity,
+            None => value.suggested_rarity(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggested_rarity() {
+        assert_eq!(Item::Coin.suggested_rarity(), Rarity::Common);
+        assert_eq!(Item::Crown.suggested_rarity(), Rarity::Legendary);
+        assert_eq!(Creature::Rat.suggested_rarity(), Rarity::Common);
+        assert_eq!(Creature::Dragon.suggested_rarity(), Rarity::Legendary);
+    }
+
+    #[test]
+    fn test_rarity_registry() {
+        let mut registry = RarityRegistry::new().with(Creature::Rat, Rarity::Epic);
+        assert_eq!(registry.get(Creature::Rat), Rarity::Epic);
+        assert_eq!(registry.get(Creature::Wolf), Rarity::Uncommon);
+
+        registry.reset(&Creature::Rat);
+        assert_eq!(registry.get(Creature::Rat), Rarity::Common);
+    }
+
+    #[test]
+    fn test_rarity_order() {
+        let mut sorted = Rarity::ALL.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, Rarity::ALL);
+    }
+}
@@ -0,0 +1,422 @@
+//! Renderers, which centralize how emojis are presented, so a game configures presentation once.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::fallback::Fallback;
+//! use mythoji::render::Renderer;
+//! use mythoji::{Creature, Emoji, UnicodeVersion};
+//!
+//! let renderer = Renderer::new()
+//!     .max_unicode_version(UnicodeVersion::V12)
+//!     .fallback(Fallback::Name);
+//!
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Dragon)), "🐉");
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Mammoth)), "[mammoth]");
+//! ```
+//!
+//! The same code path can produce deterministic plain text, e.g. for logs and tests:
+//!
+//! ```
+//! use mythoji::fallback::Fallback;
+//! use mythoji::render::Renderer;
+//! use mythoji::{Creature, Emoji};
+//!
+//! let renderer = Renderer::text(Fallback::Name);
+//! assert_eq!(renderer.render(Emoji::Creature(Creature::Dragon)), "[dragon]");
+//! ```
+//!
+//! Non-terminal backends (e.g. SDL, wgpu, or the web) implement [`EmojiRenderer`], so they can draw
+//! the same scenes (e.g. a [`Grid`](crate::grid::Grid)) as [`Terminal`]:
+//!
+//! ```
+//! use mythoji::grid::Grid;
+//! use mythoji::render::{Style, Terminal};
+//! use mythoji::{Creature, Emoji};
+//!
+//! let mut map = Grid::new(2, 1);
+//! map.set(1, 0, Emoji::Creature(Creature::Dragon));
+//!
+//! let mut terminal = Terminal::new(Vec::new());
+//! map.draw(&mut terminal, Style::new()).unwrap();
+//! assert_eq!(terminal.into_inner(), "\x1b[1;3H🐉".as_bytes());
+//! ```
+
+use std::borrow::Cow;
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::fallback::{Fallback, FallbackProvider};
+use crate::{Emoji, Gender, Qualification, SkinTone, UnicodeVersion};
+
+/// Presentation options for rendering emojis.
+///
+/// By default, every emoji is rendered fully-qualified, as is, and unsupported emojis are rendered
+/// in the [`Fallback::Ascii`] style. Any [`FallbackProvider`] can be used instead.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Renderer<F = Fallback> {
+    qualification: Qualification,
+    fallback: F,
+    max_unicode_version: Option<UnicodeVersion>,
+    skin_tones: SkinTonePolicy,
+    text_only: bool,
+}
+
+impl Renderer {
+    /// Creates a renderer with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: FallbackProvider> Renderer<F> {
+    /// Creates a renderer that renders every emoji as plain text, using the given fallback.
+    pub fn text(fallback: F) -> Self {
+        Self {
+            qualification: Qualification::Full,
+            fallback,
+            max_unicode_version: None,
+            skin_tones: SkinTonePolicy::Keep,
+            text_only: true,
+        }
+    }
+
+    /// Renders emojis with the given [`Qualification`].
+    pub fn qualification(mut self, qualification: Qualification) -> Self {
+        self.qualification = qualification;
+        self
+    }
+
+    /// Renders unsupported emojis using the given fallback, e.g. a [`Fallback`] style.
+    pub fn fallback<G: FallbackProvider>(self, fallback: G) -> Renderer<G> {
+        Renderer {
+            qualification: self.qualification,
+            fallback,
+            max_unicode_version: self.max_unicode_version,
+            skin_tones: self.skin_tones,
+            text_only: self.text_only,
+        }
+    }
+
+    /// Only supports emojis introduced in the given version of Unicode or earlier.
+    ///
+    /// A person whose skin tone or gender requires a more recent version is rendered without it,
+    /// if possible, before falling back to plain text.
+    pub fn max_unicode_version(mut self, version: UnicodeVersion) -> Self {
+        self.max_unicode_version = Some(version);
+        self
+    }
+
+    /// Applies the given [`SkinTonePolicy`] to persons.
+    pub fn skin_tones(mut self, policy: SkinTonePolicy) -> Self {
+        self.skin_tones = policy;
+        self
+    }
+
+    /// Returns whether the given emoji is rendered as an emoji, rather than as plain text.
+    pub fn supports(&self, emoji: Emoji) -> bool {
+        self.supports_version(emoji.metadata().unicode_version)
+    }
+
+    /// Returns whether emojis introduced in the given version of Unicode are rendered as emojis.
+    pub fn supports_version(&self, version: UnicodeVersion) -> bool {
+        !self.text_only && self.max_unicode_version.is_none_or(|max| version <= max)
+    }
+
+    /// Renders an emoji that is not defined by this crate, given its `PascalCase` name, its glyph,
+    /// and its plain text fallback, which is assumed to be supported unless this is text only.
+    #[cfg(feature = "pack")]
+    pub(crate) fn render_custom<'a>(
+        &self,
+        name: &'a str,
+        glyph: &'a str,
+        text: &'a str,
+    ) -> Cow<'a, str> {
+        match self.text_only {
+            true => self.fallback.fallback_custom(name, text),
+            false => Cow::Borrowed(glyph),
+        }
+    }
+
+    /// Returns whether every emoji is rendered as plain text, as by [`Renderer::text`].
+    pub fn is_text_only(&self) -> bool {
+        self.text_only
+    }
+
+    /// Renders the given emoji.
+    pub fn render(&self, emoji: Emoji) -> Cow<'static, str> {
+        let emoji = match (emoji, self.skin_tones) {
+            (Emoji::Person(person, _, gender), SkinTonePolicy::Neutral) => {
+                Emoji::Person(person, SkinTone::Neutral, gender)
+            }
+            (Emoji::Person(person, _, gender), SkinTonePolicy::Uniform(skin)) => {
+                Emoji::Person(person, skin, gender)
+            }
+            _ => emoji,
+        };
+        let candidates = match emoji {
+            Emoji::Person(person, skin, _) => [
+                emoji,
+                Emoji::Person(person, skin, Gender::Neutral),
+                Emoji::Person(person, SkinTone::Neutral, Gender::Neutral),
+            ],
+            _ => [emoji; 3],
+        };
+        match candidates.into_iter().find(|c| self.supports(*c)) {
+            Some(Emoji::Creature(creature)) => Cow::Borrowed(creature.glyph()),
+            Some(Emoji::Location(location)) => Cow::Borrowed(location.glyph()),
+            Some(Emoji::Item(item)) => Cow::Borrowed(item.glyph()),
+            Some(Emoji::Flora(flora)) => Cow::Borrowed(flora.glyph()),
+            Some(Emoji::BodyPart(body_part)) => Cow::Borrowed(body_part.glyph()),
+            Some(Emoji::Symbol(symbol)) => Cow::Borrowed(symbol.glyph()),
+            Some(person) => Cow::Owned(person.qualified(self.qualification).to_string()),
+            None => self.fallback.fallback(emoji),
+        }
+    }
+}
+
+/// A backend that draws emojis in cells, e.g. a terminal, or a window.
+pub trait EmojiRenderer {
+    /// The error returned when drawing fails.
+    type Error;
+
+    /// Draws an emoji in the cell at `(x, y)`, where `(0, 0)` is the top-left cell.
+    fn draw(&mut self, emoji: Emoji, x: usize, y: usize, style: Style) -> Result<(), Self::Error>;
+}
+
+/// How an [`EmojiRenderer`] draws a cell, beyond the emoji itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Style {
+    /// The background color of the cell, as an `(r, g, b)` tuple, if any.
+    pub background: Option<(u8, u8, u8)>,
+}
+
+impl Style {
+    /// Creates the default style, which draws only the emoji.
+    pub const fn new() -> Self {
+        Self { background: None }
+    }
+
+    /// Fills the background of the cell with the given color, e.g. [`Rarity::rgb`].
+    ///
+    /// [`Rarity::rgb`]: crate::rarity::Rarity::rgb
+    pub const fn background(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.background = Some(rgb);
+        self
+    }
+}
+
+/// Draws emojis to a terminal, using ANSI escape sequences to position the cursor.
+///
+/// Each cell is two columns wide, the width of an emoji, and is rendered by a [`Renderer`].
+#[derive(Debug, Clone)]
+pub struct Terminal<W, F = Fallback> {
+    out: W,
+    renderer: Renderer<F>,
+}
+
+impl<W: Write> Terminal<W> {
+    /// Creates a terminal that writes to the given output, e.g. [`std::io::stdout`].
+    pub fn new(out: W) -> Self {
+        Self::with_renderer(out, Renderer::new())
+    }
+}
+
+impl<W: Write, F: FallbackProvider> Terminal<W, F> {
+    /// Creates a terminal that writes to the given output, rendering emojis with the renderer.
+    pub fn with_renderer(out: W, renderer: Renderer<F>) -> Self {
+        Self { out, renderer }
+    }
+
+    /// Returns the output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Measures how many columns the terminal advances the cursor when printing the given probe.
+    ///
+    /// This prints the probe, and queries the cursor position before and after with the ANSI
+    /// "Device Status Report" sequence, reading each report from the given input (e.g.
+    /// [`std::io::stdin`]). The probe is then erased. This is useful to adapt layouts to terminals
+    /// that render sequences joined by a zero width joiner as multiple emojis, e.g. "🧝‍♀️" as "🧝♀️":
+    ///
+    /// ```no_run
+    /// use mythoji::render::Terminal;
+    /// use mythoji::{Gender, Person};
+    ///
+    /// // The terminal must be in raw mode, so the reports are not echoed or line buffered.
+    /// let mut terminal = Terminal::new(std::io::stdout());
+    /// let columns = terminal.measure(&mut std::io::stdin(), &Person::Elf.female().to_string())?;
+    /// if columns > 2 {
+    ///     // Avoid gendered sequences, which this terminal renders as multiple emojis.
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// This is opt-in, as it requires the terminal to be interactive and in raw mode (i.e. without
+    /// echo or line buffering), which is outside the scope of this crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails, or if the input does not contain a valid report, e.g.
+    /// because the output is not a terminal.
+    pub fn measure<R: Read>(&mut self, input: &mut R, probe: &str) -> io::Result<usize> {
+        let start = self.cursor_column(input)?;
+        self.out.write_all(probe.as_bytes())?;
+        let end = self.cursor_column(input)?;
+        write!(self.out, "\x1b[{}G\x1b[K", start)?;
+        self.out.flush()?;
+        Ok(end.saturating_sub(start))
+    }
+
+    /// Queries the cursor position, and returns its column, where `1` is the leftmost column.
+    fn cursor_column<R: Read>(&mut self, input: &mut R) -> io::Result<usize> {
+        self.out.write_all(b"\x1b[6n")?;
+        self.out.flush()?;
+
+        // The report is "ESC [ row ; column R", which is read byte by byte so no input is consumed
+        // past the end of the report.
+        let mut report = Vec::new();
+        let mut byte = [0];
+        while report.last() != Some(&b'R') {
+            input.read_exact(&mut byte)?;
+            report.push(byte[0]);
+        }
+        std::str::from_utf8(&report)
+            .ok()
+            .and_then(|report| report.strip_prefix("\x1b["))
+            .and_then(|report| report.strip_suffix('R'))
+            .and_then(|report| report.split_once(';'))
+            .and_then(|(_, column)| column.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid cursor report"))
+    }
+}
+
+impl<W: Write, F: FallbackProvider> EmojiRenderer for Terminal<W, F> {
+    type Error = io::Error;
+
+    fn draw(&mut self, emoji: Emoji, x: usize, y: usize, style: Style) -> io::Result<()> {
+        write!(self.out, "\x1b[{};{}H", y + 1, x * 2 + 1)?;
+        if let Some((r, g, b)) = style.background {
+            write!(self.out, "\x1b[48;2;{};{};{}m", r, g, b)?;
+        }
+        self.out.write_all(self.renderer.render(emoji).as_bytes())?;
+        if style.background.is_some() {
+            self.out.write_all(b"\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+/// How a [`Renderer`] treats the skin tones of persons.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum SkinTonePolicy {
+    /// Renders the skin tone of each person as is.
+    #[default]
+    Keep,
+
+    /// Renders every person with a neutral skin tone.
+    Neutral,
+
+    /// Renders every person with the given skin tone, e.g. the player's preference.
+    Uniform(SkinTone),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Item, Person};
+
+    #[test]
+    fn test_renderer_default() {
+        let renderer = Renderer::new();
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(renderer.render(elf), "🧝🏿‍♀️");
+        assert_eq!(renderer.render(Emoji::Item(Item::Coin)), "🪙");
+
+        let renderer = renderer.qualification(Qualification::Minimal);
+        assert_eq!(renderer.render(elf), "🧝🏿‍♀");
+    }
+
+    #[test]
+    fn test_renderer_max_unicode_version() {
+        let renderer = Renderer::new().max_unicode_version(UnicodeVersion::V12);
+        let bearded = Emoji::Person(Person::BeardedPerson, SkinTone::Dark, Gender::Female);
+        assert_eq!(renderer.render(bearded), "🧔🏿");
+
+        let renderer = renderer.max_unicode_version(UnicodeVersion::V6);
+        assert_eq!(renderer.render(bearded), "B");
+        assert!(!renderer.supports(Emoji::Item(Item::Coin)));
+        assert!(renderer.supports(Emoji::Creature(Creature::Dragon)));
+    }
+
+    #[test]
+    fn test_renderer_skin_tones() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Neutral);
+        let renderer = Renderer::new().skin_tones(SkinTonePolicy::Neutral);
+        assert_eq!(renderer.render(mage), "🧙");
+        let renderer = renderer.skin_tones(SkinTonePolicy::Uniform(SkinTone::Light));
+        assert_eq!(renderer.render(mage), "🧙🏻");
+    }
+
+    #[test]
+    fn test_renderer_text() {
+        let renderer = Renderer::text(Fallback::Name);
+        assert_eq!(renderer.render(Emoji::Item(Item::Coin)), "[coin]");
+        let elf = Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female);
+        assert_eq!(renderer.render(elf), "[elf:dark:female]");
+    }
+
+    #[test]
+    fn test_terminal() {
+        let renderer = Renderer::text(Fallback::Name);
+        let mut terminal = Terminal::with_renderer(Vec::new(), renderer);
+        let style = Style::new().background((1, 2, 3));
+        terminal.draw(Emoji::Item(Item::Coin), 2, 1, style).unwrap();
+        assert_eq!(
+            String::from_utf8(terminal.into_inner()).unwrap(),
+            "\x1b[2;5H\x1b[48;2;1;2;3m[coin]\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_terminal_measure() {
+        let mut input = "\x1b[5;3R\x1b[5;7R".as_bytes();
+        let mut terminal = Terminal::new(Vec::new());
+        assert_eq!(terminal.measure(&mut input, "🧝‍♀️").unwrap(), 4);
+        assert!(input.is_empty());
+        assert_eq!(
+            String::from_utf8(terminal.into_inner()).unwrap(),
+            "\x1b[6n🧝‍♀️\x1b[6n\x1b[3G\x1b[K"
+        );
+
+        let mut terminal = Terminal::new(Vec::new());
+        let error = terminal
+            .measure(&mut "\x1b[5R".as_bytes(), "🐉")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = terminal.measure(&mut "".as_bytes(), "🐉").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_renderer_fallback_provider() {
+        struct Question;
+
+        impl FallbackProvider for Question {
+            fn fallback(&self, _: Emoji) -> Cow<'static, str> {
+                Cow::Borrowed("?")
+            }
+        }
+
+        let renderer = Renderer::new()
+            .max_unicode_version(UnicodeVersion::V6)
+            .fallback(Question);
+        assert_eq!(renderer.render(Emoji::Item(Item::Coin)), "?");
+        assert_eq!(renderer.render(Emoji::Creature(Creature::Dragon)), "🐉");
+    }
+}
@@ -0,0 +1,314 @@
+//! The serialization format of this crate, enabled by the `serde` feature.
+//!
+//! The format is designed so that long-lived data, such as save files, can still be read after
+//! upgrading the crate:
+//!
+//! - Every variant is serialized by a stable, kebab-case name, e.g. `Item::SwordsCrossed` is
+//!   serialized as `"swords-crossed"`. If a variant is renamed, its former name is still accepted
+//!   when deserializing (see [`Metadata::aliases`](crate::Metadata::aliases)).
+//! - An [`Emoji`](crate::Emoji) is serialized as a single-entry map from its kebab-case category to
+//!   its value, e.g. `{"creature": "dragon"}`, and persons as a `[person, skin tone, gender]`
+//!   sequence, e.g. `{"person": ["elf", "dark", "female"]}`.
+//! - Documents can be wrapped in [`Versioned`], which records the [`FORMAT_VERSION`] they were
+//!   written with, and refuses to read documents written by a newer format.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use mythoji::schema::Versioned;
+//! use mythoji::{Creature, Emoji};
+//!
+//! let saved = serde_json::to_string(&Versioned::new(Emoji::Creature(Creature::Dragon))).unwrap();
+//! assert_eq!(saved, r#"{"format":1,"data":{"creature":"dragon"}}"#);
+//!
+//! let loaded: Versioned<Emoji> = serde_json::from_str(&saved).unwrap();
+//! assert_eq!(loaded.data, Emoji::Creature(Creature::Dragon));
+//! # }
+//! ```
+//!
+//! # Glyphs
+//!
+//! Where readability matters more than stability, e.g. in logs or hand-written files, an
+//! [`Emoji`](crate::Emoji) field can instead be serialized as its glyph, e.g. `"🐉"`, with
+//! [`glyph`], or a collection of emojis with [`Glyph`]. Glyphs are parsed as by
+//! [`Emoji::from_glyph`](crate::Emoji::from_glyph).
+//!
+//! Not every emoji has a glyph of its own, so serializing the following emojis fails rather than
+//! writing a glyph that would be read back as a different emoji:
+//!
+//! - [`Location::Oasis`](crate::Location::Oasis), which shares a glyph with
+//!   [`Location::Desert`](crate::Location::Desert);
+//! - [`Location::Palace`](crate::Location::Palace), which shares a glyph with
+//!   [`Location::CastleJapanese`](crate::Location::CastleJapanese);
+//! - persons with a gender that their glyph does not display, i.e. a male or female
+//!   [`Artist`](crate::Person::Artist), [`Baby`](crate::Person::Baby),
+//!   [`BaldPerson`](crate::Person::BaldPerson), [`HeadScarfPerson`](crate::Person::HeadScarfPerson),
+//!   [`Royalty`](crate::Person::Royalty), or [`SkullCapPerson`](crate::Person::SkullCapPerson);
+//! - persons with a skin tone that their glyph does not display, i.e. a
+//!   [`Genie`](crate::Person::Genie) or [`Zombie`](crate::Person::Zombie) with a skin tone.
+//!
+//! Use the structured format for data that may contain these emojis.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use mythoji::schema::Glyph;
+//! use mythoji::{Creature, Emoji, Item};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Inventory {
+//!     #[serde(with = "mythoji::schema::glyph")]
+//!     pet: Emoji,
+//!     items: Vec<Glyph>,
+//! }
+//!
+//! let inventory = Inventory {
+//!     pet: Emoji::Creature(Creature::Dragon),
+//!     items: vec![Glyph(Emoji::Item(Item::Coin))],
+//! };
+//! let saved = serde_json::to_string(&inventory).unwrap();
+//! assert_eq!(saved, r#"{"pet":"🐉","items":["🪙"]}"#);
+//! assert_eq!(serde_json::from_str::<Inventory>(&saved).unwrap(), inventory);
+//! # }
+//! ```
+//!
+//! # Migration notes
+//!
+//! ## Format 1
+//!
+//! The initial format, introduced in crate version `0.2.0`.
+//!
+//! - `"heard-scarf-person"` is accepted as an alias of `"head-scarf-person"`.
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::Emoji;
+
+/// The current version of the serialization format.
+///
+/// This is incremented whenever a change is made that older crate versions cannot read, and every
+/// change is described in the [migration notes](self#migration-notes).
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Wraps serialized data with the [`FORMAT_VERSION`] it was written with.
+///
+/// Deserializing fails if the data was written with a newer format than [`FORMAT_VERSION`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Versioned<T> {
+    /// The version of the format the data was written with.
+    pub format: u32,
+
+    /// The data.
+    pub data: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T> Versioned<T> {
+    /// Wraps `data` with the current [`FORMAT_VERSION`].
+    pub const fn new(data: T) -> Self {
+        Self {
+            format: FORMAT_VERSION,
+            data,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Versioned<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Unchecked<T> {
+            format: u32,
+            data: T,
+        }
+
+        let Unchecked { format, data } = Unchecked::deserialize(deserializer)?;
+        if format > FORMAT_VERSION {
+            return Err(D::Error::custom(format!(
+                "format version {} is newer than the supported version {}",
+                format, FORMAT_VERSION
+            )));
+        }
+        Ok(Self { format, data })
+    }
+}
+
+/// Serializes an [`Emoji`] field as its glyph, e.g. `"🐉"`, with
+/// `#[serde(with = "mythoji::schema::glyph")]`.
+///
+/// See the [module documentation](self#glyphs) for details.
+#[cfg(feature = "serde")]
+pub mod glyph {
+    use super::*;
+
+    /// Serializes the emoji as its glyph.
+    ///
+    /// Fails if the glyph would be read back as a different emoji, see the
+    /// [module documentation](super#glyphs).
+    pub fn serialize<S: Serializer>(emoji: &Emoji, serializer: S) -> Result<S::Ok, S::Error> {
+        let glyph = emoji.to_string();
+        if Emoji::from_glyph(&glyph) != Some(*emoji) {
+            return Err(serde::ser::Error::custom(format!(
+                "{:?} cannot be serialized as a glyph, as {:?} is read back as another emoji",
+                emoji, glyph
+            )));
+        }
+        serializer.serialize_str(&glyph)
+    }
+
+    /// Deserializes an emoji from its glyph.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Emoji, D::Error> {
+        let glyph = String::deserialize(deserializer)?;
+        Emoji::from_glyph(&glyph)
+            .ok_or_else(|| D::Error::custom(format!("unknown emoji glyph {:?}", glyph)))
+    }
+}
+
+/// An [`Emoji`] that is serialized as its glyph, e.g. `"🐉"`, for collections such as `Vec<Glyph>`.
+///
+/// See the [module documentation](self#glyphs) for details.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Glyph(#[serde(with = "glyph")] pub Emoji);
+
+#[cfg(feature = "serde")]
+impl From<Emoji> for Glyph {
+    fn from(value: Emoji) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Glyph> for Emoji {
+    fn from(value: Glyph) -> Self {
+        value.0
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{Creature, Emoji, Gender, Item, Location, Person, SkinTone, Symbol};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SaveFile {
+        party: Vec<Emoji>,
+        mount: Emoji,
+        home: Emoji,
+        inventory: Vec<Emoji>,
+        status: Vec<Symbol>,
+    }
+
+    #[test]
+    fn test_format_1_fixture() {
+        let save: Versioned<SaveFile> =
+            serde_json::from_str(include_str!("../tests/fixtures/format-1.json")).unwrap();
+        assert_eq!(save.format, 1);
+        assert_eq!(
+            save.data,
+            SaveFile {
+                party: vec![
+                    Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Female),
+                    Emoji::Person(Person::HeadScarfPerson, SkinTone::Neutral, Gender::Neutral),
+                    Emoji::Person(
+                        Person::HeadScarfPerson,
+                        SkinTone::MediumLight,
+                        Gender::Neutral
+                    ),
+                ],
+                mount: Emoji::Creature(Creature::Horse),
+                home: Emoji::Location(Location::CastleJapanese),
+                inventory: vec![Emoji::Item(Item::SwordsCrossed), Emoji::Item(Item::Coin)],
+                status: vec![Symbol::Zzz, Symbol::ExclamationWithQuestion],
+            }
+        );
+    }
+
+    #[test]
+    fn test_serialize_names() {
+        let elf = Emoji::Person(Person::Elf, SkinTone::MediumDark, Gender::Male);
+        assert_eq!(
+            serde_json::to_string(&elf).unwrap(),
+            r#"{"person":["elf","medium-dark","male"]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Location::TreeEvergreen).unwrap(),
+            r#""tree-evergreen""#
+        );
+    }
+
+    #[test]
+    fn test_glyph() {
+        let elf = Glyph(Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female));
+        let saved = serde_json::to_string(&elf).unwrap();
+        assert_eq!(saved, r#""🧝🏿‍♀️""#);
+        assert_eq!(serde_json::from_str::<Glyph>(&saved).unwrap(), elf);
+
+        // Variation selectors are optional.
+        let swords: Glyph = serde_json::from_str(r#""⚔""#).unwrap();
+        assert_eq!(Emoji::from(swords), Emoji::Item(Item::SwordsCrossed));
+
+        let error = serde_json::from_str::<Glyph>(r#""🚀""#).unwrap_err();
+        assert!(
+            error.to_string().contains("unknown emoji glyph"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_glyph_round_trip() {
+        let mut lossy = Vec::new();
+        for emoji in crate::glyph::all() {
+            match serde_json::to_string(&Glyph(emoji)) {
+                Ok(saved) => {
+                    assert_eq!(serde_json::from_str(&saved).ok(), Some(Glyph(emoji)));
+                }
+                Err(_) => lossy.push(emoji),
+            }
+        }
+
+        // Every lossy emoji is listed in the module documentation.
+        let (persons, others): (Vec<_>, Vec<_>) = lossy.iter().partition(|e| e.is_person());
+        assert_eq!(
+            others,
+            [
+                &Emoji::Location(Location::Oasis),
+                &Emoji::Location(Location::Palace)
+            ]
+        );
+        let hides_gender = [
+            Person::Artist,
+            Person::Baby,
+            Person::BaldPerson,
+            Person::HeadScarfPerson,
+            Person::Royalty,
+            Person::SkullCapPerson,
+        ];
+        let hides_skin = [Person::Genie, Person::Zombie];
+        let expected = crate::glyph::all().filter(|emoji| match emoji {
+            Emoji::Person(person, skin, gender) => {
+                (*gender != Gender::Neutral && hides_gender.contains(person))
+                    || (*skin != SkinTone::Neutral && hides_skin.contains(person))
+            }
+            _ => false,
+        });
+        assert!(expected.eq(persons.into_iter().copied()));
+        assert_eq!(lossy.len(), 104);
+    }
+
+    #[test]
+    fn test_newer_format_is_rejected() {
+        let error = serde_json::from_str::<Versioned<Item>>(r#"{"format":2,"data":"coin"}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("format version 2 is newer"), "{}", error);
+    }
+}
@@ -0,0 +1,138 @@
+//! Seasons, which give each time of the year a palette of emojis, so a world can change with its
+//! calendar.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::grid::Overlay;
+//! use mythoji::season::Season;
+//! use mythoji::Location;
+//!
+//! let season = Season::Autumn.next();
+//! assert_eq!(season, Season::Winter);
+//! assert_eq!(season.palette(), ["❄️", "☃️", "🌨️", "🧊"]);
+//!
+//! // Deciduous trees lose their leaves in winter.
+//! assert_eq!(season.tree(Location::TreeDeciduous), Location::TreeLeafless);
+//!
+//! // Snow falls over the whole map.
+//! let snow = Overlay::new(season.weather(), 0, 0, 10, 10);
+//! assert_eq!(snow.glyph(), "🌨️");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::Location;
+
+/// A season of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Season {
+    /// Spring, e.g. "🌸".
+    Spring,
+
+    /// Summer, e.g. "☀️".
+    Summer,
+
+    /// Autumn, or fall, e.g. "🍂".
+    Autumn,
+
+    /// Winter, e.g. "❄️".
+    Winter,
+}
+
+impl_all!(Season, [Spring, Summer, Autumn, Winter]);
+
+impl Season {
+    /// Returns the season that follows this one, wrapping from winter to spring.
+    pub const fn next(&self) -> Self {
+        match self {
+            Self::Spring => Self::Summer,
+            Self::Summer => Self::Autumn,
+            Self::Autumn => Self::Winter,
+            Self::Winter => Self::Spring,
+        }
+    }
+
+    /// Returns the fully-qualified emoji that represents this season.
+    pub const fn emoji(&self) -> &'static str {
+        self.palette()[0]
+    }
+
+    /// Returns a themed palette of fully-qualified emojis for this season, starting with
+    /// [`Season::emoji`].
+    ///
+    /// Some of these share the glyph of a variant in this crate's catalog, e.g. autumn's
+    /// [`Item::LeafFallen`](crate::Item::LeafFallen) and winter's
+    /// [`Symbol::Snowflake`](crate::Symbol::Snowflake), but most (e.g. "🌸") have none.
+    pub const fn palette(&self) -> &'static [&'static str] {
+        match self {
+            Self::Spring => &["🌸", "🌱", "🌷", "🐣"],
+            Self::Summer => &["☀️", "🌻", "🌴", "🏖️"],
+            Self::Autumn => &["🍂", "🍁", "🎃", "🍄"],
+            Self::Winter => &["❄️", "☃️", "🌨️", "🧊"],
+        }
+    }
+
+    /// Returns the typical weather of this season, e.g. to draw as an
+    /// [`Overlay`](crate::grid::Overlay).
+    pub const fn weather(&self) -> &'static str {
+        match self {
+            Self::Spring => "🌦️",
+            Self::Summer => "☀️",
+            Self::Autumn => "🌧️",
+            Self::Winter => "🌨️",
+        }
+    }
+
+    /// Returns the given tree as it appears in this season.
+    ///
+    /// Deciduous trees are leafless in winter, and leafless trees are in leaf in spring and summer.
+    /// Every other location, including evergreen and palm trees, is returned as is.
+    pub const fn tree(&self, tree: Location) -> Location {
+        match (self, tree) {
+            (Self::Winter, Location::TreeDeciduous) => Location::TreeLeafless,
+            (Self::Spring | Self::Summer, Location::TreeLeafless) => Location::TreeDeciduous,
+            _ => tree,
+        }
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.emoji())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::width;
+
+    #[test]
+    fn test_season() {
+        for season in Season::ALL {
+            assert_eq!(season.next().next().next().next(), *season);
+            for emoji in season.palette().iter().chain([&season.weather()]) {
+                assert_eq!(width(emoji), 2, "{:?}", emoji);
+            }
+        }
+        assert_eq!(Season::Spring.to_string(), "🌸");
+        assert_eq!(
+            Season::Spring.tree(Location::TreeLeafless),
+            Location::TreeDeciduous
+        );
+        assert_eq!(
+            Season::Autumn.tree(Location::TreeDeciduous),
+            Location::TreeDeciduous
+        );
+        assert_eq!(
+            Season::Winter.tree(Location::TreeEvergreen),
+            Location::TreeEvergreen
+        );
+    }
+}
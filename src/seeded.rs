@@ -0,0 +1,204 @@
+//! Deterministic sampling of emojis from a seed, e.g. for procedurally generated worlds.
+//!
+//! Sampling uses [rendezvous hashing][] over a fixed hash function and the name of each variant,
+//! which means that:
+//!
+//! - the same seed always samples the same emoji, across platforms and crate versions;
+//! - adding new variants in a later crate version only changes the outcome for the (proportionally
+//!   few) seeds that now sample a new variant, rather than reshuffling every seed.
+//!
+//! [rendezvous hashing]: https://en.wikipedia.org/wiki/Rendezvous_hashing
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::{Category, Creature, Emoji};
+//!
+//! let world_seed = 42;
+//! assert_eq!(Creature::sample_seeded(world_seed), Creature::sample_seeded(world_seed));
+//!
+//! // Derive a seed per use, e.g. per map cell, to sample different emojis from a single world seed.
+//! let cell = Emoji::sample_seeded(world_seed ^ 7, Category::Location);
+//! assert!(matches!(cell, Some(Emoji::Location(_))));
+//! ```
+
+use crate::{
+    BodyPart, Category, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol,
+};
+
+impl Emoji {
+    /// Deterministically samples an emoji of the given category from `seed`.
+    ///
+    /// Persons are sampled without skin tone or gender modifiers.
+    ///
+    /// Returns `None` if the category has no emojis.
+    pub fn sample_seeded(seed: u64, category: Category) -> Option<Emoji> {
+        match category {
+            Category::Person => Some(Emoji::Person(
+                Person::sample_seeded(seed),
+                SkinTone::Neutral,
+                Gender::Neutral,
+            )),
+            Category::Creature => Some(Emoji::Creature(Creature::sample_seeded(seed))),
+            Category::Location => Some(Emoji::Location(Location::sample_seeded(seed))),
+            Category::Item => Some(Emoji::Item(Item::sample_seeded(seed))),
+            Category::Flora => Some(Emoji::Flora(Flora::sample_seeded(seed))),
+            Category::BodyPart => Some(Emoji::BodyPart(BodyPart::sample_seeded(seed))),
+            Category::Symbol => Some(Emoji::Symbol(Symbol::sample_seeded(seed))),
+        }
+    }
+}
+
+impl Person {
+    /// Deterministically samples a person from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |p| p.metadata().name)
+    }
+}
+
+impl Creature {
+    /// Deterministically samples a creature from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |c| c.metadata().name)
+    }
+}
+
+impl Location {
+    /// Deterministically samples a location from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |l| l.metadata().name)
+    }
+}
+
+impl Item {
+    /// Deterministically samples an item from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |i| i.metadata().name)
+    }
+}
+
+impl Flora {
+    /// Deterministically samples a plant or fungus from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |f| f.metadata().name)
+    }
+}
+
+impl BodyPart {
+    /// Deterministically samples a body part from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |b| b.metadata().name)
+    }
+}
+
+impl Symbol {
+    /// Deterministically samples a symbol from `seed`.
+    ///
+    /// See [`Emoji::sample_seeded`] for details.
+    pub fn sample_seeded(seed: u64) -> Self {
+        sample(seed, Self::ALL, |s| s.metadata().name)
+    }
+}
+
+/// Returns the value with the highest score for `seed`, preferring earlier values on a tie.
+fn sample<T: Copy>(seed: u64, values: &[T], key: impl Fn(&T) -> &'static str) -> T {
+    let mut best = values[0];
+    let mut best_score = score(seed, key(&best));
+    for value in &values[1..] {
+        let score = score(seed, key(value));
+        if score > best_score {
+            best = *value;
+            best_score = score;
+        }
+    }
+    best
+}
+
+/// Scores a key for a seed.
+///
+/// **NOTE**: This must never change, as doing so would change the outcome of every seed.
+fn score(seed: u64, key: &str) -> u64 {
+    // 64-bit FNV-1a over the little-endian seed, followed by the key.
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in seed.to_le_bytes().iter().chain(key.as_bytes()) {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    // SplitMix64 finalizer, as FNV-1a alone distributes similar keys poorly.
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_seeded_is_stable() {
+        // These must never change; see `score`.
+        assert_eq!(score(0, ""), 0x813f_0174_a236_7c13);
+        assert_eq!(score(42, "Dragon"), 0x4668_2814_26b6_9e49);
+        assert_eq!(score(u64::MAX, "HeadScarfPerson"), 0xbf20_d557_40c9_ad3f);
+    }
+
+    #[test]
+    fn test_sample_seeded_is_deterministic() {
+        for seed in 0..100 {
+            assert_eq!(Creature::sample_seeded(seed), Creature::sample_seeded(seed));
+            assert_eq!(
+                Emoji::sample_seeded(seed, Category::Item),
+                Some(Emoji::Item(Item::sample_seeded(seed)))
+            );
+        }
+        assert_eq!(
+            Emoji::sample_seeded(0, Category::Symbol),
+            Some(Emoji::Symbol(Symbol::sample_seeded(0)))
+        );
+    }
+
+    #[test]
+    fn test_sample_seeded_only_extends() {
+        let key = |c: &Creature| c.metadata().name;
+        let (old, _) = Creature::ALL.split_at(Creature::ALL.len() - 1);
+
+        let mut changed = 0;
+        for seed in 0..1000 {
+            let before = sample(seed, old, key);
+            let after = sample(seed, Creature::ALL, key);
+            if before != after {
+                assert_eq!(after, *Creature::ALL.last().unwrap());
+                changed += 1;
+            }
+        }
+        assert!(changed < 100, "{}", changed);
+    }
+
+    #[test]
+    fn test_sample_seeded_is_distributed() {
+        let mut seen = Vec::new();
+        for seed in 0..1000 {
+            let location = Location::sample_seeded(seed);
+            if !seen.contains(&location) {
+                seen.push(location);
+            }
+        }
+        assert_eq!(seen.len(), Location::ALL.len());
+    }
+}
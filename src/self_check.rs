@@ -0,0 +1,186 @@
+//! Verifies that every emitted sequence can be parsed back into the emoji that emitted it.
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::metadata::{from_name, kebab_case};
+use crate::{Emoji, Gender, Qualification, SkinTone};
+
+/// An emoji that did not round-trip through one of the reverse lookups, returned by [`self_check`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mismatch {
+    emoji: Emoji,
+    lookup: &'static str,
+    parsed: Option<Emoji>,
+}
+
+impl Mismatch {
+    /// Returns the emoji that did not round-trip.
+    pub fn emoji(&self) -> Emoji {
+        self.emoji
+    }
+
+    /// Returns the name of the reverse lookup that failed, e.g. `"glyph"`.
+    pub fn lookup(&self) -> &'static str {
+        self.lookup
+    }
+
+    /// Returns the emoji that was parsed instead, if any.
+    pub fn parsed(&self) -> Option<Emoji> {
+        self.parsed
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} ({:?}) ", self.emoji, self.emoji)?;
+        match self.parsed {
+            Some(parsed) => write!(
+                f,
+                "was parsed by {} as {} ({:?})",
+                self.lookup, parsed, parsed
+            ),
+            None => write!(f, "was not found by {}", self.lookup),
+        }
+    }
+}
+
+/// Renders every emoji, parses it back with each reverse lookup, and returns every mismatch.
+///
+/// The reverse lookups are:
+///
+/// - `"glyph"`: [`Emoji::parse_prefix`], for every [`Qualification`];
+/// - `"name"`: the `:name:` tokens of [`stream`](crate::stream), for emojis without modifiers;
+/// - `"stable hash"`: [`Emoji::from_stable_hash32`];
+/// - `"unicode escape"`: [`Emoji::from_unicode_escape`].
+///
+/// Every lookup must return the emoji itself, except that the glyph lookups (`"glyph"` and
+/// `"unicode escape"`) return a person without the skin tone or gender modifiers that its glyph does
+/// not display, e.g. a genie with a skin tone parses as a genie without one. Any other emoji that
+/// shares a glyph with an earlier declared emoji is a mismatch, as the crate cannot tell them apart.
+///
+/// ```
+/// use mythoji::{Emoji, Location};
+///
+/// // Both share a glyph with an earlier declared location, e.g. "🏜" is also a desert.
+/// let mismatches = mythoji::self_check().unwrap_err();
+/// assert!(mismatches.iter().all(|mismatch| matches!(
+///     mismatch.emoji(),
+///     Emoji::Location(Location::Oasis | Location::Palace)
+/// )));
+/// ```
+pub fn self_check() -> std::result::Result<(), Vec<Mismatch>> {
+    let mut mismatches = Vec::new();
+    let mut check = |emoji: Emoji, lookup: &'static str, parsed: Option<Emoji>| {
+        let expected = match lookup {
+            "glyph" | "unicode escape" => displayed(emoji),
+            _ => emoji,
+        };
+        if parsed != Some(expected) {
+            mismatches.push(Mismatch {
+                emoji,
+                lookup,
+                parsed,
+            });
+        }
+    };
+    for emoji in crate::glyph::all() {
+        for qualification in [Qualification::Full, Qualification::Minimal] {
+            let rendered = emoji.qualified(qualification).to_string();
+            let parsed = Emoji::parse_prefix(&rendered)
+                .filter(|(_, rest)| rest.is_empty())
+                .map(|(parsed, _)| parsed);
+            check(emoji, "glyph", parsed);
+        }
+        check(
+            emoji,
+            "stable hash",
+            Emoji::from_stable_hash32(emoji.stable_hash32()),
+        );
+        check(
+            emoji,
+            "unicode escape",
+            Emoji::from_unicode_escape(&emoji.to_unicode_escape()),
+        );
+    }
+    for emoji in crate::glyph::bases() {
+        check(emoji, "name", from_name(&kebab_case(emoji.metadata().name)));
+    }
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches)
+    }
+}
+
+/// Returns the emoji without the skin tone and gender modifiers that its glyph does not display.
+fn displayed(emoji: Emoji) -> Emoji {
+    match emoji {
+        Emoji::Person(person, skin, gender) => Emoji::Person(
+            person,
+            match person.supports_skin_tone() {
+                true => skin,
+                false => SkinTone::Neutral,
+            },
+            match person.supports_gender() || person.gendered_glyph(&gender).is_some() {
+                true => gender,
+                false => Gender::Neutral,
+            },
+        ),
+        _ => emoji,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Location};
+
+    #[test]
+    fn test_self_check() {
+        let oasis = Emoji::Location(Location::Oasis);
+        let palace = Emoji::Location(Location::Palace);
+        let desert = Some(Emoji::Location(Location::Desert));
+        let castle = Some(Emoji::Location(Location::CastleJapanese));
+        let mismatch = |emoji, lookup, parsed| Mismatch {
+            emoji,
+            lookup,
+            parsed,
+        };
+        assert_eq!(
+            self_check(),
+            Err(vec![
+                mismatch(oasis, "glyph", desert),
+                mismatch(oasis, "glyph", desert),
+                mismatch(oasis, "unicode escape", desert),
+                mismatch(palace, "glyph", castle),
+                mismatch(palace, "glyph", castle),
+                mismatch(palace, "unicode escape", castle),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let mismatch = Mismatch {
+            emoji: Emoji::Location(Location::Oasis),
+            lookup: "glyph",
+            parsed: Some(Emoji::Creature(Creature::Dragon)),
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "🏜 (Location(Oasis)) was parsed by glyph as 🐉 (Creature(Dragon))"
+        );
+        assert_eq!(mismatch.emoji(), Emoji::Location(Location::Oasis));
+        assert_eq!(mismatch.lookup(), "glyph");
+
+        let mismatch = Mismatch {
+            parsed: None,
+            ..mismatch
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "🏜 (Location(Oasis)) was not found by glyph"
+        );
+        assert_eq!(mismatch.parsed(), None);
+    }
+}
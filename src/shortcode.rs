@@ -0,0 +1,392 @@
+//! GitHub and Slack style shortcodes, e.g. `:crossed_swords:`, for platforms that render them.
+
+use crate::glyph::glyph_eq;
+use crate::{BodyPart, Creature, Emoji, Flora, Item, Location, Person, SkinTone, Symbol};
+
+/// Shortcodes of every emoji, sorted by shortcode, from the [gemoji] database.
+///
+/// Emojis with several shortcodes are listed once per shortcode, where the first in sorted order is
+/// the one returned by [`Emoji::shortcode`]. Persons are listed without skin tones, which are
+/// written as a separate `:skin-tone-N:` suffix instead.
+///
+/// [gemoji]: https://github.com/github/gemoji
+const SHORTCODES: &[(&str, Emoji)] = &[
+    ("adhesive_bandage", Emoji::Item(Item::Bandage)),
+    ("adult", Person::Person.neutral()),
+    ("amphora", Emoji::Item(Item::Jar)),
+    ("anger", Emoji::Symbol(Symbol::Anger)),
+    ("ant", Emoji::Creature(Creature::Ant)),
+    ("artist", Person::Artist.neutral()),
+    ("axe", Emoji::Item(Item::Axe)),
+    ("baby", Person::Baby.neutral()),
+    ("badger", Emoji::Creature(Creature::Badger)),
+    ("bangbang", Emoji::Symbol(Symbol::ExclamationDouble)),
+    ("bat", Emoji::Creature(Creature::Bat)),
+    ("bear", Emoji::Creature(Creature::Bear)),
+    ("bearded_person", Person::BeardedPerson.neutral()),
+    ("beaver", Emoji::Creature(Creature::Beaver)),
+    ("bed", Emoji::Item(Item::Bed)),
+    ("bee", Emoji::Creature(Creature::Honeybee)),
+    ("beer", Emoji::Item(Item::Beer)),
+    ("bison", Emoji::Creature(Creature::Bison)),
+    ("black_bird", Emoji::Creature(Creature::BlackBird)),
+    ("black_flag", Emoji::Item(Item::FlagBlack)),
+    ("blowfish", Emoji::Creature(Creature::Blowfish)),
+    ("boar", Emoji::Creature(Creature::Boar)),
+    ("boat", Emoji::Location(Location::BoatSail)),
+    ("bomb", Emoji::Item(Item::Bomb)),
+    ("book", Emoji::Item(Item::BookOpen)),
+    ("boomerang", Emoji::Item(Item::Boomerang)),
+    ("bow_and_arrow", Emoji::Item(Item::BowAndArrow)),
+    ("boy", Person::Child.male()),
+    ("brain", Emoji::BodyPart(BodyPart::Brain)),
+    ("bricks", Emoji::Item(Item::Brick)),
+    ("bug", Emoji::Creature(Creature::Bug)),
+    ("bulb", Emoji::Item(Item::LightBulb)),
+    ("bust_in_silhouette", Emoji::Symbol(Symbol::Silhouette)),
+    ("busts_in_silhouette", Emoji::Symbol(Symbol::Silhouettes)),
+    ("butterfly", Emoji::Creature(Creature::Butterfly)),
+    ("cactus", Emoji::Flora(Flora::Cactus)),
+    ("camel", Emoji::Creature(Creature::Camel)),
+    ("camping", Emoji::Location(Location::Campsite)),
+    ("candle", Emoji::Item(Item::Candle)),
+    ("canoe", Emoji::Location(Location::Canoe)),
+    ("cat2", Emoji::Creature(Creature::Cat)),
+    ("checkered_flag", Emoji::Item(Item::FlagCheckered)),
+    ("child", Person::Child.neutral()),
+    (
+        "classical_building",
+        Emoji::Location(Location::BuildingClassic),
+    ),
+    ("closed_book", Emoji::Item(Item::BookClosed)),
+    ("coat", Emoji::Item(Item::Coat)),
+    ("cockroach", Emoji::Creature(Creature::Cockroach)),
+    ("coffin", Emoji::Item(Item::Coffin)),
+    ("coin", Emoji::Item(Item::Coin)),
+    ("comet", Emoji::Symbol(Symbol::Comet)),
+    ("cow2", Emoji::Creature(Creature::Cow)),
+    ("crab", Emoji::Creature(Creature::Crab)),
+    ("cricket", Emoji::Creature(Creature::Cricket)),
+    ("crocodile", Emoji::Creature(Creature::Crocodile)),
+    ("crossed_flags", Emoji::Item(Item::FlagsCrossed)),
+    ("crossed_swords", Emoji::Item(Item::SwordsCrossed)),
+    ("crown", Emoji::Item(Item::Crown)),
+    ("crystal_ball", Emoji::Item(Item::CrystalBall)),
+    ("cut_of_meat", Emoji::Item(Item::MeatCut)),
+    ("cyclone", Emoji::Symbol(Symbol::Cyclone)),
+    ("dagger", Emoji::Item(Item::Dagger)),
+    ("dart", Emoji::Item(Item::Dart)),
+    ("deciduous_tree", Emoji::Location(Location::TreeDeciduous)),
+    ("deer", Emoji::Creature(Creature::Deer)),
+    ("desert", Emoji::Location(Location::Desert)),
+    ("diya_lamp", Emoji::Item(Item::DiyaLamp)),
+    ("dog2", Emoji::Creature(Creature::Dog)),
+    ("dolphin", Emoji::Creature(Creature::Dolphin)),
+    ("donkey", Emoji::Creature(Creature::Donkey)),
+    ("door", Emoji::Item(Item::Door)),
+    ("dove", Emoji::Creature(Creature::Dove)),
+    ("dragon", Emoji::Creature(Creature::Dragon)),
+    ("drop_of_blood", Emoji::Item(Item::BloodDrop)),
+    ("droplet", Emoji::Item(Item::WaterDrop)),
+    ("eagle", Emoji::Creature(Creature::Eagle)),
+    ("ear", Emoji::BodyPart(BodyPart::Ear)),
+    ("ear_of_rice", Emoji::Flora(Flora::Sheaf)),
+    ("elephant", Emoji::Creature(Creature::Elephant)),
+    ("elf", Person::Elf.neutral()),
+    ("elf_man", Person::Elf.male()),
+    ("elf_woman", Person::Elf.female()),
+    ("european_castle", Emoji::Location(Location::Castle)),
+    ("evergreen_tree", Emoji::Location(Location::TreeEvergreen)),
+    ("exclamation", Emoji::Symbol(Symbol::ExclamationRed)),
+    ("eye", Emoji::BodyPart(BodyPart::Eye)),
+    ("fairy", Person::Fairy.neutral()),
+    ("fairy_man", Person::Fairy.male()),
+    ("fairy_woman", Person::Fairy.female()),
+    ("fallen_leaf", Emoji::Item(Item::LeafFallen)),
+    ("female_sign", Emoji::Symbol(Symbol::GenderFemale)),
+    ("fingerprint", Emoji::Symbol(Symbol::Fingerprint)),
+    ("fire", Emoji::Symbol(Symbol::Fire)),
+    ("firecracker", Emoji::Item(Item::Firecracker)),
+    ("fish", Emoji::Creature(Creature::Fish)),
+    ("flashlight", Emoji::Item(Item::Flashlight)),
+    ("flipper", Emoji::Creature(Creature::Dolphin)),
+    ("fly", Emoji::Creature(Creature::Fly)),
+    ("footprints", Emoji::BodyPart(BodyPart::Footprints)),
+    ("four_leaf_clover", Emoji::Flora(Flora::Clover)),
+    ("fox_face", Emoji::Creature(Creature::Fox)),
+    ("frog", Emoji::Creature(Creature::Frog)),
+    ("funeral_urn", Emoji::Item(Item::Urn)),
+    ("gem", Emoji::Item(Item::GemStone)),
+    ("genie", Person::Genie.neutral()),
+    ("genie_man", Person::Genie.male()),
+    ("genie_woman", Person::Genie.female()),
+    ("ghost", Emoji::Creature(Creature::Ghost)),
+    ("girl", Person::Child.female()),
+    ("goat", Emoji::Creature(Creature::Goat)),
+    ("gorilla", Emoji::Creature(Creature::Gorilla)),
+    ("grey_exclamation", Emoji::Symbol(Symbol::ExclamationWhite)),
+    ("grey_question", Emoji::Symbol(Symbol::QuestionWhite)),
+    ("hammer", Emoji::Item(Item::Hammer)),
+    ("hammer_and_pick", Emoji::Item(Item::HammerAndPick)),
+    ("harp", Emoji::Item(Item::Harp)),
+    ("headstone", Emoji::Item(Item::Grave)),
+    ("heart", Emoji::Item(Item::HeartRed)),
+    (
+        "heavy_exclamation_mark",
+        Emoji::Symbol(Symbol::ExclamationRed),
+    ),
+    ("hedgehog", Emoji::Creature(Creature::Hedgehog)),
+    ("herb", Emoji::Flora(Flora::Herb)),
+    ("hole", Emoji::Location(Location::Cave)),
+    ("honeybee", Emoji::Creature(Creature::Honeybee)),
+    ("hourglass", Emoji::Item(Item::HourglassDone)),
+    (
+        "hourglass_flowing_sand",
+        Emoji::Item(Item::HourglassNotDone),
+    ),
+    ("hut", Emoji::Location(Location::Hut)),
+    (
+        "interrobang",
+        Emoji::Symbol(Symbol::ExclamationWithQuestion),
+    ),
+    ("izakaya_lantern", Emoji::Item(Item::RedLantern)),
+    ("japanese_castle", Emoji::Location(Location::CastleJapanese)),
+    ("japanese_goblin", Emoji::Creature(Creature::Goblin)),
+    ("japanese_ogre", Emoji::Creature(Creature::Ogre)),
+    ("jellyfish", Emoji::Creature(Creature::Jellyfish)),
+    ("lady_beetle", Emoji::Creature(Creature::Beetle)),
+    ("lantern", Emoji::Item(Item::RedLantern)),
+    ("leafless_tree", Emoji::Location(Location::TreeLeafless)),
+    ("leaves", Emoji::Item(Item::Leaf)),
+    (
+        "left_speech_bubble",
+        Emoji::Symbol(Symbol::SpeechBubbleLeft),
+    ),
+    ("leopard", Emoji::Creature(Creature::Leopard)),
+    ("lion", Emoji::Creature(Creature::Lion)),
+    ("lips", Emoji::BodyPart(BodyPart::Lips)),
+    ("lizard", Emoji::Creature(Creature::Lizard)),
+    ("llama", Emoji::Creature(Creature::Llama)),
+    ("mage", Person::Mage.neutral()),
+    ("mage_man", Person::Mage.male()),
+    ("mage_woman", Person::Mage.female()),
+    ("magic_wand", Emoji::Item(Item::Wand)),
+    ("male_sign", Emoji::Symbol(Symbol::GenderMale)),
+    ("mammoth", Emoji::Creature(Creature::Mammoth)),
+    ("man", Person::Person.male()),
+    ("man_beard", Person::BeardedPerson.male()),
+    ("man_with_gua_pi_mao", Person::SkullCapPerson.neutral()),
+    ("man_with_turban", Person::TurbanPerson.male()),
+    ("maple_leaf", Emoji::Item(Item::LeafMaple)),
+    ("meat_on_bone", Emoji::Item(Item::MeatOnBone)),
+    ("mermaid", Person::MerPerson.female()),
+    ("merman", Person::MerPerson.male()),
+    ("merperson", Person::MerPerson.neutral()),
+    ("monkey", Emoji::Creature(Creature::Monkey)),
+    ("moose", Emoji::Creature(Creature::Moose)),
+    ("mosquito", Emoji::Creature(Creature::Mosquito)),
+    ("mountain", Emoji::Location(Location::Mountain)),
+    ("mountain_snow", Emoji::Location(Location::MountainSnow)),
+    ("mouse2", Emoji::Creature(Creature::Mouse)),
+    ("mouse_trap", Emoji::Item(Item::MouseTrap)),
+    ("muscle", Emoji::BodyPart(BodyPart::Muscle)),
+    ("mushroom", Emoji::Flora(Flora::Mushroom)),
+    ("nazar_amulet", Emoji::Item(Item::Amulet)),
+    ("octopus", Emoji::Creature(Creature::Octopus)),
+    ("old_key", Emoji::Item(Item::Key)),
+    ("older_adult", Person::OldPerson.neutral()),
+    ("older_man", Person::OldPerson.male()),
+    ("older_woman", Person::OldPerson.female()),
+    ("open_book", Emoji::Item(Item::BookOpen)),
+    ("otter", Emoji::Creature(Creature::Otter)),
+    ("owl", Emoji::Creature(Creature::Owl)),
+    ("ox", Emoji::Creature(Creature::Ox)),
+    ("palm_tree", Emoji::Location(Location::TreePalm)),
+    ("parrot", Emoji::Creature(Creature::Parrot)),
+    ("peacock", Emoji::Creature(Creature::Peacock)),
+    ("person_bald", Person::BaldPerson.neutral()),
+    ("person_with_turban", Person::TurbanPerson.neutral()),
+    ("pick", Emoji::Item(Item::Pick)),
+    ("pig2", Emoji::Creature(Creature::Pig)),
+    ("poultry_leg", Emoji::Item(Item::PoultryLeg)),
+    ("prayer_beads", Emoji::Item(Item::PrayerBeads)),
+    ("prince", Person::Royalty.neutral()),
+    ("question", Emoji::Symbol(Symbol::QuestionRed)),
+    ("rabbit2", Emoji::Creature(Creature::Rabbit)),
+    ("raccoon", Emoji::Creature(Creature::Raccoon)),
+    ("racehorse", Emoji::Creature(Creature::Horse)),
+    ("ram", Emoji::Creature(Creature::Ram)),
+    ("rat", Emoji::Creature(Creature::Rat)),
+    ("red_envelope", Emoji::Item(Item::RedEnvelope)),
+    ("rhinoceros", Emoji::Creature(Creature::Rhinoceros)),
+    (
+        "right_anger_bubble",
+        Emoji::Symbol(Symbol::SpeechBubbleAngry),
+    ),
+    ("rock", Emoji::Item(Item::Rock)),
+    ("rooster", Emoji::Creature(Creature::Rooster)),
+    ("rose", Emoji::Flora(Flora::Rose)),
+    ("sailboat", Emoji::Location(Location::BoatSail)),
+    ("school_satchel", Emoji::Item(Item::Bag)),
+    ("scorpion", Emoji::Creature(Creature::Scorpion)),
+    ("scroll", Emoji::Item(Item::Scroll)),
+    ("seal", Emoji::Creature(Creature::Seal)),
+    ("shark", Emoji::Creature(Creature::Shark)),
+    ("sheep", Emoji::Creature(Creature::Sheep)),
+    ("shield", Emoji::Item(Item::Shield)),
+    ("shovel", Emoji::Item(Item::Shovel)),
+    ("snail", Emoji::Creature(Creature::Snail)),
+    ("snake", Emoji::Creature(Creature::Snake)),
+    ("snowflake", Emoji::Symbol(Symbol::Snowflake)),
+    ("sparkles", Emoji::Symbol(Symbol::Sparkles)),
+    ("speech_balloon", Emoji::Symbol(Symbol::SpeechBubble)),
+    ("spider", Emoji::Creature(Creature::Spider)),
+    ("spider_web", Emoji::Item(Item::SpiderWeb)),
+    ("splatter", Emoji::Symbol(Symbol::Splatter)),
+    ("squid", Emoji::Creature(Creature::Squid)),
+    ("sunflower", Emoji::Flora(Flora::Sunflower)),
+    ("swan", Emoji::Creature(Creature::Swan)),
+    ("tent", Emoji::Location(Location::Tent)),
+    ("thought_balloon", Emoji::Symbol(Symbol::ThoughtBubble)),
+    ("tiger2", Emoji::Creature(Creature::Tiger)),
+    ("tooth", Emoji::BodyPart(BodyPart::Tooth)),
+    ("triangular_flag_on_post", Emoji::Item(Item::FlagTriangle)),
+    ("trident", Emoji::Item(Item::Trident)),
+    ("tropical_fish", Emoji::Creature(Creature::TropicalFish)),
+    ("turkey", Emoji::Creature(Creature::Turkey)),
+    ("turtle", Emoji::Creature(Creature::Turtle)),
+    ("vampire", Person::Vampire.neutral()),
+    ("vampire_man", Person::Vampire.male()),
+    ("vampire_woman", Person::Vampire.female()),
+    ("volcano", Emoji::Location(Location::Volcano)),
+    ("water_buffalo", Emoji::Creature(Creature::WaterBuffalo)),
+    ("whale2", Emoji::Creature(Creature::Whale)),
+    ("wilted_flower", Emoji::Flora(Flora::FlowerWilted)),
+    ("wolf", Emoji::Creature(Creature::Wolf)),
+    ("woman", Person::Person.female()),
+    ("woman_beard", Person::BeardedPerson.female()),
+    ("woman_with_headscarf", Person::HeadScarfPerson.neutral()),
+    ("woman_with_turban", Person::TurbanPerson.female()),
+    ("world_map", Emoji::Item(Item::Map)),
+    ("worm", Emoji::Creature(Creature::Worm)),
+    ("zap", Emoji::Symbol(Symbol::Electricity)),
+    ("zombie", Person::Zombie.neutral()),
+    ("zombie_man", Person::Zombie.male()),
+    ("zombie_woman", Person::Zombie.female()),
+    ("zzz", Emoji::Symbol(Symbol::Zzz)),
+];
+
+impl Emoji {
+    /// Returns the shortcode of this emoji, e.g. `":crossed_swords:"`, if it has one.
+    ///
+    /// Shortcodes follow the [gemoji] database used by GitHub, and are also understood by e.g. Slack
+    /// and Discord. A person with a skin tone is suffixed by the Slack style skin tone shortcode,
+    /// from `:skin-tone-2:` (light) to `:skin-tone-6:` (dark).
+    ///
+    /// [gemoji]: https://github.com/github/gemoji
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Item, Person, SkinTone};
+    ///
+    /// let swords = Emoji::Item(Item::SwordsCrossed);
+    /// assert_eq!(swords.shortcode().as_deref(), Some(":crossed_swords:"));
+    ///
+    /// let elf = Emoji::Person(Person::Elf, SkinTone::Medium, Gender::Female);
+    /// assert_eq!(elf.shortcode().as_deref(), Some(":elf_woman::skin-tone-4:"));
+    /// ```
+    pub fn shortcode(&self) -> Option<String> {
+        let (base, skin) = match self {
+            Self::Person(person, skin, gender) if person.supports_skin_tone() => {
+                (Self::Person(*person, SkinTone::Neutral, *gender), *skin)
+            }
+            _ => (*self, SkinTone::Neutral),
+        };
+        let glyph = base.to_string();
+        let (shortcode, _) = SHORTCODES
+            .iter()
+            .find(|(_, emoji)| glyph_eq(&emoji.to_string(), &glyph))?;
+        Some(match skin.fitzpatrick() {
+            Some(fitzpatrick) => format!(":{}::skin-tone-{}:", shortcode, fitzpatrick.max(2)),
+            None => format!(":{}:", shortcode),
+        })
+    }
+
+    /// Returns the emoji with the given shortcode, e.g. `":crossed_swords:"`.
+    ///
+    /// Every shortcode in the [gemoji] database is accepted, including alternate shortcodes of the
+    /// same emoji, e.g. `":boat:"` and `":sailboat:"`. A person may be followed by a skin tone
+    /// shortcode, as returned by [`Emoji::shortcode`]. The surrounding colons are optional.
+    ///
+    /// [gemoji]: https://github.com/github/gemoji
+    ///
+    /// ```
+    /// use mythoji::{Emoji, Gender, Item, Person, SkinTone};
+    ///
+    /// assert_eq!(
+    ///     Emoji::from_shortcode(":crossed_swords:"),
+    ///     Some(Emoji::Item(Item::SwordsCrossed))
+    /// );
+    /// assert_eq!(
+    ///     Emoji::from_shortcode(":elf_woman::skin-tone-4:"),
+    ///     Some(Emoji::Person(Person::Elf, SkinTone::Medium, Gender::Female))
+    /// );
+    /// assert_eq!(Emoji::from_shortcode(":rocket:"), None);
+    /// ```
+    pub fn from_shortcode(shortcode: &str) -> Option<Emoji> {
+        let shortcode = shortcode.strip_prefix(':').unwrap_or(shortcode);
+        let shortcode = shortcode.strip_suffix(':').unwrap_or(shortcode);
+        let (shortcode, skin) = match shortcode.split_once("::skin-tone-") {
+            Some((shortcode, fitzpatrick)) => {
+                let fitzpatrick = fitzpatrick.parse().ok().filter(|n| *n >= 2)?;
+                (shortcode, SkinTone::from_fitzpatrick(fitzpatrick)?)
+            }
+            None => (shortcode, SkinTone::Neutral),
+        };
+        let i = SHORTCODES
+            .binary_search_by_key(&shortcode, |(shortcode, _)| shortcode)
+            .ok()?;
+        match (SHORTCODES[i].1, skin) {
+            (emoji, SkinTone::Neutral) => Some(emoji),
+            (Self::Person(person, _, gender), skin) if person.supports_skin_tone() => {
+                Some(Self::Person(person, skin, gender))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gender;
+
+    #[test]
+    fn test_shortcodes_sorted() {
+        assert!(SHORTCODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_shortcode() {
+        for emoji in crate::glyph::all() {
+            let Some(shortcode) = emoji.shortcode() else {
+                continue;
+            };
+            let parsed = Emoji::from_shortcode(&shortcode).unwrap();
+            assert_eq!(parsed.to_string(), emoji.to_string(), "{}", shortcode);
+        }
+        for emoji in crate::glyph::bases() {
+            assert!(emoji.shortcode().is_some(), "{:?}", emoji);
+        }
+
+        let elf = Emoji::Person(Person::Elf, SkinTone::Light, Gender::Male);
+        assert_eq!(elf.shortcode().as_deref(), Some(":elf_man::skin-tone-2:"));
+        assert_eq!(Emoji::from_shortcode("elf_man::skin-tone-2"), Some(elf));
+        assert_eq!(
+            Emoji::from_shortcode(":sailboat:"),
+            Some(Emoji::Location(Location::BoatSail))
+        );
+        assert_eq!(Emoji::from_shortcode(":elf::skin-tone-1:"), None);
+        assert_eq!(Emoji::from_shortcode(":dragon::skin-tone-3:"), None);
+        assert_eq!(Emoji::from_shortcode("::"), None);
+    }
+}
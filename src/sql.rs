@@ -0,0 +1,304 @@
+//! Storage of emojis in SQL databases as text, enabled by the `sqlx` feature.
+//!
+//! Every enum in this crate (and [`Emoji`]) implements [`sqlx::Type`], [`sqlx::Encode`], and
+//! [`sqlx::Decode`] for any database that supports strings, and is stored by its stable [name]
+//! (e.g. `"dragon"`, or `"creature:dragon"` for an [`Emoji`]). To store the glyph (e.g. `"🐉"`)
+//! instead, wrap the value in [`Glyph`].
+//!
+//! [name]: Text::to_name
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::sql::Text;
+//! use mythoji::{Creature, Emoji};
+//!
+//! let dragon = Emoji::Creature(Creature::Dragon);
+//! assert_eq!(dragon.to_name(), "creature:dragon");
+//! assert_eq!(dragon.to_glyph(), "🐉");
+//! ```
+
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    Database, Type,
+};
+
+use crate::metadata::kebab_case;
+use crate::{BodyPart, Creature, Emoji, Flora, Gender, Item, Location, Person, SkinTone, Symbol};
+
+/// A value that can be stored as text.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait Text: Sized + private::Sealed {
+    /// Returns the stable name of the value, e.g. `"tree-evergreen"`.
+    ///
+    /// Names are kebab-case, and match the names used by the `serde` feature (see
+    /// [`schema`](crate::schema)). An [`Emoji`] is named by its kebab-case category followed by the
+    /// names of its values, separated by colons, e.g. `"location:tree-evergreen"` or
+    /// `"person:elf:dark:female"`.
+    fn to_name(&self) -> String;
+
+    /// Returns the value with the given name, if any, including former names of renamed variants.
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Returns the glyph of the value, e.g. `"🌲"`.
+    fn to_glyph(&self) -> String;
+
+    /// Returns the value with the given glyph, if any.
+    ///
+    /// Variation selectors are optional, e.g. `"⚔"` and `"⚔️"` are the same glyph. If multiple values share a glyph, e.g. [`Location::Desert`] and [`Location::Oasis`], the
+    /// first declared value is returned.
+    fn from_glyph(glyph: &str) -> Option<Self>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Stores a value as its glyph, rather than its name.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyph<T>(pub T);
+
+impl<T: Text, DB: Database> Type<DB> for Glyph<T>
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, T: Text, DB: Database> Encode<'q, DB> for Glyph<T>
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.to_glyph().encode(buf)
+    }
+}
+
+impl<'r, T: Text, DB: Database> Decode<'r, DB> for Glyph<T>
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let glyph = <&str as Decode<DB>>::decode(value)?;
+        match T::from_glyph(glyph) {
+            Some(value) => Ok(Glyph(value)),
+            None => Err(format!("unknown glyph {:?}", glyph).into()),
+        }
+    }
+}
+
+macro_rules! impl_sqlx {
+    ($($ty:ident),*) => {$(
+        impl<DB: Database> Type<DB> for $ty
+        where
+            String: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <String as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <String as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $ty
+        where
+            String: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                self.to_name().encode(buf)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $ty
+        where
+            &'r str: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let name = <&str as Decode<DB>>::decode(value)?;
+                match Self::from_name(name) {
+                    Some(value) => Ok(value),
+                    None => Err(format!("unknown name {:?}", name).into()),
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_text {
+    ($($ty:ident => $aliases:expr),*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl Text for $ty {
+            fn to_name(&self) -> String {
+                kebab_case(&format!("{:?}", self))
+            }
+
+            fn from_name(name: &str) -> Option<Self> {
+                let aliases: fn(&Self) -> &'static [&'static str] = $aliases;
+                Self::ALL.iter().copied().find(|value| {
+                    value.to_name() == name || aliases(value).iter().any(|a| kebab_case(a) == name)
+                })
+            }
+
+            fn to_glyph(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_glyph(glyph: &str) -> Option<Self> {
+                Self::ALL.iter().copied().find(|value| *value == glyph)
+            }
+        }
+    )*};
+}
+
+impl_text!(
+    Person => |p| p.metadata().aliases,
+    SkinTone => |_| &[],
+    Gender => |_| &[],
+    Creature => |c| c.metadata().aliases,
+    Location => |l| l.metadata().aliases,
+    Item => |i| i.metadata().aliases,
+    Flora => |f| f.metadata().aliases,
+    BodyPart => |b| b.metadata().aliases,
+    Symbol => |s| s.metadata().aliases
+);
+
+impl_sqlx!(Emoji, Person, SkinTone, Gender, Creature, Location, Item, Flora, BodyPart, Symbol);
+
+impl private::Sealed for Emoji {}
+
+impl Text for Emoji {
+    fn to_name(&self) -> String {
+        match self {
+            Emoji::Person(person, skin, gender) => format!(
+                "person:{}:{}:{}",
+                person.to_name(),
+                skin.to_name(),
+                gender.to_name()
+            ),
+            Emoji::Creature(creature) => format!("creature:{}", creature.to_name()),
+            Emoji::Location(location) => format!("location:{}", location.to_name()),
+            Emoji::Item(item) => format!("item:{}", item.to_name()),
+            Emoji::Flora(flora) => format!("flora:{}", flora.to_name()),
+            Emoji::BodyPart(body_part) => format!("body-part:{}", body_part.to_name()),
+            Emoji::Symbol(symbol) => format!("symbol:{}", symbol.to_name()),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let (category, name) = name.split_once(':')?;
+        match category {
+            "person" => {
+                let mut parts = name.split(':');
+                let person = Person::from_name(parts.next()?)?;
+                let skin = SkinTone::from_name(parts.next()?)?;
+                let gender = Gender::from_name(parts.next()?)?;
+                match parts.next() {
+                    Some(_) => None,
+                    None => Some(Emoji::Person(person, skin, gender)),
+                }
+            }
+            "creature" => Creature::from_name(name).map(Emoji::Creature),
+            "location" => Location::from_name(name).map(Emoji::Location),
+            "item" => Item::from_name(name).map(Emoji::Item),
+            "flora" => Flora::from_name(name).map(Emoji::Flora),
+            "body-part" => BodyPart::from_name(name).map(Emoji::BodyPart),
+            "symbol" => Symbol::from_name(name).map(Emoji::Symbol),
+            _ => None,
+        }
+    }
+
+    fn to_glyph(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_glyph(glyph: &str) -> Option<Self> {
+        Emoji::from_glyph(glyph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Item::SwordsCrossed.to_name(), "swords-crossed");
+        assert_eq!(Item::from_name("swords-crossed"), Some(Item::SwordsCrossed));
+        assert_eq!(Item::from_name("SwordsCrossed"), None);
+        assert_eq!(
+            Person::from_name("heard-scarf-person"),
+            Some(Person::HeadScarfPerson)
+        );
+
+        let elf = Emoji::Person(Person::Elf, SkinTone::MediumDark, Gender::Female);
+        assert_eq!(elf.to_name(), "person:elf:medium-dark:female");
+        assert_eq!(Emoji::from_name("person:elf:medium-dark:female"), Some(elf));
+        assert_eq!(Emoji::from_name("person:elf:medium-dark"), None);
+        assert_eq!(Emoji::from_name("person:elf:medium-dark:female:"), None);
+        assert_eq!(
+            Emoji::from_name("location:castle"),
+            Some(Emoji::Location(Location::Castle))
+        );
+        assert_eq!(
+            Emoji::from_name("flora:flower-wilted"),
+            Some(Emoji::Flora(Flora::FlowerWilted))
+        );
+        assert_eq!(Emoji::Flora(Flora::Rose).to_name(), "flora:rose");
+        assert_eq!(
+            Emoji::from_name("body-part:eye"),
+            Some(Emoji::BodyPart(BodyPart::Eye))
+        );
+        assert_eq!(Emoji::from_name("castle"), None);
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for person in Person::ALL {
+            for skin in SkinTone::ALL {
+                for gender in Gender::ALL {
+                    let emoji = Emoji::Person(*person, *skin, *gender);
+                    assert_eq!(Emoji::from_name(&emoji.to_name()), Some(emoji));
+                }
+            }
+        }
+        for symbol in Symbol::ALL {
+            assert_eq!(Symbol::from_name(&symbol.to_name()), Some(*symbol));
+        }
+    }
+
+    #[test]
+    fn test_glyphs() {
+        let mage = Emoji::Person(Person::Mage, SkinTone::Light, Gender::Male);
+        assert_eq!(Emoji::from_glyph(&mage.to_glyph()), Some(mage));
+        assert_eq!(
+            Emoji::from_glyph("🐉"),
+            Some(Emoji::Creature(Creature::Dragon))
+        );
+        assert_eq!(Location::from_glyph("🏜"), Some(Location::Desert));
+        assert_eq!(SkinTone::from_glyph(""), Some(SkinTone::Neutral));
+        assert_eq!(Item::from_glyph("🐉"), None);
+
+        let swords = Emoji::Item(Item::SwordsCrossed);
+        assert_eq!(<Emoji as Text>::from_glyph("⚔"), Some(swords));
+        assert_eq!(<Emoji as Text>::from_glyph("⚔\u{fe0f}"), Some(swords));
+        assert_eq!(<Item as Text>::from_glyph("⚔"), Some(Item::SwordsCrossed));
+    }
+}
@@ -0,0 +1,108 @@
+//! Status effects, which map common conditions of characters to the emojis shown in a HUD.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::status::StatusEffect;
+//!
+//! let effects = [StatusEffect::Poisoned, StatusEffect::Asleep];
+//! let hud: String = effects.iter().map(StatusEffect::emoji).collect();
+//! assert_eq!(hud, "🤢💤");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+/// A temporary condition affecting a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum StatusEffect {
+    /// Taking damage from poison, e.g. "🤢".
+    Poisoned,
+
+    /// Taking damage from fire, e.g. "🔥".
+    Burning,
+
+    /// Unable to move due to cold, e.g. "❄️".
+    Frozen,
+
+    /// Unable to act for a short time, e.g. "💫".
+    Stunned,
+
+    /// Unable to act until woken, e.g. "💤".
+    Asleep,
+
+    /// Taking damage from wounds, e.g. "🩸".
+    Bleeding,
+
+    /// Under the influence of another character, e.g. "❤️".
+    Charmed,
+
+    /// Protected or empowered by a divine force, e.g. "✨".
+    Blessed,
+}
+
+impl_all!(
+    StatusEffect,
+    [Poisoned, Burning, Frozen, Stunned, Asleep, Bleeding, Charmed, Blessed]
+);
+
+impl StatusEffect {
+    /// Returns the fully-qualified emoji that represents this effect.
+    ///
+    /// Most effects share the glyph of a symbol or item, e.g. burning is
+    /// [`Symbol::Fire`](crate::Symbol::Fire) and bleeding is
+    /// [`Item::BloodDrop`](crate::Item::BloodDrop), but poisoned ("🤢") and stunned ("💫") have no
+    /// variant in this crate's catalog.
+    pub const fn emoji(&self) -> &'static str {
+        match self {
+            Self::Poisoned => "🤢",
+            Self::Burning => "🔥",
+            Self::Frozen => "❄️",
+            Self::Stunned => "💫",
+            Self::Asleep => "💤",
+            Self::Bleeding => "🩸",
+            Self::Charmed => "❤️",
+            Self::Blessed => "✨",
+        }
+    }
+}
+
+impl Display for StatusEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.emoji())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Item, Symbol};
+
+    #[test]
+    fn test_status_effect() {
+        assert_eq!(StatusEffect::Burning.to_string(), Symbol::Fire.to_string());
+        assert_eq!(
+            StatusEffect::Frozen.to_string(),
+            Symbol::Snowflake.to_string()
+        );
+        assert_eq!(StatusEffect::Asleep.to_string(), Symbol::Zzz.to_string());
+        assert_eq!(
+            StatusEffect::Blessed.to_string(),
+            Symbol::Sparkles.to_string()
+        );
+        assert_eq!(
+            StatusEffect::Bleeding.to_string(),
+            Item::BloodDrop.to_string()
+        );
+        assert_eq!(
+            StatusEffect::Charmed.to_string(),
+            Item::HeartRed.to_string()
+        );
+        assert_eq!(StatusEffect::Poisoned.to_string(), "🤢");
+        assert_eq!(StatusEffect::Stunned.to_string(), "💫");
+    }
+}
@@ -0,0 +1,414 @@
+//! Streaming adapters, which decode emojis from readers and encode them into writers.
+//!
+//! Emojis are recognized either by their glyph, e.g. "🧙", or by a `:name:` token containing the
+//! `kebab-case` name, alias, or [synonym](crate::Emoji::synonyms) of the emoji, e.g. `:mage:`,
+//! `:swords-crossed:`, or `:wyvern:`. Persons named by a token have a neutral skin tone and gender.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::stream::{Decoder, Event};
+//! use mythoji::{Creature, Emoji, Item};
+//!
+//! let input = "look 🐉 :swords-crossed:!".as_bytes();
+//! let events: Vec<_> = Decoder::new(input).collect::<Result<_, _>>().unwrap();
+//! assert_eq!(
+//!     events,
+//!     [
+//!         Event::Text("look ".to_string()),
+//!         Event::Emoji(Emoji::Creature(Creature::Dragon)),
+//!         Event::Text(" ".to_string()),
+//!         Event::Emoji(Emoji::Item(Item::SwordsCrossed)),
+//!         Event::Text("!".to_string()),
+//!     ],
+//! );
+//! ```
+//!
+//! An [`Encoder`] replaces `:name:` tokens with glyphs as text is written through it:
+//!
+//! ```
+//! use std::fmt::Write;
+//!
+//! use mythoji::stream::Encoder;
+//!
+//! let mut encoder = Encoder::new(String::new());
+//! write!(encoder, "The :mage: fights the :dra").unwrap();
+//! write!(encoder, "gon:!").unwrap();
+//! assert_eq!(encoder.finish().unwrap(), "The 🧙 fights the 🐉!");
+//! ```
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::metadata::from_name;
+use crate::width::{clusters, is_zero_width};
+use crate::Emoji;
+
+/// The longest `:name:` token that is recognized, in bytes, including the colons.
+const MAX_TOKEN_LEN: usize = 64;
+
+/// A piece of a stream decoded by a [`Decoder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// Text that is not an emoji.
+    ///
+    /// Text is yielded as soon as it is read, so consecutive text events may split a word or line.
+    Text(String),
+
+    /// An emoji, either by glyph or by a `:name:` token.
+    Emoji(Emoji),
+}
+
+/// Decodes a stream of UTF-8 text into [`Event`]s, reading incrementally from a [`BufRead`].
+///
+/// Invalid UTF-8 is replaced with "\u{fffd}", as in [`String::from_utf8_lossy`].
+///
+/// Input is only held back when it might be the start of a longer emoji or `:name:` token, e.g.
+/// "🧝" might be followed by a skin tone, so events are available as soon as the input is.
+#[derive(Debug)]
+pub struct Decoder<R> {
+    reader: R,
+    pending: String,
+    partial: Vec<u8>,
+    eof: bool,
+}
+
+impl<R: BufRead> Decoder<R> {
+    /// Creates a decoder that reads from the given reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending: String::new(),
+            partial: Vec::new(),
+            eof: false,
+        }
+    }
+
+    /// Returns the underlying reader, discarding any input that has been read but not decoded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next chunk of input into the pending text.
+    fn fill(&mut self) -> io::Result<()> {
+        let chunk = self.reader.fill_buf()?;
+        if chunk.is_empty() {
+            self.eof = true;
+            if !self.partial.is_empty() {
+                self.partial.clear();
+                self.pending.push(char::REPLACEMENT_CHARACTER);
+            }
+            return Ok(());
+        }
+        self.partial.extend_from_slice(chunk);
+        let len = chunk.len();
+        self.reader.consume(len);
+
+        // A UTF-8 sequence may be split across chunks, in which case the end is kept for later.
+        loop {
+            let (valid, invalid) = match std::str::from_utf8(&self.partial) {
+                Ok(text) => (text.len(), None),
+                Err(error) => (error.valid_up_to(), error.error_len()),
+            };
+            let text = std::str::from_utf8(&self.partial[..valid]).expect("valid UTF-8");
+            self.pending.push_str(text);
+            match invalid {
+                Some(invalid) => {
+                    self.pending.push(char::REPLACEMENT_CHARACTER);
+                    self.partial.drain(..valid + invalid);
+                }
+                None => {
+                    self.partial.drain(..valid);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Returns the next event from the pending text, or `None` if more input is needed.
+    fn decode(&mut self) -> Option<Event> {
+        let mut text = 0;
+        loop {
+            match self.classify(&self.pending[text..]) {
+                Scan::Text(len) => text += len,
+                Scan::Emoji(emoji, len) if text == 0 => {
+                    self.pending.drain(..len);
+                    return Some(Event::Emoji(emoji));
+                }
+                Scan::Emoji(..) | Scan::Incomplete => break,
+            }
+        }
+        if text == 0 {
+            return None;
+        }
+        Some(Event::Text(self.pending.drain(..text).collect()))
+    }
+
+    /// Determines what the start of the given text is.
+    fn classify(&self, text: &str) -> Scan {
+        if text.is_empty() {
+            return Scan::Incomplete;
+        }
+        if let Some(rest) = text.strip_prefix(':') {
+            return match rest.find(':') {
+                Some(end) if end + 2 <= MAX_TOKEN_LEN => match from_name(&rest[..end]) {
+                    Some(emoji) => Scan::Emoji(emoji, end + 2),
+                    None => Scan::Text(1),
+                },
+                None if !self.eof && text.len() < MAX_TOKEN_LEN => Scan::Incomplete,
+                _ => Scan::Text(1),
+            };
+        }
+        if text.as_bytes()[0].is_ascii() {
+            let len = text.find(|c: char| c == ':' || !c.is_ascii());
+            return Scan::Text(len.unwrap_or(text.len()));
+        }
+
+        // Emojis and other clusters may be extended by input that has not been read yet.
+        let extendable = |rest: &str| !self.eof && rest.chars().next().is_none_or(is_zero_width);
+        if let Some((emoji, rest)) = Emoji::parse_prefix(text) {
+            if extendable(rest) {
+                return Scan::Incomplete;
+            }
+            return Scan::Emoji(emoji, text.len() - rest.len());
+        }
+        let (cluster, _) = clusters(text).next().expect("text is not empty");
+        if extendable(&text[cluster.len()..]) {
+            return Scan::Incomplete;
+        }
+        Scan::Text(cluster.len())
+    }
+}
+
+/// Decodes events until the end of the stream, or an I/O error.
+impl<R: BufRead> Iterator for Decoder<R> {
+    type Item = io::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.decode() {
+                return Some(Ok(event));
+            }
+            if self.eof {
+                return None;
+            }
+            if let Err(error) = self.fill() {
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// The start of some pending text.
+enum Scan {
+    /// Text of the given length in bytes.
+    Text(usize),
+
+    /// An emoji of the given length in bytes.
+    Emoji(Emoji, usize),
+
+    /// More input is needed to decide.
+    Incomplete,
+}
+
+/// Replaces `:name:` tokens with glyphs as text is written through it, to a [`fmt::Write`] or an
+/// [`io::Write`].
+///
+/// A `:` that might start a token is held back until the token is complete, so the end of the text
+/// must be written by [`Encoder::finish`] or [`io::Write::flush`]. Unknown tokens are written as is.
+#[derive(Debug)]
+pub struct Encoder<W> {
+    inner: W,
+    pending: Vec<u8>,
+}
+
+impl<W> Encoder<W> {
+    /// Creates an encoder that writes to the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer, discarding any text that is held back.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Encodes the given bytes, passing each complete piece of output to `write`.
+    ///
+    /// Pieces are split at `:`, so they are valid UTF-8 if the input is.
+    fn encode<E>(
+        &mut self,
+        bytes: &[u8],
+        end: bool,
+        mut write: impl FnMut(&mut W, &[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.pending.extend_from_slice(bytes);
+        let mut start = 0;
+        while let Some(colon) = self.pending[start..].iter().position(|&b| b == b':') {
+            let colon = start + colon;
+            write(&mut self.inner, &self.pending[start..colon])?;
+            start = colon;
+
+            let rest = &self.pending[colon + 1..];
+            match rest.iter().position(|&b| b == b':') {
+                Some(len) if len + 2 <= MAX_TOKEN_LEN => {
+                    let name = std::str::from_utf8(&rest[..len]).ok();
+                    match name.and_then(from_name) {
+                        Some(emoji) => {
+                            write(&mut self.inner, emoji.to_string().as_bytes())?;
+                            start += len + 2;
+                        }
+                        None => {
+                            write(&mut self.inner, b":")?;
+                            start += 1;
+                        }
+                    }
+                }
+                None if !end && rest.len() + 1 < MAX_TOKEN_LEN => {
+                    self.pending.drain(..start);
+                    return Ok(());
+                }
+                _ => {
+                    write(&mut self.inner, b":")?;
+                    start += 1;
+                }
+            }
+        }
+        write(&mut self.inner, &self.pending[start..])?;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write> Encoder<W> {
+    /// Writes any text that is held back, and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        self.encode(&[], true, write_str)?;
+        Ok(self.inner)
+    }
+}
+
+fn write_str<W: fmt::Write>(inner: &mut W, bytes: &[u8]) -> fmt::Result {
+    inner.write_str(std::str::from_utf8(bytes).expect("split at a colon"))
+}
+
+impl<W: fmt::Write> fmt::Write for Encoder<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.encode(s.as_bytes(), false, write_str)
+    }
+}
+
+/// Writes text, which should be UTF-8 for any tokens to be recognized.
+///
+/// Flushing writes any text that is held back, so a token split across a flush is not replaced.
+impl<W: io::Write> io::Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.encode(buf, false, |inner, bytes| inner.write_all(bytes))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.encode(&[], true, |inner, bytes| inner.write_all(bytes))?;
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Creature, Gender, Person, SkinTone};
+
+    /// Reads one byte at a time, to split every UTF-8 sequence and emoji across chunks.
+    fn decode_bytewise(input: &str) -> Vec<Event> {
+        decode_bytewise_bytes(input.as_bytes())
+    }
+
+    fn decode_bytewise_bytes(input: &[u8]) -> Vec<Event> {
+        let reader = io::BufReader::with_capacity(1, input);
+        Decoder::new(reader).collect::<io::Result<_>>().unwrap()
+    }
+
+    fn concat_text(events: Vec<Event>) -> Vec<Event> {
+        let mut merged = Vec::new();
+        for event in events {
+            match (merged.last_mut(), event) {
+                (Some(Event::Text(text)), Event::Text(more)) => text.push_str(&more),
+                (_, event) => merged.push(event),
+            }
+        }
+        merged
+    }
+
+    #[test]
+    fn test_decoder_bytewise() {
+        let events = concat_text(decode_bytewise("🧝🏿‍♀️: :mage::nope: 🐉"));
+        assert_eq!(
+            events,
+            [
+                Event::Emoji(Emoji::Person(Person::Elf, SkinTone::Dark, Gender::Female)),
+                Event::Text(": ".to_string()),
+                Event::Emoji(Emoji::Person(
+                    Person::Mage,
+                    SkinTone::Neutral,
+                    Gender::Neutral
+                )),
+                Event::Text(":nope: ".to_string()),
+                Event::Emoji(Emoji::Creature(Creature::Dragon)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decoder_unterminated() {
+        let events = concat_text(decode_bytewise("a :mage"));
+        assert_eq!(events, [Event::Text("a :mage".to_string())]);
+        assert!(decode_bytewise("").is_empty());
+    }
+
+    #[test]
+    fn test_decoder_invalid_utf8() {
+        let events = concat_text(decode_bytewise_bytes(&[b'a', 0xff, b'b', 0xf0, 0x9f]));
+        assert_eq!(events, [Event::Text("a\u{fffd}b\u{fffd}".to_string())]);
+    }
+
+    #[test]
+    fn test_encoder_fmt() {
+        use std::fmt::Write;
+
+        let mut encoder = Encoder::new(String::new());
+        for c in ":mage::nope: :: :".chars() {
+            encoder.write_char(c).unwrap();
+        }
+        assert_eq!(encoder.get_ref(), "🧙:nope: :: ");
+        assert_eq!(encoder.finish().unwrap(), "🧙:nope: :: :");
+
+        let long = format!(":{}", "a".repeat(MAX_TOKEN_LEN));
+        let mut encoder = Encoder::new(String::new());
+        encoder.write_str(&long).unwrap();
+        assert_eq!(encoder.into_inner(), long);
+    }
+
+    #[test]
+    fn test_encoder_io() {
+        use std::io::Write;
+
+        let mut encoder = Encoder::new(Vec::new());
+        encoder
+            .write_all(":swords-crossed: :cas".as_bytes())
+            .unwrap();
+        encoder.write_all("tle: 🐉 :drag".as_bytes()).unwrap();
+        encoder.flush().unwrap();
+        encoder.write_all("on:".as_bytes()).unwrap();
+        encoder.flush().unwrap();
+        let output = String::from_utf8(encoder.into_inner()).unwrap();
+        assert_eq!(output, "⚔️ 🏰 🐉 :dragon:");
+    }
+}
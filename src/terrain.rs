@@ -0,0 +1,94 @@
+//! Terrain, which maps the ground of a map to a consistent palette of double-width glyphs.
+//!
+//! Every glyph is two columns wide, so terrain can fill a [`Grid`](crate::grid::Grid) without
+//! misaligning its columns, and creatures or locations can be drawn over it in a second grid.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::grid::Grid;
+//! use mythoji::terrain::Terrain;
+//!
+//! let mut map = Grid::new(3, 2);
+//! map.fill(Terrain::Grass);
+//! map.set(2, 0, Terrain::Water);
+//! map.set(2, 1, Terrain::Sand);
+//! assert_eq!(map.to_string(), "🟩🟩🌊\n🟩🟩🟨");
+//! ```
+
+use std::fmt::{Display, Formatter, Result};
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+/// The ground of a single map tile.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Terrain {
+    /// Grassland or open fields, e.g. "🟩".
+    #[default]
+    Grass,
+
+    /// Deep or open water, e.g. "🌊".
+    Water,
+
+    /// Sand, such as a beach or a desert, e.g. "🟨".
+    Sand,
+
+    /// Bare rock or a cave floor, e.g. "🪨".
+    Stone,
+
+    /// Snow or ice, e.g. "⬜".
+    Snow,
+
+    /// Molten lava, e.g. "🟥".
+    Lava,
+
+    /// A swamp or mud, e.g. "🟫".
+    Swamp,
+}
+
+impl_all!(Terrain, [Grass, Water, Sand, Stone, Snow, Lava, Swamp]);
+
+impl Terrain {
+    /// Returns the fully-qualified emoji that represents this terrain.
+    ///
+    /// Only stone has a variant in this crate's catalog ([`Item::Rock`](crate::Item::Rock)); the
+    /// other terrain is drawn with colored squares and waves, chosen for their uniform width.
+    pub const fn emoji(&self) -> &'static str {
+        match self {
+            Self::Grass => "🟩",
+            Self::Water => "🌊",
+            Self::Sand => "🟨",
+            Self::Stone => "🪨",
+            Self::Snow => "⬜",
+            Self::Lava => "🟥",
+            Self::Swamp => "🟫",
+        }
+    }
+}
+
+impl Display for Terrain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.emoji())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::width;
+    use crate::Item;
+
+    #[test]
+    fn test_terrain() {
+        for terrain in Terrain::ALL {
+            assert_eq!(width(terrain.emoji()), 2, "{:?}", terrain);
+        }
+        assert_eq!(Terrain::Stone.to_string(), Item::Rock.to_string());
+        assert_eq!(Terrain::Grass.to_string(), "🟩");
+        assert_eq!(Terrain::Water.to_string(), "🌊");
+        assert_eq!(Terrain::Snow.to_string(), "⬜");
+    }
+}
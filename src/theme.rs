@@ -0,0 +1,183 @@
+//! Themes, which map the semantic roles of a game to emojis, so a game can be reskinned in one place.
+//!
+//! # Examples
+//!
+//! ```
+//! use mythoji::theme::{Role, Theme};
+//! use mythoji::{Creature, Emoji};
+//!
+//! assert_eq!(Theme::default().boss().to_string(), "🐉");
+//!
+//! // A spooky reskin, where every other role keeps its default.
+//! let theme = Theme::default().with(Role::Boss, Emoji::Creature(Creature::Ghost));
+//! assert_eq!(theme.boss().to_string(), "👻");
+//! assert_eq!(theme.currency().to_string(), "🪙");
+//! ```
+
+#[cfg(feature = "iter")]
+use strum_macros::EnumIter;
+
+use crate::{Creature, Emoji, Item, Location, Person};
+
+/// A semantic role in a game, which a [`Theme`] maps to an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "iter", derive(EnumIter))]
+pub enum Role {
+    /// The player character, e.g. "🧝".
+    Hero,
+
+    /// The main antagonist, e.g. "🧛".
+    Villain,
+
+    /// A character that buys and sells items, e.g. "🧑".
+    Merchant,
+
+    /// A character that restores health, e.g. "🧚".
+    Healer,
+
+    /// A powerful enemy, e.g. "🐉".
+    Boss,
+
+    /// Money, e.g. "🪙".
+    Currency,
+
+    /// A hazard or a warning, e.g. "💣".
+    Danger,
+
+    /// Health or hit points, e.g. "❤️".
+    Health,
+
+    /// A place to rest, e.g. "🏕".
+    Camp,
+}
+
+impl_all!(
+    Role,
+    [Hero, Villain, Merchant, Healer, Boss, Currency, Danger, Health, Camp]
+);
+
+impl Role {
+    /// Returns the emoji of this role in the default theme.
+    pub const fn default_emoji(&self) -> Emoji {
+        match self {
+            Self::Hero => Person::Elf.neutral(),
+            Self::Villain => Person::Vampire.neutral(),
+            Self::Merchant => Person::Person.neutral(),
+            Self::Healer => Person::Fairy.neutral(),
+            Self::Boss => Emoji::Creature(Creature::Dragon),
+            Self::Currency => Emoji::Item(Item::Coin),
+            Self::Danger => Emoji::Item(Item::Bomb),
+            Self::Health => Emoji::Item(Item::HeartRed),
+            Self::Camp => Emoji::Location(Location::Campsite),
+        }
+    }
+}
+
+/// The emojis a game uses for each [`Role`], which are the defaults unless overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    emojis: [Emoji; Role::ALL.len()],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Theme {
+    /// Creates the default theme, which uses the default emoji of every role.
+    pub const fn new() -> Self {
+        let mut emojis = [Emoji::Item(Item::Coin); Role::ALL.len()];
+        let mut i = 0;
+        while i < Role::ALL.len() {
+            emojis[i] = Role::ALL[i].default_emoji();
+            i += 1;
+        }
+        Self { emojis }
+    }
+
+    /// Uses the given emoji for the given role, instead of its current emoji.
+    pub const fn with(mut self, role: Role, emoji: Emoji) -> Self {
+        self.emojis[role as usize] = emoji;
+        self
+    }
+
+    /// Returns the emoji used for the given role.
+    pub const fn get(&self, role: Role) -> Emoji {
+        self.emojis[role as usize]
+    }
+
+    /// Returns the emoji used for [`Role::Hero`].
+    pub const fn hero(&self) -> Emoji {
+        self.get(Role::Hero)
+    }
+
+    /// Returns the emoji used for [`Role::Villain`].
+    pub const fn villain(&self) -> Emoji {
+        self.get(Role::Villain)
+    }
+
+    /// Returns the emoji used for [`Role::Merchant`].
+    pub const fn merchant(&self) -> Emoji {
+        self.get(Role::Merchant)
+    }
+
+    /// Returns the emoji used for [`Role::Healer`].
+    pub const fn healer(&self) -> Emoji {
+        self.get(Role::Healer)
+    }
+
+    /// Returns the emoji used for [`Role::Boss`].
+    pub const fn boss(&self) -> Emoji {
+        self.get(Role::Boss)
+    }
+
+    /// Returns the emoji used for [`Role::Currency`].
+    pub const fn currency(&self) -> Emoji {
+        self.get(Role::Currency)
+    }
+
+    /// Returns the emoji used for [`Role::Danger`].
+    pub const fn danger(&self) -> Emoji {
+        self.get(Role::Danger)
+    }
+
+    /// Returns the emoji used for [`Role::Health`].
+    pub const fn health(&self) -> Emoji {
+        self.get(Role::Health)
+    }
+
+    /// Returns the emoji used for [`Role::Camp`].
+    pub const fn camp(&self) -> Emoji {
+        self.get(Role::Camp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gender, SkinTone};
+
+    #[test]
+    fn test_theme() {
+        let theme = Theme::default();
+        for (i, role) in Role::ALL.iter().enumerate() {
+            assert_eq!(*role as usize, i);
+            assert_eq!(theme.get(*role), role.default_emoji());
+        }
+        assert_eq!(theme.hero().to_string(), "🧝");
+        assert_eq!(theme.villain().to_string(), "🧛");
+        assert_eq!(theme.merchant().to_string(), "🧑");
+        assert_eq!(theme.healer().to_string(), "🧚");
+        assert_eq!(theme.danger().to_string(), "💣");
+        assert_eq!(theme.health().to_string(), "❤️");
+        assert_eq!(theme.camp().to_string(), "🏕");
+
+        let hero = Emoji::Person(Person::Mage, SkinTone::Dark, Gender::Female);
+        let theme = theme.with(Role::Hero, hero);
+        assert_eq!(theme.hero(), hero);
+        assert_eq!(theme.villain(), Role::Villain.default_emoji());
+    }
+}
@@ -0,0 +1,246 @@
+//! Measures, truncates, and wraps text containing emojis, in terminal columns.
+
+use std::borrow::Cow;
+
+use crate::components::{VS15, VS16, ZWJ};
+
+/// Returns the width of the given text in terminal columns.
+///
+/// Emojis are two columns wide, including sequences joined by a zero width joiner, or followed by
+/// modifiers such as skin tones and variation selectors. Other characters are one column wide,
+/// except for wide East Asian characters, which are two columns wide.
+pub(crate) fn width(text: &str) -> usize {
+    clusters(text).map(|(_, columns)| columns).sum()
+}
+
+/// Returns the given text, truncated to at most `max_columns` terminal columns.
+///
+/// If the text is too wide, it is cut between characters and an ellipsis ("…") is appended, all
+/// within `max_columns`. Emoji sequences are never split, so a person with a skin tone or gender
+/// is either kept or removed as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use mythoji::truncate;
+///
+/// assert_eq!(truncate("🧝🏿‍♀️ Elf", 10), "🧝🏿‍♀️ Elf");
+/// assert_eq!(truncate("🧝🏿‍♀️ Elf", 5), "🧝🏿‍♀️ E…");
+/// assert_eq!(truncate("🧝🏿‍♀️ Elf", 2), "…");
+/// ```
+pub fn truncate(text: &str, max_columns: usize) -> Cow<'_, str> {
+    if width(text) <= max_columns {
+        return Cow::Borrowed(text);
+    }
+    if max_columns == 0 {
+        return Cow::Borrowed("");
+    }
+    let mut end = 0;
+    let mut columns = 0;
+    for (cluster, width) in clusters(text) {
+        if columns + width + 1 > max_columns {
+            break;
+        }
+        end += cluster.len();
+        columns += width;
+    }
+    Cow::Owned(format!("{}…", &text[..end]))
+}
+
+/// Returns the given text, wrapped into lines of at most `columns` terminal columns.
+///
+/// Lines are broken at whitespace where possible, and words that are too wide for a line on their
+/// own are broken between characters. Emoji sequences are never split, so a single emoji that is
+/// wider than `columns` is placed on a line of its own. Existing line breaks are preserved.
+///
+/// # Examples
+///
+/// ```
+/// use mythoji::wrap;
+///
+/// assert_eq!(
+///     wrap("🧙‍♂️ You shall not pass!", 12),
+///     ["🧙‍♂️ You shall", "not pass!"],
+/// );
+/// ```
+pub fn wrap(text: &str, columns: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in paragraph.split_whitespace() {
+            let word_width = width(word);
+            if line_width > 0 && line_width + 1 + word_width <= columns {
+                line.push(' ');
+                line.push_str(word);
+                line_width += 1 + word_width;
+                continue;
+            }
+            if line_width > 0 {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            for (cluster, width) in clusters(word) {
+                if line_width > 0 && line_width + width > columns {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push_str(cluster);
+                line_width += width;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Splits text into clusters that are drawn together, and returns each with its width in columns.
+///
+/// A cluster is a character followed by any modifiers, and any characters joined to it by a zero
+/// width joiner.
+pub(crate) fn clusters(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let mut chars = rest.char_indices().peekable();
+        let (_, c) = chars.next()?;
+        let mut columns = char_width(c);
+        let mut end = c.len_utf8();
+        let mut joined = false;
+        while let Some(&(i, next)) = chars.peek() {
+            match next {
+                // A zero width joiner merges the following character into the cluster.
+                _ if joined => joined = false,
+                ZWJ => joined = true,
+                // Variation selectors choose text (VS15) or emoji (VS16) presentation.
+                VS15 => columns = 1,
+                VS16 => columns = 2,
+                _ if is_zero_width(next) => {}
+                _ => break,
+            }
+            chars.next();
+            end = i + next.len_utf8();
+        }
+        let (cluster, tail) = rest.split_at(end);
+        rest = tail;
+        Some((cluster, columns))
+    })
+}
+
+/// Returns the width of a character that starts a sequence.
+fn char_width(c: char) -> usize {
+    match c {
+        _ if is_zero_width(c) => 0,
+        // Characters with a default emoji presentation.
+        '\u{231a}'..='\u{231b}'
+        | '\u{23e9}'..='\u{23ec}'
+        | '\u{23f0}'
+        | '\u{23f3}'
+        | '\u{25fd}'..='\u{25fe}'
+        | '\u{2614}'..='\u{2615}'
+        | '\u{2648}'..='\u{2653}'
+        | '\u{267f}'
+        | '\u{2693}'
+        | '\u{26a1}'
+        | '\u{26aa}'..='\u{26ab}'
+        | '\u{26bd}'..='\u{26be}'
+        | '\u{26c4}'..='\u{26c5}'
+        | '\u{26ce}'
+        | '\u{26d4}'
+        | '\u{26ea}'
+        | '\u{26f2}'..='\u{26f3}'
+        | '\u{26f5}'
+        | '\u{26fa}'
+        | '\u{26fd}'
+        | '\u{2705}'
+        | '\u{270a}'..='\u{270b}'
+        | '\u{2728}'
+        | '\u{274c}'
+        | '\u{274e}'
+        | '\u{2753}'..='\u{2755}'
+        | '\u{2757}'
+        | '\u{2795}'..='\u{2797}'
+        | '\u{27b0}'
+        | '\u{27bf}'
+        | '\u{2b1b}'..='\u{2b1c}'
+        | '\u{2b50}'
+        | '\u{2b55}'
+        // Pictographs, which are treated as emojis regardless of their default presentation.
+        | '\u{1f000}'..='\u{1faff}'
+        // Wide East Asian characters.
+        | '\u{1100}'..='\u{115f}'
+        | '\u{2e80}'..='\u{a4cf}'
+        | '\u{ac00}'..='\u{d7a3}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{fe30}'..='\u{fe4f}'
+        | '\u{ff00}'..='\u{ff60}'
+        | '\u{ffe0}'..='\u{ffe6}'
+        | '\u{20000}'..='\u{3fffd}' => 2,
+        _ => 1,
+    }
+}
+
+/// Returns whether a character modifies the preceding character, rather than taking up space.
+pub(crate) fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        // Combining marks, including the combining enclosing keycap.
+        '\u{0300}'..='\u{036f}'
+            | '\u{20d0}'..='\u{20ff}'
+            // Variation selectors, and the zero width joiner.
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{200d}'
+            // Skin tone modifiers.
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            // Tags, used by subdivision flags.
+            | '\u{e0020}'..='\u{e007f}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width() {
+        assert_eq!(width(""), 0);
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("🐉"), 2);
+        assert_eq!(width("⚔"), 1);
+        assert_eq!(width("⚔️"), 2);
+        assert_eq!(width("⚡"), 2);
+        assert_eq!(width("⚡\u{fe0e}"), 1);
+        assert_eq!(width("🧝🏿‍♀️"), 2);
+        assert_eq!(width("🧑‍🎨"), 2);
+        assert_eq!(width("4️⃣2️⃣"), 4);
+        assert_eq!(width("a🐉b"), 4);
+        assert_eq!(width("城"), 2);
+    }
+
+    #[test]
+    fn test_clusters() {
+        let clusters: Vec<_> = clusters("a🧝🏿‍♀️4️⃣⚔").collect();
+        assert_eq!(clusters, [("a", 1), ("🧝🏿‍♀️", 2), ("4️⃣", 2), ("⚔", 1)]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("", 0), "");
+        assert_eq!(truncate("abc", 0), "");
+        assert_eq!(truncate("abc", 1), "…");
+        assert_eq!(truncate("abc", 2), "a…");
+        assert_eq!(truncate("abc", 3), "abc");
+        assert_eq!(truncate("🐉🐉🐉", 4), "🐉…");
+        assert_eq!(truncate("🐉🐉🐉", 5), "🐉🐉…");
+        assert_eq!(truncate("🧑‍🎨🧑‍🎨", 3), "🧑‍🎨…");
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert!(wrap("", 10).is_empty());
+        assert_eq!(wrap("a  b\n\nc", 10), ["a b", "", "c"]);
+        assert_eq!(wrap("🐉 breathes fire", 8), ["🐉", "breathes", "fire"]);
+        assert_eq!(wrap("abcdefg hi", 3), ["abc", "def", "g", "hi"]);
+        assert_eq!(wrap("🐉🐉🐉 x", 5), ["🐉🐉", "🐉 x"]);
+        assert_eq!(wrap("🧑‍🎨🧑‍🎨", 1), ["🧑‍🎨", "🧑‍🎨"]);
+    }
+}